@@ -0,0 +1,134 @@
+//! Generates one test for every example input `data/<YEAR>/day_N_test*.txt` which has a sibling file
+//! `data/<YEAR>/day_N_test*.toml` with the expected answers, see `tests/fixtures.rs`.
+//!
+//! Also generates the version of every solver for the cache of answers, see `src/cache.rs`.
+
+use std::{collections::BTreeSet, env, fs, path::{Path, PathBuf}};
+
+fn main() {
+    let out = PathBuf::from(env::var("OUT_DIR").expect("cargo sets OUT_DIR"));
+    write_fixtures(&out);
+    write_versions(&out);
+}
+
+fn write_fixtures(out: &Path) {
+    println!("cargo:rerun-if-changed=data");
+
+    let mut fixtures = vec![];
+    for year_dir in fs::read_dir("data").expect("data/ is readable") {
+        let year_dir = year_dir.expect("data/ is readable").path();
+        let Some(year) = year_dir.file_name().and_then(|name| name.to_str()?.parse::<u16>().ok()) else { continue };
+        for entry in fs::read_dir(&year_dir).expect("the year directories are readable") {
+            let path = entry.expect("the year directories are readable").path();
+            let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else { continue };
+            if path.extension().is_some_and(|ext| ext == "txt") && path.with_extension("toml").exists() {
+                if let Some(day) = fixture_day(stem) {
+                    fixtures.push((year, day, stem.to_string()));
+                }
+            }
+        }
+    }
+    fixtures.sort();
+
+    let tests = fixtures
+        .iter()
+        .map(|(year, day, stem)| format!(
+            "#[test]\nfn y{}_{}() {{\n    check_fixture({}, {}, \"{}\");\n}}\n",
+            year, stem, year, day, stem
+        ))
+        .collect::<String>();
+    fs::write(out.join("fixtures.rs"), tests).expect("OUT_DIR is writable");
+}
+
+/// Writes `versions_<YEAR>.rs` with the versions of the days of every season in `src/y<YEAR>/`.
+/// A version is a hash of the source of the day, of the modules it uses (`crate::grid`, ...) with the
+/// modules those use in turn, and of `lib.rs`, `error.rs` and the `mod.rs` of the season, which every
+/// day uses. So it changes whenever a change of the code could change an answer of the day
+fn write_versions(out: &Path) {
+    println!("cargo:rerun-if-changed=src");
+
+    for year_dir in fs::read_dir("src").expect("src/ is readable") {
+        let year_dir = year_dir.expect("src/ is readable").path();
+        let Some(year) = year_dir.file_name().and_then(|name| name.to_str()?.strip_prefix('y')?.parse::<u16>().ok()) else { continue };
+
+        let mut versions = vec![];
+        for day in 1.. {
+            let day_file = year_dir.join(format!("day_{}.rs", day));
+            if !day_file.exists() {
+                break;
+            }
+            let mut files = BTreeSet::from([PathBuf::from("src/lib.rs"), PathBuf::from("src/error.rs"), year_dir.join("mod.rs")]);
+            let mut pending = vec![day_file];
+            while let Some(file) = pending.pop() {
+                if files.insert(file.clone()) {
+                    pending.extend(used_modules(&file));
+                }
+            }
+            let source = files.iter().flat_map(|file| fs::read(file).expect("the sources are readable")).collect::<Vec<u8>>();
+            versions.push(format!("\"{:016x}\"", fnv1a(&source)));
+        }
+        let versions = format!(
+            "/// Version of every day, day n is at index n - 1. Generated by build.rs\npub static VERSIONS: [&str; {}] = [{}];\n",
+            versions.len(),
+            versions.join(", ")
+        );
+        fs::write(out.join(format!("versions_{}.rs", year)), versions).expect("OUT_DIR is writable");
+    }
+}
+
+// the files of the modules a file names with `crate::`, like `grid` in `use crate::{grid::Grid, Answer}`.
+// Names which aren't modules (`Answer`, macros like `span`) are in `lib.rs` or can't change an answer
+fn used_modules(file: &Path) -> Vec<PathBuf> {
+    let source = fs::read_to_string(file).expect("the sources are readable");
+    let mut names = vec![];
+    for (i, _) in source.match_indices("crate::") {
+        let path = &source[i + "crate::".len()..];
+        let Some(group) = path.strip_prefix('{') else {
+            names.push(identifier(path));
+            continue;
+        };
+        // the first segment of every path of the group, split at the commas which aren't nested
+        let mut depth = 0;
+        let mut item = 0;
+        for (j, c) in group.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => {
+                    names.push(identifier(&group[item..j]));
+                    break;
+                },
+                '}' => depth -= 1,
+                ',' if depth == 0 => {
+                    names.push(identifier(&group[item..j]));
+                    item = j + 1;
+                },
+                _ => (),
+            }
+        }
+    }
+
+    names
+        .iter()
+        .filter(|name| !name.is_empty())
+        .flat_map(|name| [Path::new("src").join(format!("{}.rs", name)), Path::new("src").join(name).join("mod.rs")])
+        .filter(|file| file.exists())
+        .collect()
+}
+
+// the identifier at the start of `s`, after any whitespace
+fn identifier(s: &str) -> &str {
+    let s = s.trim_start();
+    &s[..s.find(|c: char| !c.is_alphanumeric() && c != '_').unwrap_or(s.len())]
+}
+
+// 64 bit FNV-1a, the same hash as `cache::hash`
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
+
+// returns the day of a file name like day_12_test_2, the whole name has to be a valid identifier
+fn fixture_day(stem: &str) -> Option<usize> {
+    let (day, rest) = stem.strip_prefix("day_")?.split_once("_test")?;
+    let valid = rest.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    day.parse().ok().filter(|_| valid)
+}
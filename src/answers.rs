@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use crate::{Answer, Error, Result};
+
+/// File name of the manifest in an input directory
+pub fn file_name(test: bool) -> &'static str {
+    if test { "answers_test.toml" } else { "answers.toml" }
+}
+
+/// Expected answers, read from a manifest like
+///
+/// ```toml
+/// [day_1]
+/// part_1 = 1754
+/// part_2 = "text answers are quoted"
+/// ```
+///
+/// Only this subset of TOML is supported: tables named `day_N`, keys named `part_N`,
+/// integers, strings with the escapes `\n`, `\"` and `\\`, and comments starting with `#`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    expected: HashMap<(usize, u8), String>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut expected = HashMap::new();
+        let mut day = None;
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[').and_then(|table| table.strip_suffix(']')) {
+                let n = table.trim().strip_prefix("day_").and_then(|n| n.parse::<usize>().ok());
+                day = Some(n.ok_or_else(|| Error::at(input, line, "expected a table like [day_1]"))?);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| Error::at(input, line, "answers have to be inside of a [day_N] table"))?;
+            let (key, value) = line.split_once('=')
+                .ok_or_else(|| Error::at(input, line, "expected an answer like 'part_1 = 42'"))?;
+            let part = match key.trim() {
+                "part_1" => 1,
+                "part_2" => 2,
+                key => return Err(Error::at(input, key, format!("unknown key '{}', expected part_1 or part_2", key))),
+            };
+            expected.insert((day, part), parse_value(input, value.trim())?);
+        }
+
+        Ok(Answers { expected })
+    }
+
+    pub fn get(&self, day: usize, part: u8) -> Option<&str> {
+        self.expected.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    /// Compares an answer with the expected one, trailing whitespace is ignored
+    pub fn check(&self, day: usize, part: u8, answer: &Answer) -> Verdict {
+        let actual = answer.to_string();
+        match self.get(day, part) {
+            Some(expected) if expected.trim_end() == actual.trim_end() => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string(), actual },
+            None => Verdict::Missing { actual },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    /// The manifest has no answer for the part
+    Missing { actual: String },
+}
+
+// a value is either an integer or a quoted string
+fn parse_value(input: &str, value: &str) -> Result<String> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        let value = value.split('#').next().unwrap_or_default().trim();
+        return match value.parse::<i128>() {
+            Ok(n) => Ok(n.to_string()),
+            Err(_) => Err(Error::at(input, value, format!("expected a number or a string, got '{}'", value))),
+        };
+    };
+
+    let mut string = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Ok(string),
+            '\\' => match chars.next() {
+                Some('n') => string.push('\n'),
+                Some('"') => string.push('"'),
+                Some('\\') => string.push('\\'),
+                _ => return Err(Error::at(input, value, "unknown escape sequence")),
+            },
+            c => string.push(c),
+        }
+    }
+
+    Err(Error::at(input, value, "missing closing quote"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    use super::{Answers, Verdict};
+
+    const MANIFEST: &str = "\
+# answers of the example inputs
+[day_1]
+part_1 = 7
+part_2 = -5 # negative
+
+[day_13]
+part_2 = \"#..#\\n\\\"ok\\\"\"
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+        assert_eq!(answers.get(1, 1), Some("7"));
+        assert_eq!(answers.get(1, 2), Some("-5"));
+        assert_eq!(answers.get(13, 2), Some("#..#\n\"ok\""));
+        assert_eq!(answers.get(13, 1), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = Answers::parse("[day_1]\npart_3 = 4").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: unknown key 'part_3', expected part_1 or part_2");
+        let error = Answers::parse("part_1 = 4").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: answers have to be inside of a [day_N] table");
+        assert!(Answers::parse("[day_1]\npart_1 = \"open").is_err());
+        assert!(Answers::parse("[day_1]\npart_1 = four").is_err());
+        assert!(Answers::parse("[day_x]").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+        assert_eq!(answers.check(1, 1, &Answer::from(7u32)), Verdict::Pass);
+        assert_eq!(answers.check(13, 2, &Answer::from("#..#\n\"ok\"\n")), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, 2, &Answer::from(5i32)),
+            Verdict::Fail { expected: "-5".to_string(), actual: "5".to_string() }
+        );
+        assert_eq!(answers.check(2, 1, &Answer::from(1u8)), Verdict::Missing { actual: "1".to_string() });
+    }
+}
@@ -0,0 +1,203 @@
+use std::{fmt::Display, str::FromStr, time::{Duration, Instant}};
+
+use crate::{Answer, Result, Solver};
+
+/// Summary of the run times of one part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Returns None if there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+        let min = *sorted.first()?;
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let nanos = sorted.iter().map(|d| d.as_nanos() as f64).collect::<Vec<f64>>();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Some(Stats {
+            runs,
+            min,
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// Runs a part `warmup` times without measuring, then `runs` times with measuring,
+/// at least one run is measured. Fails on the first error of the solver
+pub fn measure(solver: &dyn Solver, part: u8, input: &str, warmup: usize, runs: usize) -> Result<Stats> {
+    let runs = runs.max(1);
+    let mut samples = Vec::with_capacity(runs);
+    for i in 0..warmup + runs {
+        let start = Instant::now();
+        solver.solve(part, input).unwrap_or(Ok(Answer::None))?;
+        if i >= warmup {
+            samples.push(start.elapsed());
+        }
+    }
+
+    Ok(Stats::from_samples(&samples).expect("there is at least one sample"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: usize,
+    pub part: u8,
+    pub stats: Stats,
+}
+
+/// Output format of a [`Report`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format '{}', expected text, json or csv", s)),
+        }
+    }
+}
+
+/// Results of a benchmark over several days
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Report {
+    /// Sum of the medians, the time one run of all measured parts takes
+    pub fn total(&self) -> Duration {
+        self.measurements.iter().map(|m| m.stats.median).sum()
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_string(),
+            Format::Json => self.to_json(),
+            Format::Csv => self.to_csv(),
+        }
+    }
+
+    /// All durations are in nanoseconds
+    pub fn to_json(&self) -> String {
+        let measurements = self.measurements
+            .iter()
+            .map(|m| format!(
+                "    {{\"day\": {}, \"part\": {}, \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}, \"std_dev_ns\": {}}}",
+                m.day, m.part, m.stats.runs, m.stats.min.as_nanos(), m.stats.median.as_nanos(),
+                m.stats.mean.as_nanos(), m.stats.std_dev.as_nanos()
+            ))
+            .collect::<Vec<String>>();
+
+        format!(
+            "{{\n  \"measurements\": [\n{}\n  ],\n  \"total_ns\": {}\n}}\n",
+            measurements.join(",\n"),
+            self.total().as_nanos()
+        )
+    }
+
+    /// All durations are in nanoseconds, the total is left out so every row is a measurement
+    pub fn to_csv(&self) -> String {
+        let mut csv = "day,part,runs,min_ns,median_ns,mean_ns,std_dev_ns\n".to_string();
+        for m in &self.measurements {
+            csv += &format!(
+                "{},{},{},{},{},{},{}\n",
+                m.day, m.part, m.stats.runs, m.stats.min.as_nanos(), m.stats.median.as_nanos(),
+                m.stats.mean.as_nanos(), m.stats.std_dev.as_nanos()
+            );
+        }
+        csv
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Part     \t{:>10}\t{:>10}\t{:>10}\t{:>10}\truns", "min", "median", "mean", "std dev")?;
+        for m in &self.measurements {
+            writeln!(
+                f,
+                "Day {}.{}:\t{:>8}us\t{:>8}us\t{:>8}us\t{:>8}us\t{}",
+                m.day, m.part, m.stats.min.as_micros(), m.stats.median.as_micros(),
+                m.stats.mean.as_micros(), m.stats.std_dev.as_micros(), m.stats.runs
+            )?;
+        }
+        write!(f, "Total of the medians: {}us", self.total().as_micros())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Format, Measurement, Report, Stats};
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|n| Duration::from_micros(*n)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[4, 2, 9, 5])).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_nanos(4500));
+        assert_eq!(stats.mean, Duration::from_nanos(5000));
+        assert_eq!(stats.std_dev, Duration::from_nanos(2550));
+
+        let stats = Stats::from_samples(&micros(&[3, 1, 2])).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(2));
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_report() {
+        let stats = Stats::from_samples(&micros(&[1, 3])).unwrap();
+        let report = Report {
+            measurements: vec![
+                Measurement { day: 1, part: 1, stats },
+                Measurement { day: 1, part: 2, stats },
+            ],
+        };
+        assert_eq!(report.total(), Duration::from_micros(4));
+        assert_eq!(
+            report.to_csv(),
+            "day,part,runs,min_ns,median_ns,mean_ns,std_dev_ns\n1,1,2,1000,2000,2000,1000\n1,2,2,1000,2000,2000,1000\n"
+        );
+        assert!(report.to_json().contains("{\"day\": 1, \"part\": 2, \"runs\": 2, \"min_ns\": 1000, \"median_ns\": 2000"));
+        assert!(report.to_json().ends_with("\"total_ns\": 4000\n}\n"));
+        assert!(report.render(Format::Text).ends_with("Total of the medians: 4us"));
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
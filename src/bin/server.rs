@@ -0,0 +1,41 @@
+//! Serves the solvers on localhost, see `advent_of_code::server` for the routes.
+
+use std::process::ExitCode;
+
+use advent_of_code::{limit::CountingAlloc, server::{self, Server}, trace::{self, Level}};
+
+/// Counts the memory of every part
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+fn main() -> ExitCode {
+    let config = match server::parse_args(std::env::args().skip(1)) {
+        Ok(Some(config)) => config,
+        Ok(None) => {
+            println!("{}", server::USAGE);
+            return ExitCode::SUCCESS;
+        },
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, server::USAGE);
+            return ExitCode::from(2);
+        },
+    };
+    trace::set_level(Level::from_verbosity(config.verbose));
+
+    let server = match Server::bind(&config.address, config.limits) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("unable to listen on {}: {}", config.address, e);
+            return ExitCode::FAILURE;
+        },
+    };
+    match server.url() {
+        Ok(url) => eprintln!("listening on {}", url),
+        Err(e) => eprintln!("listening on {} ({})", config.address, e),
+    }
+    if let Err(e) = server.serve() {
+        eprintln!("the server stopped: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
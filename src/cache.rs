@@ -0,0 +1,239 @@
+//! Answers, run times and memory of the parts which were solved before, so a run doesn't solve
+//! them again if neither the input nor the code of the day changed.
+//!
+//! An entry is keyed by the part, a [`hash`] of the input and the version of the solver, which
+//! build.rs generates from the code of the day (see [`Season::version`](crate::Season::version)).
+//! Entries of other versions are dropped when the cache is opened. The cache is stored as tab
+//! separated lines `year day part version input kind elapsed_ns memory answer`.
+
+use std::{
+    collections::HashMap,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use crate::{season, Answer, Error, Result};
+
+pub const CACHE_FILE: &str = "cache.tsv";
+
+/// 64 bit FNV-1a hash of the input
+pub fn hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Key {
+    pub year: u16,
+    pub day: usize,
+    pub part: u8,
+    pub version: String,
+    /// [`hash`] of the input
+    pub input: u64,
+}
+
+impl Key {
+    /// Returns the key of a part with the current version of its solver, None if there is no solver
+    pub fn new(year: u16, day: usize, part: u8, input: &str) -> Option<Self> {
+        let version = season(year)?.version(day)?.to_string();
+        Some(Key { year, day, part, version, input: hash(input) })
+    }
+}
+
+/// What a part returned and how long it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cached {
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// Most bytes allocated at once, None if they weren't counted
+    pub memory: Option<usize>,
+}
+
+/// The cached parts, shared by the threads which solve the days
+#[derive(Debug)]
+pub struct Cache {
+    path: PathBuf,
+    entries: Mutex<HashMap<Key, Cached>>,
+}
+
+impl Cache {
+    /// Reads the cache at `path`, a missing file is an empty cache.
+    /// Lines which can't be read and entries of days whose version changed are dropped
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(Error::from(e).in_file(path.display().to_string())),
+        };
+        let entries = content
+            .lines()
+            .filter_map(parse_entry)
+            .filter(|(key, _)| season(key.year).and_then(|season| season.version(key.day)) == Some(key.version.as_str()))
+            .collect();
+        Ok(Cache { path, entries: Mutex::new(entries) })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, key: &Key) -> Option<Cached> {
+        self.entries().get(key).cloned()
+    }
+
+    pub fn insert(&self, key: Key, cached: Cached) {
+        self.entries().insert(key, cached);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    /// Writes all entries to the file, ordered by their key
+    pub fn save(&self) -> Result<()> {
+        let entries = self.entries();
+        let mut keys = entries.keys().collect::<Vec<&Key>>();
+        keys.sort();
+        let content = keys.into_iter().map(|key| format_entry(key, &entries[key])).collect::<String>();
+        fs::write(&self.path, content).map_err(|e| Error::from(e).in_file(self.path.display().to_string()))
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashMap<Key, Cached>> {
+        // the map is consistent even if a thread panicked while holding the lock
+        self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn format_entry(key: &Key, cached: &Cached) -> String {
+    let (kind, answer) = match &cached.answer {
+        Answer::Signed(n) => ("signed", n.to_string()),
+        Answer::Unsigned(n) => ("unsigned", n.to_string()),
+        Answer::Text(text) => ("text", escape(text)),
+        Answer::None => ("none", String::new()),
+    };
+    let memory = cached.memory.map_or("-".to_string(), |bytes| bytes.to_string());
+    format!(
+        "{}\t{}\t{}\t{}\t{:016x}\t{}\t{}\t{}\t{}\n",
+        key.year, key.day, key.part, key.version, key.input, kind, cached.elapsed.as_nanos(), memory, answer
+    )
+}
+
+fn parse_entry(line: &str) -> Option<(Key, Cached)> {
+    let [year, day, part, version, input, kind, elapsed, memory, answer] = line.splitn(9, '\t').collect::<Vec<&str>>()[..] else {
+        return None;
+    };
+    let key = Key {
+        year: year.parse().ok()?,
+        day: day.parse().ok()?,
+        part: part.parse().ok()?,
+        version: version.to_string(),
+        input: u64::from_str_radix(input, 16).ok()?,
+    };
+    let answer = match kind {
+        "signed" => Answer::Signed(answer.parse().ok()?),
+        "unsigned" => Answer::Unsigned(answer.parse().ok()?),
+        "text" => Answer::Text(unescape(answer)?),
+        "none" => Answer::None,
+        _ => return None,
+    };
+    let memory = match memory {
+        "-" => None,
+        bytes => Some(bytes.parse().ok()?),
+    };
+    Some((key, Cached { answer, elapsed: Duration::from_nanos(elapsed.parse().ok()?), memory }))
+}
+
+// keeps a text answer on one line
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\n', "\\n").replace('\t', "\\t")
+}
+
+fn unescape(escaped: &str) -> Option<String> {
+    let mut text = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        text.push(match c {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                '\\' => '\\',
+                _ => return None,
+            },
+            c => c,
+        });
+    }
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use crate::Answer;
+
+    use super::{hash, parse_entry, Cache, Cached, Key};
+
+    fn cached(answer: Answer) -> Cached {
+        Cached { answer, elapsed: Duration::from_micros(1500), memory: Some(4096) }
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(""), 0xcbf29ce484222325);
+        assert_eq!(hash("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(hash("1\n2\n"), hash("1\n2"));
+    }
+
+    #[test]
+    fn test_key() {
+        let key = Key::new(2021, 7, 2, "16,1,2").unwrap();
+        assert_eq!(key.version.len(), 16);
+        assert_ne!(key.version, Key::new(2021, 8, 2, "16,1,2").unwrap().version);
+        assert!(Key::new(2021, 26, 1, "").is_none());
+        assert!(Key::new(2015, 1, 1, "").is_none());
+    }
+
+    #[test]
+    fn test_save_and_open() {
+        let path = env::temp_dir().join(format!("aoc_cache_{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let cache = Cache::open(&path).unwrap();
+        assert!(cache.is_empty());
+
+        let text = Key::new(2021, 13, 2, "6,10\n\nfold along y=7\n").unwrap();
+        let number = Key::new(2021, 1, 1, "1\n2\n").unwrap();
+        cache.insert(text.clone(), cached(Answer::Text("#.\n\t#\\".to_string())));
+        cache.insert(number.clone(), Cached { memory: None, ..cached(Answer::Unsigned(1)) });
+        cache.save().unwrap();
+
+        // an entry of an older version of day 1 is dropped
+        let saved = fs::read_to_string(&path).unwrap();
+        let outdated = saved.lines().find(|line| line.starts_with("2021\t1\t")).unwrap().replace(&number.version, "0123456789abcdef");
+        fs::write(&path, saved.clone() + &outdated + "\nnot an entry\n").unwrap();
+
+        let cache = Cache::open(&path).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&text), Some(cached(Answer::Text("#.\n\t#\\".to_string()))));
+        assert_eq!(cache.get(&number).unwrap().memory, None);
+        assert!(cache.get(&Key::new(2021, 1, 1, "1\n3\n").unwrap()).is_none());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        let (key, cached) = parse_entry("2021\t4\t1\tv1\t00000000000000ff\tsigned\t2000\t-\t-42").unwrap();
+        assert_eq!((key.year, key.day, key.part, key.version.as_str(), key.input), (2021, 4, 1, "v1", 255));
+        assert_eq!(cached, Cached { answer: Answer::Signed(-42), elapsed: Duration::from_micros(2), memory: None });
+
+        assert!(parse_entry("2021\t4\t1\tv1\t00000000000000ff\tsigned\t2000\t-").is_none());
+        assert!(parse_entry("2021\t4\t1\tv1\t00000000000000ff\tfloat\t2000\t-\t1.5").is_none());
+        assert!(parse_entry("2021\t4\t1\tv1\t00000000000000ff\ttext\t2000\t-\ta\\b").is_none());
+    }
+}
@@ -0,0 +1,557 @@
+use std::{path::PathBuf, time::Duration};
+
+use advent_of_code::{
+    answers,
+    bench::Format,
+    cache::CACHE_FILE,
+    input::{self, InputSource, DEFAULT_INPUT_DIR},
+    latest_year,
+    limit::Limits,
+    observe::DumpFormat,
+    pool,
+    season,
+    submit::LEDGER_FILE,
+    Season,
+};
+
+pub const USAGE: &str = "\
+Usage: advent_of_code [run|bench|verify|submit|repl] [OPTIONS]
+
+Commands:
+  run       Run one or all days (default)
+  bench     Run one or all days repeatedly and report their run times
+  verify    Run one or all days and compare the answers with the answers manifest
+  submit    Run one part and submit its answer to the website (needs --day and --part)
+  repl      Load the parsed model of a day and explore it with commands from stdin (needs --day)
+  help      Print this message
+
+Options:
+  -y, --year <YEAR>     Season to run (default is the latest)
+  -d, --day <DAY>       Day to run (1-25)
+  -p, --part <PART>     Part to run (1 or 2), runs both if omitted
+  -i, --input <PATH>    Input file, \"-\" reads from stdin
+  -a, --all             Run all days (default if no day is given)
+  -t, --test            Use the example input day_<DAY>_test.txt
+  -j, --jobs <N>        Run up to N days in parallel, 0 uses all CPUs (default 1, run and verify)
+  -v, --verbose         Trace to stderr, -vv adds parsing and solver summaries, -vvv every iteration
+      --timeout <SECS>  Stop a part which runs longer, it's reported as TIMEOUT (run and verify)
+      --max-memory <MB> Stop a part which allocates more, it's reported as OUT OF MEMORY (run and verify)
+      --no-cache        Solve every part, even if its answer is cached (run and verify)
+  -h, --help            Print this message
+
+Run options (need --day):
+      --animate <MS>    Animate a simulation in the terminal, waiting MS milliseconds per step
+      --frames <DIR>    Write every step of a simulation to a numbered file in DIR
+      --frame-format <FMT>
+                        Format of the frames: text or ppm (default text)
+
+Bench options:
+  -n, --runs <N>        Measured runs of every part (default 10)
+  -w, --warmup <N>      Unmeasured runs before measuring (default 1)
+  -f, --format <FMT>    Report format: text, json or csv (default text)
+  -o, --output <PATH>   Write the report to a file instead of stdout
+
+Verify options:
+      --answers <PATH>  Answers manifest (default <YEAR>/answers.toml or <YEAR>/answers_test.toml
+                        in the input directory)
+
+Submit options:
+      --ledger <PATH>   Ledger of submitted answers (default submissions.tsv in the input directory)
+
+Without --input, <YEAR>/day_<DAY>.txt is read from the directory in AOC_INPUT_DIR or from data/";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Verify(VerifyArgs),
+    Submit(SubmitArgs),
+    /// The day is always set
+    Repl(RunArgs),
+    Help,
+}
+
+impl Command {
+    /// Returns the options every command except help has
+    pub fn run_args(&self) -> Option<&RunArgs> {
+        match self {
+            Command::Run(args) => Some(args),
+            Command::Bench(args) => Some(&args.run),
+            Command::Verify(args) => Some(&args.run),
+            Command::Submit(args) => Some(&args.run),
+            Command::Repl(args) => Some(args),
+            Command::Help => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub year: u16,
+    /// Day to run, None runs all days
+    pub day: Option<usize>,
+    /// Part to run, None runs both parts
+    pub part: Option<u8>,
+    pub input: Option<PathBuf>,
+    pub test: bool,
+    /// Days run in parallel, 0 uses all CPUs
+    pub jobs: usize,
+    /// How the steps of a simulation are shown, None only prints the answers
+    pub watch: Option<Watch>,
+    /// Number of -v, 0 doesn't trace anything
+    pub verbose: u8,
+    /// Time and memory a part may use, unlimited by default
+    pub limits: Limits,
+    /// Whether parts which were solved before with the same input and code are taken from the cache
+    pub cache: bool,
+}
+
+/// Where the steps of a simulation go
+#[derive(Debug, PartialEq, Eq)]
+pub enum Watch {
+    Animate(Duration),
+    Frames(PathBuf, DumpFormat),
+}
+
+impl Default for RunArgs {
+    fn default() -> Self {
+        RunArgs {
+            year: latest_year(),
+            day: None,
+            part: None,
+            input: None,
+            test: false,
+            jobs: 1,
+            watch: None,
+            verbose: 0,
+            limits: Limits::default(),
+            cache: true,
+        }
+    }
+}
+
+impl RunArgs {
+    /// Returns the season of the year, which is validated by the cli
+    pub fn season(&self) -> &'static Season {
+        season(self.year).expect("years are validated by the cli")
+    }
+
+    /// Returns the days which should be run
+    pub fn days(&self) -> Vec<usize> {
+        match self.day {
+            Some(day) => vec![day],
+            None => self.season().days().map(|(day, _)| day).collect(),
+        }
+    }
+
+    /// Returns the parts which should be run
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    /// Returns the number of threads the days are run on
+    pub fn threads(&self) -> usize {
+        match self.jobs {
+            0 => pool::available_threads(),
+            jobs => jobs,
+        }
+    }
+
+    /// Returns where the inputs are read from
+    pub fn input_source(&self) -> InputSource {
+        InputSource::resolve(self.input.as_deref())
+    }
+
+    /// Returns the path of the cache of answers
+    pub fn cache_path(&self) -> PathBuf {
+        self.input_dir().join(CACHE_FILE)
+    }
+
+    /// Returns the directory with the inputs, files which belong to the inputs are stored there.
+    /// If the input is a single file, the default directory is used
+    pub fn input_dir(&self) -> PathBuf {
+        match self.input_source() {
+            InputSource::Dir(dir) => dir,
+            _ => PathBuf::from(DEFAULT_INPUT_DIR),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BenchArgs {
+    /// Which days and inputs are measured
+    pub run: RunArgs,
+    pub runs: usize,
+    pub warmup: usize,
+    pub format: Format,
+    /// File the report is written to, None prints it
+    pub output: Option<PathBuf>,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        BenchArgs { run: RunArgs::default(), runs: 10, warmup: 1, format: Format::Text, output: None }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct VerifyArgs {
+    /// Which days and inputs are verified
+    pub run: RunArgs,
+    /// Manifest with the expected answers, None uses the one next to the inputs
+    pub answers: Option<PathBuf>,
+}
+
+impl VerifyArgs {
+    /// Returns the path of the answers manifest
+    pub fn answers_path(&self) -> PathBuf {
+        match &self.answers {
+            Some(path) => path.clone(),
+            None => input::year_dir(&self.run.input_dir(), self.run.year).join(answers::file_name(self.run.test)),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SubmitArgs {
+    /// The day and part which are submitted, both are always set
+    pub run: RunArgs,
+    /// Ledger of the submitted answers, None uses the one next to the inputs
+    pub ledger: Option<PathBuf>,
+}
+
+impl SubmitArgs {
+    pub fn day(&self) -> usize {
+        self.run.day.expect("submit has a day")
+    }
+
+    pub fn part(&self) -> u8 {
+        self.run.part.expect("submit has a part")
+    }
+
+    /// Returns the path of the ledger
+    pub fn ledger_path(&self) -> PathBuf {
+        match &self.ledger {
+            Some(path) => path.clone(),
+            None => self.run.input_dir().join(LEDGER_FILE),
+        }
+    }
+}
+
+/// Parses the command line arguments, without the name of the binary
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+
+    let command = match args.peek().map(|arg| arg.as_str()) {
+        Some(command @ ("run" | "bench" | "verify" | "submit" | "repl")) => command.to_string(),
+        Some("help") => return Ok(Command::Help),
+        Some(arg) if !arg.starts_with('-') => return Err(format!("unknown command '{}'", arg)),
+        _ => "run".to_string(),
+    };
+    if args.peek() == Some(&command) {
+        args.next();
+    }
+    let bench = command == "bench";
+    let run = command == "run";
+    let verify = command == "verify";
+    let submit = command == "submit";
+    let repl = command == "repl";
+
+    let mut bench_args = BenchArgs::default();
+    let mut answers = None;
+    let mut ledger = None;
+    let (mut animate, mut frames, mut frame_format) = (None, None, None);
+    let run_args = &mut bench_args.run;
+    let mut all = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-y" | "--year" => run_args.year = parse_value(&arg, args.next())?,
+            "-d" | "--day" => run_args.day = Some(parse_value(&arg, args.next())?),
+            "-p" | "--part" => {
+                let part = parse_value::<u8>(&arg, args.next())?;
+                if part != 1 && part != 2 {
+                    return Err(format!("part has to be 1 or 2, got {}", part));
+                }
+                run_args.part = Some(part);
+            },
+            "-i" | "--input" => run_args.input = Some(parse_value::<PathBuf>(&arg, args.next())?),
+            "-a" | "--all" => all = true,
+            "-t" | "--test" => run_args.test = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            "-v" | "--verbose" => run_args.verbose += 1,
+            "-vv" => run_args.verbose += 2,
+            "-vvv" => run_args.verbose += 3,
+            "-j" | "--jobs" if run || verify => run_args.jobs = parse_value(&arg, args.next())?,
+            "--timeout" if run || verify => {
+                let secs = parse_value::<f64>(&arg, args.next())?;
+                let timeout = Duration::try_from_secs_f64(secs).ok().filter(|timeout| !timeout.is_zero());
+                run_args.limits.timeout = Some(timeout.ok_or(format!("--timeout has to be a positive number of seconds, got {}", secs))?);
+            },
+            "--max-memory" if run || verify => {
+                let megabytes = parse_value::<usize>(&arg, args.next())?;
+                if megabytes == 0 {
+                    return Err("--max-memory has to be at least 1".to_string());
+                }
+                run_args.limits.memory = Some(megabytes.saturating_mul(1 << 20));
+            },
+            "-n" | "--runs" if bench => {
+                bench_args.runs = parse_value(&arg, args.next())?;
+                if bench_args.runs == 0 {
+                    return Err("--runs has to be at least 1".to_string());
+                }
+            },
+            "-w" | "--warmup" if bench => bench_args.warmup = parse_value(&arg, args.next())?,
+            "-f" | "--format" if bench => bench_args.format = parse_value(&arg, args.next())?,
+            "-o" | "--output" if bench => bench_args.output = Some(parse_value(&arg, args.next())?),
+            "--animate" if run => animate = Some(Duration::from_millis(parse_value(&arg, args.next())?)),
+            "--frames" if run => frames = Some(parse_value::<PathBuf>(&arg, args.next())?),
+            "--frame-format" if run => frame_format = Some(parse_value::<DumpFormat>(&arg, args.next())?),
+            "--no-cache" if run || verify => run_args.cache = false,
+            "--answers" if verify => answers = Some(parse_value(&arg, args.next())?),
+            "--ledger" if submit => ledger = Some(parse_value(&arg, args.next())?),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    let Some(season) = season(run_args.year) else {
+        return Err(format!("there are no solutions for {}", run_args.year));
+    };
+    if let Some(day) = run_args.day.filter(|day| season.solver(*day).is_none()) {
+        return Err(format!("day has to be between 1 and {}, got {}", season.solvers.len(), day));
+    }
+    if all && run_args.day.is_some() {
+        return Err("--all can't be used together with --day".to_string());
+    }
+    if run_args.input.is_some() && run_args.day.is_none() {
+        return Err("--input needs a --day".to_string());
+    }
+    if run_args.input.is_some() && run_args.test {
+        return Err("--input can't be used together with --test".to_string());
+    }
+
+    run_args.watch = match (animate, frames) {
+        (Some(_), Some(_)) => return Err("--animate can't be used together with --frames".to_string()),
+        (Some(_), None) | (None, Some(_)) if run_args.day.is_none() => {
+            return Err("a simulation can only be watched for a single --day".to_string())
+        },
+        (Some(delay), None) if frame_format.is_none() => Some(Watch::Animate(delay)),
+        (None, Some(dir)) => Some(Watch::Frames(dir, frame_format.unwrap_or_default())),
+        (None, None) if frame_format.is_none() => None,
+        _ => return Err("--frame-format needs --frames".to_string()),
+    };
+
+    if submit {
+        if run_args.day.is_none() || run_args.part.is_none() {
+            return Err("submit needs a --day and a --part".to_string());
+        }
+        if run_args.test {
+            return Err("the answer of an example can't be submitted".to_string());
+        }
+    }
+
+    if repl {
+        match run_args.day {
+            None => return Err("repl needs a --day".to_string()),
+            Some(day) if !season.models.contains(&day) => {
+                return Err(format!("day {} has no model to explore, try day {:?} of {}", day, season.models, season.year))
+            },
+            Some(_) => (),
+        }
+        if run_args.input_source() == InputSource::Stdin {
+            return Err("repl reads its commands from stdin, the input has to be a file".to_string());
+        }
+    }
+
+    if bench {
+        Ok(Command::Bench(bench_args))
+    } else if submit {
+        Ok(Command::Submit(SubmitArgs { run: bench_args.run, ledger }))
+    } else if verify {
+        Ok(Command::Verify(VerifyArgs { run: bench_args.run, answers }))
+    } else if repl {
+        Ok(Command::Repl(bench_args.run))
+    } else {
+        Ok(Command::Run(bench_args.run))
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(arg: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or(format!("missing value for {}", arg))?;
+    value.parse().map_err(|_| format!("invalid value '{}' for {}", value, arg))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use advent_of_code::{bench::Format, input::InputSource, limit::Limits, observe::DumpFormat};
+
+    use super::{parse_args, BenchArgs, Command, RunArgs, SubmitArgs, VerifyArgs, Watch};
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args_defaults() {
+        assert_eq!(parse(""), Ok(Command::Run(RunArgs::default())));
+        assert_eq!(parse("run"), Ok(Command::Run(RunArgs::default())));
+        assert_eq!(parse("run --all"), Ok(Command::Run(RunArgs::default())));
+        assert_eq!(parse("help"), Ok(Command::Help));
+        assert_eq!(parse("run -h"), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_parse_args_run() {
+        let expected = RunArgs { day: Some(15), part: Some(2), input: Some(PathBuf::from("input.txt")), ..Default::default() };
+        assert_eq!(parse("run --day 15 --part 2 --input input.txt --year 2021"), Ok(Command::Run(expected)));
+
+        let expected = RunArgs { day: Some(3), test: true, ..Default::default() };
+        assert_eq!(parse("-t -d 3"), Ok(Command::Run(expected)));
+
+        let expected = RunArgs { jobs: 4, ..Default::default() };
+        assert_eq!(parse("run --all -j 4"), Ok(Command::Run(expected)));
+        let expected = VerifyArgs { run: RunArgs { jobs: 0, ..Default::default() }, answers: None };
+        assert_eq!(parse("verify --jobs 0"), Ok(Command::Verify(expected)));
+
+        let expected = RunArgs { verbose: 3, ..Default::default() };
+        assert_eq!(parse("-v -vv"), Ok(Command::Run(expected)));
+
+        let expected = RunArgs { cache: false, ..Default::default() };
+        assert_eq!(parse("run --no-cache"), Ok(Command::Run(expected)));
+        let expected = VerifyArgs { run: RunArgs { cache: false, ..Default::default() }, answers: None };
+        assert_eq!(parse("verify --no-cache"), Ok(Command::Verify(expected)));
+        assert!(parse("bench --no-cache").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_limits() {
+        let limits = Limits { timeout: Some(Duration::from_millis(2500)), memory: Some(64 << 20) };
+        let expected = RunArgs { limits, ..Default::default() };
+        assert_eq!(parse("run --timeout 2.5 --max-memory 64"), Ok(Command::Run(expected)));
+        let expected = VerifyArgs { run: RunArgs { limits: Limits { timeout: Some(Duration::from_secs(10)), memory: None }, ..Default::default() }, answers: None };
+        assert_eq!(parse("verify --timeout 10"), Ok(Command::Verify(expected)));
+
+        assert!(parse("run --timeout 0").is_err());
+        assert!(parse("run --timeout -1").is_err());
+        assert!(parse("run --max-memory 0").is_err());
+        assert!(parse("bench --timeout 10").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_watch() {
+        let expected = RunArgs { day: Some(25), watch: Some(Watch::Animate(Duration::from_millis(50))), ..Default::default() };
+        assert_eq!(parse("-d 25 --animate 50"), Ok(Command::Run(expected)));
+
+        let watch = Watch::Frames(PathBuf::from("frames"), DumpFormat::Ppm { scale: 4 });
+        let expected = RunArgs { day: Some(20), part: Some(2), watch: Some(watch), ..Default::default() };
+        assert_eq!(parse("run -d 20 -p 2 --frames frames --frame-format ppm"), Ok(Command::Run(expected)));
+
+        assert!(parse("--animate 50").is_err());
+        assert!(parse("-d 25 --animate 50 --frames frames").is_err());
+        assert!(parse("-d 25 --animate 50 --frame-format ppm").is_err());
+        assert!(parse("-d 25 --frames frames --frame-format gif").is_err());
+        assert!(parse("verify -d 25 --animate 50").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_invalid() {
+        assert!(parse("walk").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --day 26").is_err());
+        assert!(parse("run --day two").is_err());
+        assert!(parse("run --day 0").is_err());
+        assert!(parse("run --year 2015").is_err());
+        assert!(parse("run --part 3").is_err());
+        assert!(parse("run --all --day 1").is_err());
+        assert!(parse("run --input input.txt").is_err());
+        assert!(parse("run --day 1 --test --input input.txt").is_err());
+        assert!(parse("run --quiet").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_bench() {
+        assert_eq!(parse("bench"), Ok(Command::Bench(BenchArgs::default())));
+
+        let expected = BenchArgs {
+            run: RunArgs { day: Some(4), part: Some(1), ..Default::default() },
+            runs: 50,
+            warmup: 0,
+            format: Format::Csv,
+            output: Some(PathBuf::from("bench.csv")),
+        };
+        assert_eq!(parse("bench -d 4 -p 1 --runs 50 --warmup 0 -f csv -o bench.csv"), Ok(Command::Bench(expected)));
+
+        assert!(parse("bench --runs 0").is_err());
+        assert!(parse("bench --format xml").is_err());
+        assert!(parse("run --runs 5").is_err());
+        assert!(parse("bench --jobs 4").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_verify() {
+        assert_eq!(parse("verify"), Ok(Command::Verify(VerifyArgs::default())));
+
+        let expected = VerifyArgs {
+            run: RunArgs { day: Some(23), test: true, ..Default::default() },
+            answers: Some(PathBuf::from("answers.toml")),
+        };
+        assert_eq!(parse("verify -d 23 -t --answers answers.toml"), Ok(Command::Verify(expected)));
+
+        assert!(parse("verify --runs 5").is_err());
+        assert!(parse("run --answers answers.toml").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_submit() {
+        let expected = SubmitArgs {
+            run: RunArgs { day: Some(7), part: Some(2), ..Default::default() },
+            ledger: Some(PathBuf::from("ledger.tsv")),
+        };
+        assert_eq!(parse("submit -d 7 -p 2 --ledger ledger.tsv"), Ok(Command::Submit(expected)));
+
+        assert!(parse("submit -d 7").is_err());
+        assert!(parse("submit -p 1").is_err());
+        assert!(parse("submit -d 7 -p 1 --test").is_err());
+        assert!(parse("submit -d 7 -p 1 --timeout 10").is_err());
+        assert!(parse("submit -d 7 -p 1 -j 2").is_err());
+        assert!(parse("run --ledger ledger.tsv").is_err());
+    }
+
+    #[test]
+    fn test_parse_args_repl() {
+        let expected = RunArgs { day: Some(25), test: true, ..Default::default() };
+        assert_eq!(parse("repl -d 25 -t"), Ok(Command::Repl(expected)));
+
+        assert!(parse("repl").is_err());
+        assert!(parse("repl -d 3").is_err());
+        assert!(parse("repl -d 25 --input -").is_err());
+        assert!(parse("repl -d 25 --animate 50").is_err());
+        assert!(parse("repl -d 25 --max-memory 64").is_err());
+        assert!(parse("repl -d 25 --jobs 2").is_err());
+    }
+
+    #[test]
+    fn test_answers_path() {
+        let args = VerifyArgs { answers: Some(PathBuf::from("mine.toml")), ..Default::default() };
+        assert_eq!(args.answers_path(), PathBuf::from("mine.toml"));
+
+        let args = VerifyArgs {
+            run: RunArgs { day: Some(1), input: Some(PathBuf::from("input.txt")), ..Default::default() },
+            answers: None,
+        };
+        assert_eq!(args.answers_path(), PathBuf::from("data/2021/answers.toml"));
+    }
+
+    #[test]
+    fn test_input_source() {
+        let args = RunArgs { day: Some(7), input: Some(PathBuf::from("-")), ..Default::default() };
+        assert_eq!(args.input_source(), InputSource::Stdin);
+
+        let args = RunArgs { day: Some(7), input: Some(PathBuf::from("input.txt")), ..Default::default() };
+        assert_eq!(args.input_source(), InputSource::File(PathBuf::from("input.txt")));
+    }
+}
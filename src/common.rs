@@ -0,0 +1,19 @@
+//! Helpers which every season uses.
+
+use std::{fs::File, io::Read};
+
+use crate::{Error, Result};
+
+pub fn parse_lines(file_name: &str) -> Result<Vec<String>> {
+    let mut buffer = String::new();
+    File::open(file_name)
+        .and_then(|mut file| file.read_to_string(&mut buffer))
+        .map_err(|e| Error::from(e).in_file(file_name))?;
+
+    Ok(lines(&buffer))
+}
+
+/// Splits puzzle input into lines, a trailing newline at the end of the input is ignored
+pub fn lines(input: &str) -> Vec<String> {
+    input.trim_end_matches(['\n', '\r']).lines().map(|line| line.to_string()).collect()
+}
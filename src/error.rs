@@ -0,0 +1,140 @@
+use std::{fmt::Display, io, str::FromStr};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything that can go wrong while reading or solving a puzzle input.
+///
+/// Parsers only see the content of the input, so `file` is `None` until the caller
+/// adds it with [`Error::in_file`].
+#[derive(Debug)]
+pub enum Error {
+    /// The input couldn't be read
+    Io { file: Option<String>, error: io::Error },
+    /// The input has an unexpected format at a position, line and column start at 1
+    Parse { file: Option<String>, line: usize, column: usize, reason: String },
+    /// The input is malformed as a whole, like a missing section
+    Invalid { file: Option<String>, reason: String },
+    /// A request to the Advent of Code website failed
+    Http { url: String, reason: String },
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, reason: impl Into<String>) -> Self {
+        Error::Parse { file: None, line, column, reason: reason.into() }
+    }
+
+    /// Creates an error at the position of `part`, which has to be a slice of `input`
+    pub fn at(input: &str, part: &str, reason: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .expect("part has to be a slice of input");
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Error::parse(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            reason
+        )
+    }
+
+    /// Creates an error at the position of `part` in the line with index `line_index`.
+    /// `part` has to be a slice of `line`
+    pub fn in_line(line_index: usize, line: &str, part: &str, reason: impl Into<String>) -> Self {
+        match Error::at(line, part, reason) {
+            Error::Parse { file, column, reason, .. } => Error::Parse { file, line: line_index + 1, column, reason },
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn invalid(reason: impl Into<String>) -> Self {
+        Error::Invalid { file: None, reason: reason.into() }
+    }
+
+    pub fn http(url: impl Into<String>, reason: impl Into<String>) -> Self {
+        Error::Http { url: url.into(), reason: reason.into() }
+    }
+
+    /// Adds the name of the file the error occurred in
+    pub fn in_file(mut self, name: impl Into<String>) -> Self {
+        match &mut self {
+            Error::Io { file, .. } | Error::Parse { file, .. } | Error::Invalid { file, .. } => {
+                *file = Some(name.into())
+            },
+            Error::Http { .. } => (),
+        }
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { file: Some(file), error } => write!(f, "unable to read {}: {}", file, error),
+            Error::Io { file: None, error } => write!(f, "unable to read input: {}", error),
+            Error::Parse { file: Some(file), line, column, reason } => write!(f, "{}:{}:{}: {}", file, line, column, reason),
+            Error::Parse { file: None, line, column, reason } => write!(f, "line {}, column {}: {}", line, column, reason),
+            Error::Invalid { file: Some(file), reason } => write!(f, "{}: {}", file, reason),
+            Error::Invalid { file: None, reason } => write!(f, "invalid input: {}", reason),
+            Error::Http { url, reason } => write!(f, "request to {} failed: {}", url, reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io { file: None, error }
+    }
+}
+
+/// Parses `part` of `input` into a number or anything else implementing `FromStr`,
+/// the error points to the position of `part` in `input`
+pub fn parse_at<T: FromStr>(input: &str, part: &str) -> Result<T> {
+    part.parse().map_err(|_| Error::at(input, part, format!("can't parse '{}'", part)))
+}
+
+/// Like [`parse_at`], for a `part` of the line with index `line_index`
+pub fn parse_in_line<T: FromStr>(line_index: usize, line: &str, part: &str) -> Result<T> {
+    part.parse().map_err(|_| Error::in_line(line_index, line, part, format!("can't parse '{}'", part)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_at, parse_in_line, Error};
+
+    #[test]
+    fn test_error_at() {
+        let input = "1,2\n3,x,5";
+        let x = &input[6..7];
+        let error = Error::at(input, x, "not a number");
+        assert_eq!(error.to_string(), "line 2, column 3: not a number");
+        assert_eq!(error.in_file("data/2021/day_1.txt").to_string(), "data/2021/day_1.txt:2:3: not a number");
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let input = "12 ab";
+        assert_eq!(parse_at::<u8>(input, &input[..2]).unwrap(), 12);
+        assert_eq!(parse_at::<u8>(input, &input[3..]).unwrap_err().to_string(), "line 1, column 4: can't parse 'ab'");
+        assert_eq!(
+            parse_in_line::<u8>(4, input, &input[3..]).unwrap_err().to_string(),
+            "line 5, column 4: can't parse 'ab'"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Error::invalid("missing folds").to_string(), "invalid input: missing folds");
+        assert_eq!(Error::invalid("missing folds").in_file("in.txt").to_string(), "in.txt: missing folds");
+    }
+}
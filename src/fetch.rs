@@ -0,0 +1,157 @@
+//! Downloads puzzle inputs from the Advent of Code website and caches them in the input directory.
+//!
+//! Downloading needs the session cookie of a logged in user in `AOC_SESSION`.
+
+use std::{env, fs, path::Path, sync::{Mutex, OnceLock}, thread, time::{Duration, Instant}};
+
+use crate::{http::{self, Request, Transport}, input, Error, Result};
+
+/// Environment variable holding the session cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable to use another server than the website, like a local one
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Sent with every request, as asked for by the website
+pub const USER_AGENT: &str = concat!("github.com/paddison/advent_of_code_21 v", env!("CARGO_PKG_VERSION"));
+/// Minimum time between two requests
+pub const MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Makes sure that requests are at least `interval` apart, also if they are sent from several threads
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    last: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration) -> Self {
+        RateLimiter { interval, last: Mutex::new(None) }
+    }
+
+    /// Blocks until the next request may be sent
+    pub fn wait(&self) {
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(elapsed) = last.map(|last| last.elapsed()) {
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+/// Sends authenticated requests to the website
+pub struct Client {
+    transport: Box<dyn Transport>,
+    base_url: String,
+    session: String,
+    limiter: RateLimiter,
+}
+
+impl Client {
+    pub fn new(transport: Box<dyn Transport>, base_url: impl Into<String>, session: impl Into<String>, interval: Duration) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Client { transport, base_url, session: session.into(), limiter: RateLimiter::new(interval) }
+    }
+
+    /// Returns the client configured by the environment, None if `AOC_SESSION` isn't set.
+    /// The client is shared, so the rate limit holds for the whole process
+    pub fn from_env() -> Option<&'static Client> {
+        static CLIENT: OnceLock<Option<Client>> = OnceLock::new();
+        CLIENT.get_or_init(|| {
+            let session = env::var(SESSION_VAR).ok().filter(|session| !session.trim().is_empty())?;
+            let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+            Some(Client::new(http::transport_for(&base_url), base_url, session.trim(), MIN_INTERVAL))
+        }).as_ref()
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Sends a request with the session cookie and the user agent, waiting for the rate limit first
+    pub fn send(&self, request: Request) -> Result<http::Response> {
+        self.limiter.wait();
+        let request = request
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT);
+        self.transport.send(&request)
+    }
+
+    /// Downloads the input of a day
+    pub fn input(&self, year: u16, day: usize) -> Result<String> {
+        let url = self.url(&format!("/{}/day/{}/input", year, day));
+        let response = self.send(Request::get(&url))?;
+        match response.status {
+            200 => Ok(response.body),
+            400 | 500 => Err(Error::http(url, format!("status {}, is {} valid and not expired?", response.status, SESSION_VAR))),
+            404 => Err(Error::http(url, "status 404, the puzzle isn't unlocked yet")),
+            status => Err(Error::http(url, format!("status {}", status))),
+        }
+    }
+
+    /// Returns the input of a day from `dir`, the directory of its year.
+    /// It is downloaded and saved there if it's missing, a cached input is never downloaded again
+    pub fn cached_input(&self, dir: &Path, year: u16, day: usize) -> Result<String> {
+        let path = dir.join(input::file_name(day, false));
+        if let Ok(input) = fs::read_to_string(&path) {
+            return Ok(input);
+        }
+
+        let input = self.input(year, day)?;
+        // written to a temporary file first, so a crash never leaves half an input in the cache
+        let temporary = path.with_extension("txt.part");
+        fs::create_dir_all(dir)
+            .and_then(|_| fs::write(&temporary, &input))
+            .and_then(|_| fs::rename(&temporary, &path))
+            .map_err(|e| Error::from(e).in_file(path.display().to_string()))?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, time::{Duration, Instant}};
+
+    use crate::http::{stub, PlainHttp};
+
+    use super::{Client, RateLimiter, USER_AGENT};
+
+    #[test]
+    fn test_cached_input() {
+        let dir = env::temp_dir().join(format!("aoc_fetch_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let (url, server) = stub::serve(vec![(200, "1,2,3\n")]);
+        let client = Client::new(Box::new(PlainHttp::default()), url, "secret", Duration::ZERO);
+
+        assert_eq!(client.cached_input(&dir, 2021, 6).unwrap(), "1,2,3\n");
+        // the stub only answers once, so this has to be read from the cache
+        assert_eq!(client.cached_input(&dir, 2021, 6).unwrap(), "1,2,3\n");
+        assert_eq!(fs::read_to_string(dir.join("day_6.txt")).unwrap(), "1,2,3\n");
+
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("GET /2021/day/6/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=secret\r\n"));
+        assert!(request.contains(&format!("\r\nUser-Agent: {}\r\n", USER_AGENT)));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (url, server) = stub::serve(vec![(404, "Not Found"), (400, "Bad Request")]);
+        let client = Client::new(Box::new(PlainHttp::default()), url, "secret", Duration::ZERO);
+        assert!(client.input(2021, 25).unwrap_err().to_string().ends_with("the puzzle isn't unlocked yet"));
+        assert!(client.input(2021, 1).unwrap_err().to_string().contains("AOC_SESSION"));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let limiter = RateLimiter::new(Duration::from_millis(50));
+        let start = Instant::now();
+        limiter.wait();
+        limiter.wait();
+        limiter.wait();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,326 @@
+//! Points, rotations and boxes in 3D space, with integer coordinates.
+
+use std::{fmt::Display, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}};
+
+/// A point or the difference between two points
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// Sum of the distances along the axes
+    pub fn manhattan(&self, other: &Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Returns the coordinates in the order x, y, z
+    pub fn to_array(self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Applies `f` to each coordinate
+    pub fn map(self, mut f: impl FnMut(i64) -> i64) -> Point3 {
+        Point3::new(f(self.x), f(self.y), f(self.z))
+    }
+
+    /// Combines the coordinates of two points with `f`
+    pub fn zip(self, other: Point3, mut f: impl FnMut(i64, i64) -> i64) -> Point3 {
+        Point3::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
+    }
+}
+
+impl From<[i64; 3]> for Point3 {
+    fn from([x, y, z]: [i64; 3]) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+/// Printed like in the inputs, `x,y,z`
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        self.zip(other, |a, b| a + b)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        self.zip(other, |a, b| a - b)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, other: Point3) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, other: Point3) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        self.map(|a| -a)
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, factor: i64) -> Point3 {
+        self.map(|a| a * factor)
+    }
+}
+
+/// One of the 24 rotations which turn the axes onto each other, mirror images aren't rotations.
+/// Axis `i` of a rotated point is axis `axes[i]` of the point, multiplied by `signs[i]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotation {
+    axes: [usize; 3],
+    signs: [i64; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation { axes: [0, 1, 2], signs: [1, 1, 1] };
+
+    /// Returns all 24 rotations, starting with the identity
+    pub fn all() -> impl Iterator<Item = Rotation> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [[0, 1, 2], [1, 2, 0], [2, 0, 1], [0, 2, 1], [2, 1, 0], [1, 0, 2]];
+        PERMUTATIONS.into_iter().enumerate().flat_map(|(i, axes)| {
+            // the last three permutations swap two axes, which mirrors unless an odd number of signs is flipped
+            let parity = if i < 3 { 1 } else { -1 };
+            (0..8).map(move |bits| [bits & 1, (bits >> 1) & 1, (bits >> 2) & 1].map(|bit| 1 - 2 * bit as i64))
+                .filter(move |signs| signs.iter().product::<i64>() == parity)
+                .map(move |signs| Rotation { axes, signs })
+        })
+    }
+
+    pub fn apply(&self, point: Point3) -> Point3 {
+        let coords = point.to_array();
+        Point3::from([0, 1, 2].map(|i| self.signs[i] * coords[self.axes[i]]))
+    }
+
+    /// Returns the rotation which applies `first` and then `self`
+    pub fn after(&self, first: &Rotation) -> Rotation {
+        Rotation {
+            axes: [0, 1, 2].map(|i| first.axes[self.axes[i]]),
+            signs: [0, 1, 2].map(|i| self.signs[i] * first.signs[self.axes[i]]),
+        }
+    }
+
+    /// Returns the rotation which turns the points back
+    pub fn inverse(&self) -> Rotation {
+        let mut inverse = Rotation::IDENTITY;
+        for i in 0..3 {
+            inverse.axes[self.axes[i]] = i;
+            inverse.signs[self.axes[i]] = self.signs[i];
+        }
+        inverse
+    }
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Rotation::IDENTITY
+    }
+}
+
+impl Mul<Point3> for Rotation {
+    type Output = Point3;
+
+    fn mul(self, point: Point3) -> Point3 {
+        self.apply(point)
+    }
+}
+
+/// A box whose sides are parallel to the axes, `min` and `max` are both part of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Box3 {
+    min: Point3,
+    max: Point3,
+}
+
+impl Box3 {
+    /// Returns the box between two corners, None if `min` is larger than `max` on any axis
+    pub fn new(min: Point3, max: Point3) -> Option<Self> {
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Box3 { min, max })
+    }
+
+    /// Returns the box between any two opposite corners
+    pub fn from_corners(a: Point3, b: Point3) -> Self {
+        Box3 { min: a.zip(b, i64::min), max: a.zip(b, i64::max) }
+    }
+
+    pub fn min(&self) -> Point3 {
+        self.min
+    }
+
+    pub fn max(&self) -> Point3 {
+        self.max
+    }
+
+    /// Number of integer points in the box
+    pub fn volume(&self) -> i64 {
+        let size = self.max - self.min + Point3::new(1, 1, 1);
+        size.x * size.y * size.z
+    }
+
+    pub fn contains(&self, point: Point3) -> bool {
+        Box3::new(self.min, point).is_some() && Box3::new(point, self.max).is_some()
+    }
+
+    /// Returns the part which is in both boxes, None if they don't overlap
+    pub fn intersection(&self, other: &Box3) -> Option<Box3> {
+        Box3::new(self.min.zip(other.min, i64::max), self.max.zip(other.max, i64::min))
+    }
+
+    /// Returns the parts of this box which aren't in `other`, as up to 6 boxes which don't overlap
+    pub fn subtract(&self, other: &Box3) -> Vec<Box3> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        // slices are cut off along one axis after the other, what's left shrinks to the intersection
+        let mut parts = vec![];
+        let mut rest = *self;
+        for axis in 0..3 {
+            let (min, max) = (rest.min.to_array(), rest.max.to_array());
+            let (cut_min, cut_max) = (cut.min.to_array(), cut.max.to_array());
+            if min[axis] < cut_min[axis] {
+                let mut below = max;
+                below[axis] = cut_min[axis] - 1;
+                parts.push(Box3 { min: rest.min, max: below.into() });
+            }
+            if max[axis] > cut_max[axis] {
+                let mut above = min;
+                above[axis] = cut_max[axis] + 1;
+                parts.push(Box3 { min: above.into(), max: rest.max });
+            }
+            let (mut min, mut max) = (min, max);
+            min[axis] = cut_min[axis];
+            max[axis] = cut_max[axis];
+            rest = Box3 { min: min.into(), max: max.into() };
+        }
+
+        parts
+    }
+}
+
+/// Inclusive `[start, end]` of the x, y and z axis, the ends may be swapped
+impl From<[[i64; 2]; 3]> for Box3 {
+    fn from([x, y, z]: [[i64; 2]; 3]) -> Self {
+        Box3::from_corners(Point3::new(x[0], y[0], z[0]), Point3::new(x[1], y[1], z[1]))
+    }
+}
+
+impl Display for Box3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (min, max) = (self.min, self.max);
+        write!(f, "({}-{}, {}-{}, {}-{})", min.x, max.x, min.y, max.y, min.z, max.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{Box3, Point3, Rotation};
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point3::new(1, -2, 3);
+        let b = Point3::new(-4, 5, 6);
+        assert_eq!(a + b, Point3::new(-3, 3, 9));
+        assert_eq!(a - b, Point3::new(5, -7, -3));
+        assert_eq!(-a * 2, Point3::new(-2, 4, -6));
+        assert_eq!(a.manhattan(&b), 5 + 7 + 3);
+        assert_eq!(a.to_string(), "1,-2,3");
+    }
+
+    #[test]
+    fn test_all_rotations() {
+        let rotations: Vec<Rotation> = Rotation::all().collect();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+
+        let point = Point3::new(1, 2, 3);
+        let images: HashSet<Point3> = rotations.iter().map(|r| r.apply(point)).collect();
+        assert_eq!(images.len(), 24);
+        // a mirror image isn't a rotation
+        assert!(!images.contains(&Point3::new(-1, 2, 3)));
+        assert!(images.contains(&Point3::new(-1, -2, 3)));
+        assert!(images.contains(&Point3::new(2, -1, 3)));
+    }
+
+    #[test]
+    fn test_compose_and_invert() {
+        let point = Point3::new(1, 2, 3);
+        let rotations: Vec<Rotation> = Rotation::all().collect();
+        for a in &rotations {
+            assert_eq!(a.inverse().apply(a.apply(point)), point);
+            assert_eq!(a.after(&a.inverse()), Rotation::IDENTITY);
+            for b in &rotations {
+                let composed = a.after(b);
+                assert_eq!(composed * point, *a * (*b * point));
+                assert!(rotations.contains(&composed));
+            }
+        }
+    }
+
+    #[test]
+    fn test_box_volume() {
+        let a = Box3::from([[10, 12], [10, 12], [10, 12]]);
+        let b = Box3::from([[13, 11], [11, 13], [11, 13]]);
+        assert_eq!(a.volume(), 27);
+        assert_eq!(a.intersection(&b).map(|c| c.volume()), Some(8));
+        assert!(a.intersection(&Box3::from([[20, 21], [0, 0], [0, 0]])).is_none());
+        assert!(a.contains(Point3::new(12, 10, 11)));
+        assert!(!a.contains(Point3::new(13, 10, 11)));
+        assert!(Box3::new(Point3::new(1, 0, 0), Point3::ORIGIN).is_none());
+        assert_eq!(a.to_string(), "(10-12, 10-12, 10-12)");
+    }
+
+    #[test]
+    fn test_box_subtract() {
+        let a = Box3::from([[0, 9], [0, 9], [0, 9]]);
+        let hole = Box3::from([[3, 5], [3, 5], [3, 5]]);
+        let parts = a.subtract(&hole);
+        assert_eq!(parts.len(), 6);
+        assert_eq!(parts.iter().map(Box3::volume).sum::<i64>(), 1000 - 27);
+        for (i, part) in parts.iter().enumerate() {
+            assert!(part.intersection(&hole).is_none());
+            assert!(parts[i + 1..].iter().all(|other| part.intersection(other).is_none()));
+        }
+
+        let corner = Box3::from([[-5, 0], [-5, 0], [-5, 0]]);
+        assert_eq!(a.subtract(&corner).iter().map(Box3::volume).sum::<i64>(), 999);
+        assert_eq!(a.subtract(&Box3::from([[20, 21], [0, 0], [0, 0]])), vec![a]);
+        assert!(a.subtract(&a).is_empty());
+    }
+}
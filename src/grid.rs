@@ -0,0 +1,328 @@
+//! A rectangular grid of cells, for the days whose input is a picture made of characters.
+//!
+//! Positions are `(x, y)`, where `x` is the column and `y` the row, like in [`Frame`].
+//! Cells are stored row by row.
+
+use std::{fmt::Display, ops::{Index, IndexMut}};
+
+use crate::{observe::Frame, Error, Result};
+
+/// Steps to the 4 direct neighbours, clockwise starting to the right
+const STEPS_4: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+/// Steps to the 8 neighbours including the diagonal ones, row by row
+const STEPS_8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// A cell which is written as a single character
+pub trait Tile: Sized {
+    /// What a cell is called in error messages, like `digit`
+    const NAME: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+/// Digits, larger numbers are printed as `?`
+impl Tile for u8 {
+    const NAME: &'static str = "digit";
+
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|d| d as u8)
+    }
+
+    fn to_char(&self) -> char {
+        char::from_digit(*self as u32, 10).unwrap_or('?')
+    }
+}
+
+/// Pixels, `#` is true and `.` false
+impl Tile for bool {
+    const NAME: &'static str = "pixel";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row. Returns None if they don't fill whole rows
+    pub fn new(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+        let height = cells.len() / width;
+        Some(Grid { cells, width, height })
+    }
+
+    /// Creates a grid where every cell is `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { cells: vec![value; width * height], width, height }
+    }
+
+    /// Creates a grid with the value `cell(x, y)` at every position
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| cell(x, y)).collect();
+        Grid { cells, width, height }
+    }
+
+    /// Parses lines of characters like `#..#.`, all lines have to have the same length.
+    /// `part` is the section of `input` with the grid, like for the functions in [`crate::parse`]
+    pub fn parse(input: &str, part: &str) -> Result<Self>
+    where
+        T: Tile,
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        for line in part.lines() {
+            let expected = *width.get_or_insert(line.chars().count());
+            let mut len = 0;
+            for (pos, c) in line.char_indices() {
+                let cell = T::from_char(c).ok_or_else(|| {
+                    Error::at(input, &line[pos..pos + c.len_utf8()], format!("'{}' is not a {}", c, T::NAME))
+                })?;
+                cells.push(cell);
+                len += 1;
+            }
+            if len != expected {
+                return Err(Error::at(input, line, format!("expected {} {}s", expected, T::NAME)));
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid::new(width, cells).expect("all rows have the same length")),
+            _ => Err(Error::invalid(format!("expected a grid of {}s", T::NAME))),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the cell at (x, y), None if it's out of bounds
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Returns the cell at (x, y), positions outside of the grid continue on the opposite edge
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        &self[self.wrap(x, y)]
+    }
+
+    /// Moves a position outside of the grid onto it, as if the grid repeated in every direction
+    pub fn wrap(&self, x: isize, y: isize) -> (usize, usize) {
+        (x.rem_euclid(self.width as isize) as usize, y.rem_euclid(self.height as isize) as usize)
+    }
+
+    /// Returns the position `(dx, dy)` away from `(x, y)`, None if it's out of bounds
+    pub fn offset(&self, pos: (usize, usize), step: (isize, isize)) -> Option<(usize, usize)> {
+        offset(self.width, self.height, pos, step)
+    }
+
+    /// Returns the positions of the up to 4 direct neighbours of (x, y)
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        STEPS_4.into_iter().filter_map(move |step| offset(width, height, (x, y), step))
+    }
+
+    /// Returns the positions of the up to 8 neighbours of (x, y), including the diagonal ones
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        STEPS_8.into_iter().filter_map(move |step| offset(width, height, (x, y), step))
+    }
+
+    /// Returns all positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Returns the cells row by row
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Returns the cells of row `y`, panics if it's out of bounds
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} is out of bounds", y);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Returns the cells of column `x` from top to bottom, panics if it's out of bounds
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Creates a grid of the same size with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+}
+
+fn offset(width: usize, height: usize, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+    let x = x.checked_add_signed(dx)?;
+    let y = y.checked_add_signed(dy)?;
+    (x < width && y < height).then_some((x, y))
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| panic!("({}, {}) is out of bounds", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).unwrap_or_else(|| panic!("({}, {}) is out of bounds", x, y))
+    }
+}
+
+/// Prints the grid like it's parsed, one line per row
+impl<T: Tile> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Tile> Frame for Grid<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn cell(&self, x: usize, y: usize) -> char {
+        self[(x, y)].to_char()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    fn create_grid() -> Grid<u8> {
+        let input = "219\n398\n";
+        Grid::parse(input, input).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = create_grid();
+        assert_eq!(grid, Grid::new(3, vec![2, 1, 9, 3, 9, 8]).unwrap());
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), "219\n398");
+
+        let input = "219\n3x8";
+        assert_eq!(Grid::<u8>::parse(input, input).unwrap_err().to_string(), "line 2, column 2: 'x' is not a digit");
+        let input = "219\n39";
+        assert_eq!(Grid::<u8>::parse(input, input).unwrap_err().to_string(), "line 2, column 1: expected 3 digits");
+        assert!(Grid::<u8>::parse("", "").is_err());
+        assert!(Grid::<bool>::parse("\n", "\n").is_err());
+
+        let input = "#.\n.#";
+        assert_eq!(Grid::<bool>::parse(input, input).unwrap(), Grid::from_fn(2, 2, |x, y| x == y));
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = create_grid();
+        assert_eq!(grid.get(2, 0), Some(&9));
+        assert_eq!(grid[(0, 1)], 3);
+        assert!(grid.get(3, 0).is_none());
+        assert!(grid.get(0, 2).is_none());
+        assert_eq!(grid.offset((0, 0), (1, 1)), Some((1, 1)));
+        assert!(grid.offset((0, 0), (-1, 0)).is_none());
+        assert!(grid.offset((2, 1), (0, 1)).is_none());
+    }
+
+    #[test]
+    fn test_get_wrapping() {
+        let grid = create_grid();
+        assert_eq!(grid.get_wrapping(3, 0), &2);
+        assert_eq!(grid.get_wrapping(-1, -1), &8);
+        assert_eq!(grid.wrap(7, 5), (1, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours8(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours8(2, 1).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = create_grid();
+        assert_eq!(grid.row(1), &[3, 9, 8]);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[2, 1, 9], &[3, 9, 8]]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![9, 8]);
+        assert_eq!(grid.positions().nth(4), Some((1, 1)));
+        assert_eq!(grid.map(|n| *n as usize * 2).iter().sum::<usize>(), 64);
+    }
+}
@@ -0,0 +1,268 @@
+//! A small HTTP layer for talking to the Advent of Code website.
+//!
+//! Requests go through the [`Transport`] trait, so the clients using it can be tested
+//! against a local server or without a network at all.
+
+use std::{io::{Read, Write}, net::TcpStream, process::{Command, Stdio}, time::Duration};
+
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Method {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl Request {
+    pub fn get(url: impl Into<String>) -> Self {
+        Request { method: Method::Get, url: url.into(), headers: vec![], body: None }
+    }
+
+    pub fn post(url: impl Into<String>, body: impl Into<String>) -> Self {
+        Request { method: Method::Post, url: url.into(), headers: vec![], body: Some(body.into()) }
+    }
+
+    /// A post of a url encoded form like `level=1&answer=42`
+    pub fn post_form(url: impl Into<String>, body: impl Into<String>) -> Self {
+        Request::post(url, body).header("Content-Type", "application/x-www-form-urlencoded")
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends a request and returns the response, whatever its status is.
+/// Only failures to get a response at all are errors
+pub trait Transport: Send + Sync {
+    fn send(&self, request: &Request) -> Result<Response>;
+}
+
+/// Returns a transport which can handle the scheme of `url`:
+/// [`Curl`] for https, [`PlainHttp`] for everything else
+pub fn transport_for(url: &str) -> Box<dyn Transport> {
+    if url.starts_with("https://") {
+        Box::new(Curl)
+    } else {
+        Box::new(PlainHttp::default())
+    }
+}
+
+/// Sends requests with the `curl` binary, which has to be installed.
+///
+/// The request is passed as a config on stdin, so the session cookie doesn't show up in the process list
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Curl;
+
+impl Transport for Curl {
+    fn send(&self, request: &Request) -> Result<Response> {
+        let mut config = format!("url = \"{}\"\nrequest = \"{}\"\n", quote(&request.url), request.method.as_str());
+        for (name, value) in &request.headers {
+            config += &format!("header = \"{}: {}\"\n", quote(name), quote(value));
+        }
+        if let Some(body) = &request.body {
+            config += &format!("data-binary = \"{}\"\n", quote(body));
+        }
+
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--config", "-", "--write-out", "\n%{http_code}"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| Error::http(&request.url, format!("unable to run curl: {}", e)))?;
+        curl.stdin.take().expect("stdin is piped").write_all(config.as_bytes())?;
+        let output = curl.wait_with_output()?;
+        if !output.status.success() {
+            return Err(Error::http(&request.url, String::from_utf8_lossy(&output.stderr).trim()));
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = output.rsplit_once('\n').unwrap_or(("", &output));
+        let status = status.trim().parse().map_err(|_| Error::http(&request.url, "curl returned no status"))?;
+        Ok(Response { status, body: body.to_string() })
+    }
+}
+
+// escapes a value for a quoted string in a curl config
+fn quote(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// A minimal HTTP/1.1 client for `http://` urls, mainly used for local servers
+#[derive(Debug, Clone, Copy)]
+pub struct PlainHttp {
+    pub timeout: Duration,
+}
+
+impl Default for PlainHttp {
+    fn default() -> Self {
+        PlainHttp { timeout: Duration::from_secs(30) }
+    }
+}
+
+impl Transport for PlainHttp {
+    fn send(&self, request: &Request) -> Result<Response> {
+        let error = |reason: String| Error::http(&request.url, reason);
+        let rest = request.url.strip_prefix("http://").ok_or_else(|| error("only http:// urls are supported".to_string()))?;
+        let (host, path) = rest.find('/').map_or((rest, "/"), |i| (&rest[..i], &rest[i..]));
+        let address = if host.contains(':') { host.to_string() } else { format!("{}:80", host) };
+
+        let mut stream = TcpStream::connect(&address).map_err(|e| error(e.to_string()))?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let body = request.body.as_deref().unwrap_or_default();
+        let mut message = format!("{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n", request.method.as_str(), path, host);
+        for (name, value) in &request.headers {
+            message += &format!("{}: {}\r\n", name, value);
+        }
+        message += &format!("Content-Length: {}\r\n\r\n{}", body.len(), body);
+        stream.write_all(message.as_bytes()).map_err(|e| error(e.to_string()))?;
+
+        let mut raw = vec![];
+        stream.read_to_end(&mut raw).map_err(|e| error(e.to_string()))?;
+        parse_response(&String::from_utf8_lossy(&raw)).ok_or_else(|| error("malformed response".to_string()))
+    }
+}
+
+fn parse_response(raw: &str) -> Option<Response> {
+    let (head, body) = raw.split_once("\r\n\r\n")?;
+    let mut lines = head.lines();
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+    let chunked = lines.any(|line| {
+        line.split_once(':')
+            .is_some_and(|(name, value)| name.eq_ignore_ascii_case("transfer-encoding") && value.trim() == "chunked")
+    });
+
+    let body = if chunked { decode_chunked(body)? } else { body.to_string() };
+    Some(Response { status, body })
+}
+
+fn decode_chunked(mut body: &str) -> Option<String> {
+    let mut decoded = String::new();
+    loop {
+        let (size, rest) = body.split_once("\r\n")?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(decoded);
+        }
+        decoded += rest.get(..size)?;
+        body = rest.get(size..)?.strip_prefix("\r\n")?;
+    }
+}
+
+/// A local server for tests, which answers requests with canned responses
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{io::{Read, Write}, net::TcpListener, thread::{self, JoinHandle}};
+
+    /// Serves one connection for each response, in order.
+    /// Returns the base url and a handle which yields the raw requests after all responses are sent
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                requests.push(read_request(&mut stream));
+                let response = format!("HTTP/1.1 {} Stub\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    // reads the head and as much of the body as Content-Length says
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut raw = vec![];
+        let mut buffer = [0; 1024];
+        loop {
+            let n = stream.read(&mut buffer).unwrap();
+            raw.extend_from_slice(&buffer[..n]);
+            let text = String::from_utf8_lossy(&raw);
+            if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                let length = head.lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map_or(0, |n| n.parse().unwrap());
+                if body.len() >= length {
+                    return text.to_string();
+                }
+            }
+            if n == 0 {
+                return text.to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_chunked, parse_response, quote, stub, PlainHttp, Request, Response, Transport};
+
+    #[test]
+    fn test_plain_http() {
+        let (url, server) = stub::serve(vec![(200, "1,2,3\n"), (404, "not found")]);
+        let request = Request::get(format!("{}/2021/day/1/input", url)).header("Cookie", "session=abc");
+        assert_eq!(PlainHttp::default().send(&request).unwrap(), Response { status: 200, body: "1,2,3\n".to_string() });
+
+        let request = Request::post_form(format!("{}/2021/day/1/answer", url), "level=1&answer=42");
+        assert_eq!(PlainHttp::default().send(&request).unwrap().status, 404);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2021/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+        assert!(requests[1].starts_with("POST /2021/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=42"));
+    }
+
+    #[test]
+    fn test_plain_http_invalid() {
+        assert!(PlainHttp::default().send(&Request::get("https://adventofcode.com")).is_err());
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let raw = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n1,2,\r\n2\r\n3\n\r\n0\r\n\r\n";
+        assert_eq!(parse_response(raw), Some(Response { status: 200, body: "1,2,3\n".to_string() }));
+        assert_eq!(decode_chunked("4\r\n1,2"), None);
+        assert_eq!(parse_response("HTTP/1.1 OK\r\n\r\n"), None);
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("a \"b\" \\c"), "a \\\"b\\\" \\\\c");
+    }
+}
@@ -0,0 +1,153 @@
+use std::{env, fs, io::{self, Read}, path::{Path, PathBuf}};
+
+use crate::{fetch::Client, Error, Result};
+
+/// Environment variable pointing to a directory with a directory for every year, like `2021/day_N.txt`
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Directory used if no other source is given
+pub const DEFAULT_INPUT_DIR: &str = "data";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A single file, given by the caller
+    File(PathBuf),
+    /// A directory containing a directory for every year with `day_N.txt` and `day_N_test.txt`
+    Dir(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the source in the following order:
+    /// an explicit path (`-` means stdin), the directory in `AOC_INPUT_DIR`, the `data` directory
+    pub fn resolve(path: Option<&Path>) -> Self {
+        match path {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+            None => {
+                let dir = env::var_os(INPUT_DIR_VAR)
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+                InputSource::Dir(dir)
+            },
+        }
+    }
+
+    /// Reads the input for a day of a year, `test` selects the example input.
+    ///
+    /// If a file is missing from the input directory and the `embedded-inputs` feature is enabled,
+    /// the input which was compiled into the binary is returned instead.
+    /// Otherwise a missing puzzle input is downloaded into the directory if `AOC_SESSION` is set.
+    pub fn read(&self, year: u16, day: usize, test: bool) -> Result<String> {
+        let input = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => read_from(io::stdin().lock()),
+            InputSource::Dir(dir) => match fs::read_to_string(year_dir(dir, year).join(file_name(day, test))) {
+                Err(e) if e.kind() == io::ErrorKind::NotFound => match (embedded(year, day, test), Client::from_env()) {
+                    (Some(input), _) => Ok(input.to_string()),
+                    (None, Some(client)) if !test => return client.cached_input(&year_dir(dir, year), year, day),
+                    _ => Err(e),
+                },
+                result => result,
+            },
+        };
+        input.map_err(|e| Error::from(e).in_file(self.location(year, day, test)))
+    }
+
+    /// Describes where the input of a day is read from, used for error messages
+    pub fn location(&self, year: u16, day: usize, test: bool) -> String {
+        match self {
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Dir(dir) => year_dir(dir, year).join(file_name(day, test)).display().to_string(),
+            InputSource::Stdin => "stdin".to_string(),
+        }
+    }
+}
+
+/// Returns the directory with the files of a year in an input directory
+pub fn year_dir(dir: &Path, year: u16) -> PathBuf {
+    dir.join(year.to_string())
+}
+
+/// Returns the file name of the input of a day
+pub fn file_name(day: usize, test: bool) -> String {
+    if test {
+        format!("day_{}_test.txt", day)
+    } else {
+        format!("day_{}.txt", day)
+    }
+}
+
+/// Reads a whole input from any reader
+pub fn read_from(mut reader: impl Read) -> io::Result<String> {
+    let mut buffer = String::new();
+    reader.read_to_string(&mut buffer)?;
+    Ok(buffer)
+}
+
+macro_rules! embed_inputs {
+    ($($year:literal: [$($day:literal),*]),*) => {
+        /// Returns the input of a day which was compiled into the binary
+        #[cfg(feature = "embedded-inputs")]
+        pub fn embedded(year: u16, day: usize, test: bool) -> Option<&'static str> {
+            match (year, day, test) {
+                $($(
+                    ($year, $day, false) => Some(include_str!(concat!("../data/", $year, "/day_", $day, ".txt"))),
+                    ($year, $day, true) => Some(include_str!(concat!("../data/", $year, "/day_", $day, "_test.txt"))),
+                )*)*
+                _ => None,
+            }
+        }
+    };
+}
+
+embed_inputs!(2021: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25]);
+
+/// Without the `embedded-inputs` feature there are no inputs in the binary
+#[cfg(not(feature = "embedded-inputs"))]
+pub fn embedded(_year: u16, _day: usize, _test: bool) -> Option<&'static str> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{file_name, read_from, InputSource};
+
+    #[test]
+    fn test_resolve_explicit() {
+        assert_eq!(InputSource::resolve(Some(Path::new("-"))), InputSource::Stdin);
+        assert_eq!(
+            InputSource::resolve(Some(Path::new("input.txt"))),
+            InputSource::File(PathBuf::from("input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name(7, false), "day_7.txt");
+        assert_eq!(file_name(7, true), "day_7_test.txt");
+    }
+
+    #[test]
+    fn test_read_dir() {
+        let source = InputSource::Dir(PathBuf::from("data"));
+        assert_eq!(source.read(2021, 7, true).unwrap(), "16,1,2,0,4,2,7,1,2,14");
+        assert_eq!(source.location(2021, 7, true), Path::new("data/2021").join("day_7_test.txt").display().to_string());
+    }
+
+    #[test]
+    fn test_read_missing() {
+        let source = InputSource::File(PathBuf::from("data/2021/day_26.txt"));
+        assert!(source.read(2021, 26, false).is_err());
+        let source = InputSource::Dir(PathBuf::from("data"));
+        assert!(source.read(2021, 26, false).is_err());
+        assert!(source.read(2015, 1, true).is_err());
+    }
+
+    #[test]
+    fn test_read_from() {
+        assert_eq!(read_from("1,2,3\n".as_bytes()).unwrap(), "1,2,3\n");
+    }
+}
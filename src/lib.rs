@@ -1,58 +1,148 @@
-pub mod day_1;
-pub mod day_2;
-pub mod day_3;
-pub mod day_4;
-pub mod day_5;
-pub mod day_6;
-pub mod day_7;
-pub mod day_8;
-pub mod day_9;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-pub mod day_13;
-pub mod day_14;
-pub mod day_15;
-pub mod day_16;
-pub mod day_17;
-pub mod day_18;
-pub mod day_19;
-pub mod day_20;
-pub mod day_21;
-pub mod day_22;
-pub mod day_23;
-pub mod day_24;
-pub mod day_25;
-
-use std::{fs::File, io::Read};
-
-pub fn parse_lines(file_name: &str) -> Vec<String> {
-    let mut buffer = String::new();
-    let _ = File::open(file_name)
-        .expect("File not found.")
-        .read_to_string(&mut buffer)
-        .expect("unable to read to string");
-            
-    buffer.split('\n').map(|line| line.to_string()).collect()
-}
-
-// todo use generics for return values
-pub fn split_commas(file_name: &str) -> Vec<u8> {
-    let mut buffer = String::new();
-    let _ = File::open(file_name)
-        .expect("File not found.")
-        .read_to_string(&mut buffer)
-        .expect("unable to read to string");
-    
-        buffer.split(',').map(|n| n.parse::<u8>().expect("got non numeric input")).collect()
-}
-
-pub fn split_commas_32(file_name: &str) -> Vec<u32> {
-    let mut buffer = String::new();
-    let _ = File::open(file_name)
-        .expect("File not found.")
-        .read_to_string(&mut buffer)
-        .expect("unable to read to string");
-    
-        buffer.split(',').map(|n| n.parse::<u32>().expect("got non numeric input")).collect()
-}
\ No newline at end of file
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod common;
+pub mod error;
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod limit;
+pub mod observe;
+pub mod parse;
+pub mod pool;
+pub mod random;
+pub mod repl;
+pub mod search;
+pub mod server;
+pub mod submit;
+pub mod trace;
+pub mod y2021;
+
+use std::fmt::Display;
+
+pub use error::{Error, Result};
+use observe::Observer;
+
+/// The result of one part of a puzzle.
+///
+/// Days return all kinds of integers and some return text (like the letters on the paper
+/// of day 13), so everything is widened into one of these variants for printing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+    /// The part has no puzzle (day 25 only has one part)
+    None,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident, $wide:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $wide)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(Signed, i128, i8, i16, i32, i64, i128, isize);
+impl_answer_from!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Every day implements this, so all puzzles can be run the same way.
+///
+/// `input` is the content of the puzzle input, not a file name.
+pub trait Solver: Sync {
+    fn part_1(&self, input: &str) -> Result<Answer>;
+    fn part_2(&self, input: &str) -> Result<Answer>;
+
+    /// Runs part 1 or 2, returns None for any other part
+    fn solve(&self, part: u8, input: &str) -> Option<Result<Answer>> {
+        match part {
+            1 => Some(self.part_1(input)),
+            2 => Some(self.part_2(input)),
+            _ => None,
+        }
+    }
+
+    /// Runs part 1 or 2 like [`Solver::solve`] and calls `observer` after every step of the simulation.
+    /// Returns None if the part isn't a simulation
+    fn observe(&self, _part: u8, _input: &str, _observer: &mut dyn Observer) -> Option<Result<Answer>> {
+        None
+    }
+}
+
+/// The puzzles of one year
+pub struct Season {
+    pub year: u16,
+    /// All days of the calendar, day n is at index n - 1
+    pub solvers: &'static [&'static dyn Solver],
+    /// Version of every day, which changes with the code of the day, see [`cache`]
+    pub versions: &'static [&'static str],
+    /// Days whose parsed model can be explored with the [`repl`]
+    pub models: &'static [usize],
+}
+
+impl Season {
+    /// Returns the solver for a day, days start at 1
+    pub fn solver(&self, day: usize) -> Option<&'static dyn Solver> {
+        self.solvers.get(day.checked_sub(1)?).copied()
+    }
+
+    /// Returns the version of the solver for a day, cached answers of other versions are outdated
+    pub fn version(&self, day: usize) -> Option<&'static str> {
+        self.versions.get(day.checked_sub(1)?).copied()
+    }
+
+    /// Iterates over all days together with their day number
+    pub fn days(&self) -> impl Iterator<Item = (usize, &'static dyn Solver)> {
+        self.solvers.iter().enumerate().map(|(i, solver)| (i + 1, *solver))
+    }
+}
+
+/// All seasons, ordered by year
+pub static SEASONS: &[Season] = &[
+    Season { year: 2021, solvers: &y2021::SOLVERS, versions: &y2021::VERSIONS, models: &repl::DAYS },
+];
+
+/// Returns the season of a year
+pub fn season(year: u16) -> Option<&'static Season> {
+    SEASONS.iter().find(|season| season.year == year)
+}
+
+/// The most recent year, used if no year is given
+pub fn latest_year() -> u16 {
+    SEASONS.last().expect("there is at least one season").year
+}
+
+/// Returns the solver for a day of a year, days start at 1
+pub fn get_solver(year: u16, day: usize) -> Option<&'static dyn Solver> {
+    season(year)?.solver(day)
+}
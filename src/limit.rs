@@ -0,0 +1,357 @@
+//! Limits for running a solver: a wall-clock timeout, a memory cap and cancellation.
+//!
+//! Solvers can't be killed, so their long loops check [`stopped`] and return early. Whatever a
+//! stopped solver returns is discarded by [`run`], and a solver which panics is reported like a
+//! stopped one. Memory is counted per thread, but only if the binary installs [`CountingAlloc`]
+//! as its global allocator.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    any::Any,
+    cell::Cell,
+    fmt::Display,
+    panic,
+    ptr,
+    sync::{atomic::{AtomicBool, AtomicU8, Ordering}, mpsc::{self, RecvTimeoutError}, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How often a limited run checks whether its solver was stopped
+const POLL: Duration = Duration::from_millis(10);
+/// How long a limited run waits for a solver which went over the memory cap to return. It's
+/// stopped in its own allocation, so it usually returns right away and reports its peak memory
+const MEMORY_GRACE: Duration = Duration::from_secs(1);
+
+/// Why a solver was stopped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    Cancelled,
+    Timeout,
+    /// The solver allocated more than the memory cap
+    Memory,
+    /// The solver panicked, with the message of the panic
+    Panicked(String),
+}
+
+impl Stop {
+    /// Reasons which a [`Token`] holds, a panic isn't one of them
+    fn from_u8(n: u8) -> Option<Stop> {
+        match n {
+            1 => Some(Stop::Cancelled),
+            2 => Some(Stop::Timeout),
+            3 => Some(Stop::Memory),
+            _ => None,
+        }
+    }
+
+    fn to_u8(&self) -> u8 {
+        match self {
+            Stop::Cancelled => 1,
+            Stop::Timeout => 2,
+            Stop::Memory => 3,
+            Stop::Panicked(_) => unreachable!("a token isn't stopped by a panic"),
+        }
+    }
+
+    fn panicked(payload: Box<dyn Any + Send>) -> Stop {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload.downcast_ref::<&str>().map_or("unknown panic".to_string(), |message| message.to_string()),
+        };
+        Stop::Panicked(message)
+    }
+}
+
+/// Printed in the summary instead of an answer, with the first line of a panic's message
+impl Display for Stop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stop::Cancelled => write!(f, "CANCELLED"),
+            Stop::Timeout => write!(f, "TIMEOUT"),
+            Stop::Memory => write!(f, "OUT OF MEMORY"),
+            Stop::Panicked(message) => write!(f, "PANICKED ({})", message.lines().next().unwrap_or_default()),
+        }
+    }
+}
+
+/// Stops a solver from another thread, clones stop the same solver
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicU8>);
+
+impl Token {
+    pub fn new() -> Self {
+        Token::default()
+    }
+
+    pub fn cancel(&self) {
+        self.stop(Stop::Cancelled);
+    }
+
+    /// The first reason to stop is kept
+    fn stop(&self, reason: Stop) {
+        let _ = self.0.compare_exchange(0, reason.to_u8(), Ordering::Relaxed, Ordering::Relaxed);
+    }
+
+    /// Returns why the solver was stopped, None if it wasn't
+    pub fn stopped(&self) -> Option<Stop> {
+        Stop::from_u8(self.0.load(Ordering::Relaxed))
+    }
+}
+
+/// Limits of a single run, None means unlimited
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    /// Bytes the solver may have allocated at once
+    pub memory: Option<usize>,
+}
+
+impl Limits {
+    pub fn is_unlimited(&self) -> bool {
+        self.timeout.is_none() && self.memory.is_none()
+    }
+}
+
+thread_local! {
+    /// Token of the solver running on this thread, null outside of `run`
+    static TOKEN: Cell<*const AtomicU8> = const { Cell::new(ptr::null()) };
+    /// Bytes allocated by this thread and not freed yet
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+    static PEAK: Cell<usize> = const { Cell::new(0) };
+    static CAP: Cell<usize> = const { Cell::new(usize::MAX) };
+}
+
+/// Whether `CountingAlloc` is the global allocator, set by its first allocation
+static COUNTING: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether the solver running on this thread should stop, always false outside of [`run`]
+pub fn stopped() -> bool {
+    let token = TOKEN.with(Cell::get);
+    // SAFETY: the token is kept alive by the `Guard` which installed it
+    !token.is_null() && unsafe { (*token).load(Ordering::Relaxed) } != 0
+}
+
+/// Installs a token and a memory cap on this thread until it's dropped
+struct Guard {
+    _token: Token,
+}
+
+impl Guard {
+    fn enter(token: Token, memory: Option<usize>) -> Self {
+        TOKEN.with(|current| current.set(Arc::as_ptr(&token.0)));
+        ALLOCATED.with(|allocated| allocated.set(0));
+        PEAK.with(|peak| peak.set(0));
+        CAP.with(|cap| cap.set(memory.unwrap_or(usize::MAX)));
+        Guard { _token: token }
+    }
+
+    /// Most bytes allocated at once since the guard was entered
+    fn peak(&self) -> Option<usize> {
+        COUNTING.load(Ordering::Relaxed).then(|| PEAK.with(Cell::get))
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        TOKEN.with(|current| current.set(ptr::null()));
+        CAP.with(|cap| cap.set(usize::MAX));
+    }
+}
+
+/// The result of a limited run
+#[derive(Debug)]
+pub struct Limited<R> {
+    /// What the job returned, or why it was stopped
+    pub result: Result<R, Stop>,
+    pub elapsed: Duration,
+    /// Most bytes the job had allocated at once, None without [`CountingAlloc`] or if it didn't finish
+    pub memory: Option<usize>,
+}
+
+/// Runs `job` within `limits`, `token` can stop it from another thread.
+///
+/// Without limits the job runs on the calling thread, otherwise on a thread of its own.
+/// A job which doesn't stop in time is left running, so the caller doesn't wait for it, except for
+/// a short grace after it went over the memory cap, to report how much it had allocated.
+/// A panic of the job is caught and returned as [`Stop::Panicked`]
+pub fn run<R: Send + 'static>(limits: Limits, token: Token, job: impl FnOnce() -> R + Send + 'static) -> Limited<R> {
+    if limits.is_unlimited() {
+        return run_here(limits, token, job);
+    }
+
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let handle = {
+        let token = token.clone();
+        thread::spawn(move || {
+            let _ = sender.send(run_here(limits, token, job));
+        })
+    };
+    loop {
+        let wait = match limits.timeout {
+            Some(timeout) => timeout.saturating_sub(start.elapsed()).min(POLL),
+            None => POLL,
+        };
+        match receiver.recv_timeout(wait) {
+            Ok(limited) => return limited,
+            Err(RecvTimeoutError::Disconnected) => match handle.join() {
+                Err(payload) => return Limited { result: Err(Stop::panicked(payload)), elapsed: start.elapsed(), memory: None },
+                Ok(()) => unreachable!("the job sends its result before it finishes"),
+            },
+            Err(RecvTimeoutError::Timeout) => {
+                if limits.timeout.is_some_and(|timeout| start.elapsed() >= timeout) {
+                    token.stop(Stop::Timeout);
+                }
+                if let Some(stop) = token.stopped() {
+                    if stop == Stop::Memory {
+                        if let Ok(limited) = receiver.recv_timeout(MEMORY_GRACE) {
+                            return limited;
+                        }
+                    }
+                    return Limited { result: Err(stop), elapsed: start.elapsed(), memory: None };
+                }
+            },
+        }
+    }
+}
+
+fn run_here<R>(limits: Limits, token: Token, job: impl FnOnce() -> R) -> Limited<R> {
+    let guard = Guard::enter(token.clone(), limits.memory);
+    let start = Instant::now();
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(job));
+    let elapsed = start.elapsed();
+    let memory = guard.peak();
+    drop(guard);
+
+    // a stopped job may panic because it was stopped, the stop is the reason then
+    let result = match (token.stopped(), result) {
+        (Some(stop), _) => Err(stop),
+        (None, Ok(result)) => Ok(result),
+        (None, Err(payload)) => Err(Stop::panicked(payload)),
+    };
+    Limited { result, elapsed, memory }
+}
+
+/// Global allocator which counts the bytes allocated by each thread, install it with
+/// `#[global_allocator] static ALLOC: CountingAlloc = CountingAlloc;`
+pub struct CountingAlloc;
+
+impl CountingAlloc {
+    fn grow(bytes: usize) {
+        if !COUNTING.load(Ordering::Relaxed) {
+            COUNTING.store(true, Ordering::Relaxed);
+        }
+        let allocated = ALLOCATED.with(|allocated| {
+            allocated.set(allocated.get() + bytes);
+            allocated.get()
+        });
+        PEAK.with(|peak| peak.set(peak.get().max(allocated)));
+        if allocated > CAP.with(Cell::get) {
+            let token = TOKEN.with(Cell::get);
+            if !token.is_null() {
+                // SAFETY: the token is kept alive by the `Guard` which installed it
+                let _ = unsafe { &*token }.compare_exchange(0, Stop::Memory.to_u8(), Ordering::Relaxed, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn shrink(bytes: usize) {
+        // memory allocated by another thread can be freed here
+        ALLOCATED.with(|allocated| allocated.set(allocated.get().saturating_sub(bytes)));
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            CountingAlloc::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            CountingAlloc::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CountingAlloc::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CountingAlloc::shrink(layout.size());
+            CountingAlloc::grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::{run, stopped, Limits, Stop, Token};
+
+    #[test]
+    fn test_unlimited() {
+        let limited = run(Limits::default(), Token::new(), || 6 * 7);
+        assert_eq!(limited.result, Ok(42));
+        assert!(!stopped());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let limits = Limits { timeout: Some(Duration::from_millis(50)), memory: None };
+        // checks whether it should stop, like the loops of the solvers
+        let limited = run(limits, Token::new(), || {
+            while !stopped() {
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+        assert_eq!(limited.result, Err(Stop::Timeout));
+
+        // never checks, it's left running
+        let limited = run(limits, Token::new(), || thread::sleep(Duration::from_secs(5)));
+        assert_eq!(limited.result, Err(Stop::Timeout));
+        assert!(limited.elapsed < Duration::from_secs(1));
+
+        assert_eq!(run(limits, Token::new(), || "fast").result, Ok("fast"));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let token = Token::new();
+        let limits = Limits { timeout: Some(Duration::from_secs(5)), memory: None };
+        let canceller = {
+            let token = token.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(20));
+                token.cancel();
+            })
+        };
+        let limited = run(limits, token.clone(), || while !stopped() {});
+        canceller.join().unwrap();
+        assert_eq!(limited.result, Err(Stop::Cancelled));
+        assert_eq!(token.stopped(), Some(Stop::Cancelled));
+    }
+
+    #[test]
+    fn test_panic() {
+        let limited = run(Limits::default(), Token::new(), || -> u8 { panic!("no answer") });
+        assert_eq!(limited.result, Err(Stop::Panicked("no answer".to_string())));
+        assert!(!stopped());
+
+        let limits = Limits { timeout: Some(Duration::from_secs(5)), memory: None };
+        let limited = run(limits, Token::new(), || -> u8 { panic!("no answer for {}\nat all", 42) });
+        assert_eq!(limited.result, Err(Stop::Panicked("no answer for 42\nat all".to_string())));
+        assert_eq!(limited.result.unwrap_err().to_string(), "PANICKED (no answer for 42)");
+    }
+}
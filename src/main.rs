@@ -1,261 +1,382 @@
-use std::time::Instant;
-
-
-use advent_of_code::*;
-
-
-fn main() {
-    let start_all = Instant::now();
-
-    let start = Instant::now();
-    let result = day_1::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 1.1:\t{:>7}us,\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_1::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 1.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_2::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 2.1:\t{:>7}us,\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_2::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 2.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_3::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 3.1:\t{:>7}us,\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_3::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 3.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_4::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 4.1:\t{:>7}us,\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_4::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 4.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_5::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 5.1:\t{:>7}us,\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_5::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 5.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_6::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 6.1:\t{:>7}us,\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_6::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 6.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_7::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 7.1:\t{:>7}us,\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_7::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 7.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_8::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 8.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_8::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 8.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_9::get_solution_1(false);
-    let end = start.elapsed().as_micros();
-    println!("Day 9.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_9::get_solution_2(false);
-    let end = start.elapsed().as_micros();
-    println!("Day 9.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_10::get_solution_1(false);
-    let end = start.elapsed().as_micros();
-    println!("Day 10.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_10::get_solution_2(false);
-    let end = start.elapsed().as_micros();
-    println!("Day 10.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_11::get_solution_1(false);
-    let end = start.elapsed().as_micros();
-    println!("Day 11.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_11::get_solution_2(false);
-    let end = start.elapsed().as_micros();
-    println!("Day 11.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_12::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 12.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_12::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 12.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_13::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 13.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_13::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 13.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_14::get_solution_1(false);
-    let end = start.elapsed().as_micros();
-    println!("Day 14.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_14::get_solution_2(false, 100);
-    let end = start.elapsed().as_micros();
-    println!("Day 14.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_15::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 15.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_15::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 15.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_16::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 16.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_16::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 16.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_17::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 17.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_17::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 17.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_18::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 18.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_18::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 18.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_19::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 19.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_19::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 19.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_20::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 20.1:\t{:>7}us\t{}", end, result);
-
-    // let start = Instant::now();
-    // let result = day_20::get_solution_1();
-    // let end = start.elapsed().as_micros();
-    // println!("Day 20.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_21::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 21.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_21::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 21.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_22::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 22.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_22::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 22.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_23::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 23.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_23::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 23.2:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_24::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 24.1:\t{:>7}us\t{}", end, result);
-
-    let start = Instant::now();
-    let result = day_24::get_solution_2();
-    let end = start.elapsed().as_micros();
-    println!("Day 24.2:\t{:>7}us\t{}", end, result);
-
-
-    let result = day_25::get_solution_1();
-    let end = start.elapsed().as_micros();
-    println!("Day 25.1:\t{:>7}us\t{}", end, result);
-
-    let end_all = start_all.elapsed().as_micros();
-    println!("Day 1 to 25 took {} micros", end_all);
-
-    // 96918996924991
-    // 91811241911641
-
-}
\ No newline at end of file
+use std::{fs, io::{self, BufRead, Write}, process::ExitCode, sync::Arc};
+
+use advent_of_code::{
+    answers::{Answers, Verdict},
+    bench::{self, Measurement, Report},
+    cache::{Cache, Cached, Key},
+    fetch::{self, Client},
+    input::InputSource,
+    limit::{self, CountingAlloc, Limited, Token},
+    observe::{FrameDump, Terminal},
+    repl::Session,
+    submit::{self, Attempt, Ledger, Outcome},
+    trace::{self, Level},
+    *,
+};
+use cli::{BenchArgs, Command, RunArgs, SubmitArgs, VerifyArgs, Watch};
+
+mod cli;
+
+/// Counts the memory of every part
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+fn main() -> ExitCode {
+    let command = cli::parse_args(std::env::args().skip(1));
+    if let Some(args) = command.as_ref().ok().and_then(Command::run_args) {
+        trace::set_level(Level::from_verbosity(args.verbose));
+    }
+
+    match command {
+        Ok(Command::Run(args)) => run(&args),
+        Ok(Command::Bench(args)) => run_bench(&args),
+        Ok(Command::Verify(args)) => run_verify(&args),
+        Ok(Command::Submit(args)) => run_submit(&args),
+        Ok(Command::Repl(args)) => run_repl(&args),
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            ExitCode::from(2)
+        },
+    }
+}
+
+/// Answers, run times and memory of the parts of one day and whether they were cached,
+/// or the error reading its input
+type DayResult = Result<Vec<(u8, Limited<Result<Answer>>, bool)>>;
+
+/// Solves the parts of a day, parts in the cache aren't solved again and solved parts are added to it
+fn solve_day(args: &RunArgs, source: &InputSource, cache: Option<&Cache>, day: usize) -> DayResult {
+    let input: Arc<str> = {
+        let _span = span!(Info, "input", year = args.year, day = day);
+        source.read(args.year, day, args.test)?.into()
+    };
+    let solver = args.season().solver(day).expect("days are validated by the cli");
+
+    Ok(args.parts()
+        .into_iter()
+        .map(|part| {
+            let key = cache.and_then(|_| Key::new(args.year, day, part, &input));
+            if let Some(Cached { answer, elapsed, memory }) = cache.zip(key.as_ref()).and_then(|(cache, key)| cache.get(key)) {
+                event!(Info, "cached", day = day, part = part);
+                return (part, Limited { result: Ok(Ok(answer)), elapsed, memory }, true);
+            }
+
+            let _span = span!(Info, "solve", day = day, part = part);
+            let solving = input.clone();
+            let limited = limit::run(args.limits, Token::new(), move || solver.solve(part, &solving).unwrap_or(Ok(Answer::None)));
+            if let (Some(cache), Some(key), Ok(Ok(answer))) = (cache, key, &limited.result) {
+                cache.insert(key, Cached { answer: answer.clone(), elapsed: limited.elapsed, memory: limited.memory });
+            }
+            (part, limited, false)
+        })
+        .collect())
+}
+
+/// Opens the cache unless it's turned off, a cache which can't be read is left out
+fn open_cache(args: &RunArgs) -> Option<Cache> {
+    if !args.cache {
+        return None;
+    }
+    Cache::open(args.cache_path())
+        .map_err(|e| eprintln!("not using the cache: {}", e))
+        .ok()
+}
+
+fn save_cache(cache: Option<Cache>) {
+    if let Some(Err(e)) = cache.map(|cache| cache.save()) {
+        eprintln!("unable to save the cache: {}", e);
+    }
+}
+
+/// Formats a number of bytes in KiB, rounded up
+fn kib(bytes: Option<usize>) -> String {
+    bytes.map_or("-".to_string(), |bytes| format!("{}KiB", bytes.div_ceil(1024)))
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    if let Some(watch) = &args.watch {
+        return run_watched(args, watch);
+    }
+    let mut exit_code = ExitCode::SUCCESS;
+    let source = args.input_source();
+    let cache = open_cache(args);
+
+    pool::run_ordered(&args.days(), args.threads(), |day| solve_day(args, &source, cache.as_ref(), *day), |&day, parts| {
+        let parts = match parts {
+            Ok(parts) => parts,
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                exit_code = ExitCode::FAILURE;
+                return;
+            },
+        };
+
+        for (part, Limited { result, elapsed, memory }, cached) in parts {
+            match result {
+                Ok(Ok(result)) if cached => {
+                    println!("Day {}.{}:\t{:>7}us\t{:>9}\t{}\t(cached)", day, part, elapsed.as_micros(), kib(memory), result)
+                },
+                Ok(Ok(result)) => println!("Day {}.{}:\t{:>7}us\t{:>9}\t{}", day, part, elapsed.as_micros(), kib(memory), result),
+                Err(stop) => {
+                    println!("Day {}.{}:\t{:>7}us\t{:>9}\t{}", day, part, elapsed.as_micros(), kib(memory), stop);
+                    exit_code = ExitCode::FAILURE;
+                },
+                Ok(Err(e)) => {
+                    eprintln!("Day {}.{}: {}", day, part, e.in_file(source.location(args.year, day, args.test)));
+                    exit_code = ExitCode::FAILURE;
+                },
+            }
+        }
+    });
+    save_cache(cache);
+
+    exit_code
+}
+
+/// Runs the parts of a single day as simulations, showing every step
+fn run_watched(args: &RunArgs, watch: &Watch) -> ExitCode {
+    let day = args.day.expect("watching needs a day, which is checked by the cli");
+    let source = args.input_source();
+    let input = match source.read(args.year, day, args.test) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            return ExitCode::FAILURE;
+        },
+    };
+    let solver = args.season().solver(day).expect("days are validated by the cli");
+
+    let (mut exit_code, mut watched) = (ExitCode::SUCCESS, 0);
+    for part in args.parts() {
+        let (result, dump) = match watch {
+            Watch::Animate(delay) => (solver.observe(part, &input, &mut Terminal::stdout(*delay)), None),
+            Watch::Frames(dir, format) => {
+                let mut dump = FrameDump::new(dir, format!("day_{}_{}_", day, part), *format);
+                (solver.observe(part, &input, &mut dump), Some((dump, dir)))
+            },
+        };
+
+        // without --part, parts which aren't simulations are skipped
+        let Some(result) = result else { continue };
+        watched += 1;
+        match result {
+            Ok(result) => println!("Day {}.{}:\t{}", day, part, result),
+            Err(e) => {
+                eprintln!("Day {}.{}: {}", day, part, e.in_file(source.location(args.year, day, args.test)));
+                exit_code = ExitCode::FAILURE;
+            },
+        }
+        if let Some((dump, dir)) = dump {
+            match dump.finish() {
+                Ok(frames) => println!("{} frames written to {}", frames, dir.display()),
+                Err(e) => {
+                    eprintln!("unable to write the frames to {}: {}", dir.display(), e);
+                    exit_code = ExitCode::FAILURE;
+                },
+            }
+        }
+    }
+
+    if watched == 0 {
+        match args.part {
+            Some(part) => eprintln!("Day {}.{} isn't a simulation which can be watched", day, part),
+            None => eprintln!("Day {} has no simulation which can be watched", day),
+        }
+        return ExitCode::FAILURE;
+    }
+    exit_code
+}
+
+fn run_bench(args: &BenchArgs) -> ExitCode {
+    let mut exit_code = ExitCode::SUCCESS;
+    let source = args.run.input_source();
+    let mut report = Report::default();
+
+    for day in args.run.days() {
+        let input = match source.read(args.run.year, day, args.run.test) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            },
+        };
+        let solver = args.run.season().solver(day).expect("days are validated by the cli");
+
+        for part in args.run.parts() {
+            match bench::measure(solver, part, &input, args.warmup, args.runs) {
+                Ok(stats) => report.measurements.push(Measurement { day, part, stats }),
+                Err(e) => {
+                    eprintln!("Day {}.{}: {}", day, part, e.in_file(source.location(args.run.year, day, args.run.test)));
+                    exit_code = ExitCode::FAILURE;
+                },
+            }
+        }
+    }
+
+    let rendered = report.render(args.format);
+    match &args.output {
+        Some(path) => if let Err(e) = fs::write(path, rendered) {
+            eprintln!("unable to write {}: {}", path.display(), e);
+            exit_code = ExitCode::FAILURE;
+        },
+        None => println!("{}", rendered.trim_end()),
+    }
+
+    exit_code
+}
+
+fn run_verify(args: &VerifyArgs) -> ExitCode {
+    let path = args.answers_path();
+    let answers = match fs::read_to_string(&path).map_err(Error::from).and_then(|manifest| Answers::parse(&manifest)) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e.in_file(path.display().to_string()));
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let source = args.run.input_source();
+    let cache = open_cache(&args.run);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    pool::run_ordered(&args.run.days(), args.run.threads(), |day| solve_day(&args.run, &source, cache.as_ref(), *day), |&day, parts| {
+        let parts = match parts {
+            Ok(parts) => parts,
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                failed += args.run.parts().len();
+                return;
+            },
+        };
+
+        for (part, Limited { result, .. }, _) in parts {
+            let answer = match result {
+                Ok(Ok(answer)) => answer,
+                Err(stop) => {
+                    println!("Day {}.{}:\t{}", day, part, stop);
+                    failed += 1;
+                    continue;
+                },
+                Ok(Err(e)) => {
+                    eprintln!("Day {}.{}: {}", day, part, e.in_file(source.location(args.run.year, day, args.run.test)));
+                    // an error is only a failure if an answer is expected
+                    match answers.get(day, part) {
+                        Some(_) => failed += 1,
+                        None => missing += 1,
+                    }
+                    continue;
+                },
+            };
+            match answers.check(day, part, &answer) {
+                Verdict::Pass => {
+                    println!("Day {}.{}:\tpass", day, part);
+                    passed += 1;
+                },
+                Verdict::Fail { expected, actual } => {
+                    println!("Day {}.{}:\tFAIL\texpected {:?}, got {:?}", day, part, expected, actual);
+                    failed += 1;
+                },
+                Verdict::Missing { actual } => {
+                    println!("Day {}.{}:\tmissing\tgot {:?}", day, part, actual);
+                    missing += 1;
+                },
+            }
+        }
+    });
+    save_cache(cache);
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+fn run_submit(args: &SubmitArgs) -> ExitCode {
+    match submit_answer(args) {
+        Ok(Outcome::Correct) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+/// Reads commands from stdin until `quit` or the end of the input and prints the state after each
+fn run_repl(args: &RunArgs) -> ExitCode {
+    let day = args.day.expect("repl needs a day, which is checked by the cli");
+    let source = args.input_source();
+    let loaded = source.read(args.year, day, args.test).and_then(|input| {
+        Session::load(day, &input).map_err(|e| e.in_file(source.location(args.year, day, args.test)))
+    });
+    let mut session = match loaded {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            return ExitCode::FAILURE;
+        },
+    };
+
+    println!("{}\n\n{}", session.show(), session.help());
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("day {}> ", session.day());
+        let _ = io::stdout().flush();
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(e)) => {
+                eprintln!("unable to read the command: {}", e);
+                return ExitCode::FAILURE;
+            },
+            None => break,
+        };
+        match line.trim() {
+            "" => continue,
+            "quit" | "exit" => break,
+            command => match session.execute(command) {
+                Ok(state) => println!("{}", state),
+                Err(e) => eprintln!("error: {}", e),
+            },
+        }
+    }
+    println!();
+
+    ExitCode::SUCCESS
+}
+
+fn submit_answer(args: &SubmitArgs) -> std::result::Result<Outcome, String> {
+    let (day, part) = (args.day(), args.part());
+    let source = args.run.input_source();
+    let input = source.read(args.run.year, day, false).map_err(|e| e.to_string())?;
+    let answer = args.run.season()
+        .solver(day)
+        .expect("days are validated by the cli")
+        .solve(part, &input)
+        .unwrap_or(Ok(Answer::None))
+        .map_err(|e| e.in_file(source.location(args.run.year, day, false)).to_string())?;
+    let answer = match answer {
+        Answer::None => return Err(format!("day {} has no part {}", day, part)),
+        Answer::Text(text) if text.trim().contains('\n') => {
+            return Err(format!("the answer has several lines, read it and submit it by hand:\n{}", text))
+        },
+        answer => answer.to_string().trim().to_string(),
+    };
+    println!("Day {}.{}:\t{}", day, part, answer);
+
+    let mut ledger = Ledger::open(args.ledger_path()).map_err(|e| e.to_string())?;
+    if let Some(reason) = ledger.refusal(args.run.year, day, part, &answer) {
+        return Err(format!("not submitting, {} (see {})", reason, ledger.path().display()));
+    }
+
+    let client = Client::from_env()
+        .ok_or_else(|| format!("{} has to hold the session cookie to submit", fetch::SESSION_VAR))?;
+    let submission = submit::submit(client, args.run.year, day, part, &answer).map_err(|e| e.to_string())?;
+    ledger
+        .record(Attempt { year: args.run.year, day, part, answer, outcome: submission.outcome })
+        .map_err(|e| e.to_string())?;
+    println!("{}\n{}", submission.outcome, submission.message);
+
+    Ok(submission.outcome)
+}
@@ -0,0 +1,258 @@
+//! Hooks to watch simulations step by step.
+//!
+//! Days which simulate something call an [`Observer`] after every step with a [`Frame`],
+//! a read-only view of their state. [`Terminal`] animates the frames, [`FrameDump`] saves them.
+
+use std::{fs, io::{self, Write}, path::PathBuf, str::FromStr, thread, time::Duration};
+
+/// A read-only view of a grid shaped state, `x` is the column and `y` the row
+pub trait Frame {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+
+    /// Character of a cell, used for text output
+    fn cell(&self, x: usize, y: usize) -> char;
+
+    /// Color of a cell, used for images
+    fn color(&self, x: usize, y: usize) -> [u8; 3] {
+        palette(self.cell(x, y))
+    }
+}
+
+/// Default colors of the characters the days use
+pub fn palette(c: char) -> [u8; 3] {
+    match c {
+        '.' | ' ' => [16, 16, 32],
+        '#' => [240, 240, 240],
+        // energy levels, a flash (0) is the brightest
+        '0' => [255, 255, 160],
+        d @ '1'..='9' => {
+            let level = d as u8 - b'0';
+            [level * 20, level * 20, 40 + level * 10]
+        },
+        '>' => [80, 200, 80],
+        'v' => [80, 160, 240],
+        'A' => [230, 80, 80],
+        'B' => [230, 160, 60],
+        'C' => [80, 200, 200],
+        'D' => [200, 90, 220],
+        _ => [160, 160, 160],
+    }
+}
+
+/// Renders a frame as text, one line per row
+pub fn render(frame: &dyn Frame) -> String {
+    let mut text = String::with_capacity((frame.width() + 1) * frame.height());
+    for y in 0..frame.height() {
+        text.extend((0..frame.width()).map(|x| frame.cell(x, y)));
+        text.push('\n');
+    }
+    text
+}
+
+/// A frame made from text, for states which are drawn as a string.
+/// Short lines are padded with spaces
+pub struct TextFrame {
+    lines: Vec<Vec<char>>,
+    width: usize,
+}
+
+impl TextFrame {
+    pub fn new(text: &str) -> Self {
+        let lines = text.lines().map(|line| line.chars().collect::<Vec<char>>()).collect::<Vec<_>>();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        TextFrame { lines, width }
+    }
+}
+
+impl Frame for TextFrame {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    fn cell(&self, x: usize, y: usize) -> char {
+        self.lines[y].get(x).copied().unwrap_or(' ')
+    }
+}
+
+/// Gets called by a simulation after every step, `step` starts at 1
+pub trait Observer {
+    fn step(&mut self, step: usize, frame: &dyn Frame);
+}
+
+/// Ignores all steps, used when a simulation runs without being watched
+pub struct NoObserver;
+
+impl Observer for NoObserver {
+    fn step(&mut self, _step: usize, _frame: &dyn Frame) {}
+}
+
+impl<F: FnMut(usize, &dyn Frame)> Observer for F {
+    fn step(&mut self, step: usize, frame: &dyn Frame) {
+        self(step, frame)
+    }
+}
+
+/// Animates the steps in a terminal, the screen is cleared before every frame
+pub struct Terminal<W: Write> {
+    out: W,
+    delay: Duration,
+}
+
+impl Terminal<io::Stdout> {
+    pub fn stdout(delay: Duration) -> Self {
+        Terminal::new(io::stdout(), delay)
+    }
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        Terminal { out, delay }
+    }
+}
+
+impl<W: Write> Observer for Terminal<W> {
+    fn step(&mut self, step: usize, frame: &dyn Frame) {
+        // an animation which can't be shown isn't worth failing the run
+        let _ = writeln!(self.out, "\x1b[2J\x1b[H{}step {}", render(frame), step).and_then(|_| self.out.flush());
+        thread::sleep(self.delay);
+    }
+}
+
+/// File format of a [`FrameDump`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DumpFormat {
+    #[default]
+    Text,
+    /// Binary portable pixmap, every cell is a square of `scale` pixels
+    Ppm { scale: usize },
+}
+
+impl FromStr for DumpFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(DumpFormat::Text),
+            "ppm" => Ok(DumpFormat::Ppm { scale: 4 }),
+            _ => Err(format!("unknown frame format '{}', expected text or ppm", s)),
+        }
+    }
+}
+
+/// Writes every step to a numbered file like `<prefix>00001.txt` in a directory.
+///
+/// Steps don't fail, the first error is kept and returned by [`FrameDump::finish`]
+pub struct FrameDump {
+    dir: PathBuf,
+    prefix: String,
+    format: DumpFormat,
+    written: usize,
+    error: Option<io::Error>,
+}
+
+impl FrameDump {
+    /// The directory is created if it doesn't exist
+    pub fn new(dir: impl Into<PathBuf>, prefix: impl Into<String>, format: DumpFormat) -> Self {
+        FrameDump { dir: dir.into(), prefix: prefix.into(), format, written: 0, error: None }
+    }
+
+    /// Returns the number of written frames
+    pub fn finish(self) -> io::Result<usize> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.written),
+        }
+    }
+
+    fn write(&self, step: usize, frame: &dyn Frame) -> io::Result<()> {
+        let (extension, content) = match self.format {
+            DumpFormat::Text => ("txt", render(frame).into_bytes()),
+            DumpFormat::Ppm { scale } => ("ppm", ppm(frame, scale)),
+        };
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.dir.join(format!("{}{:05}.{}", self.prefix, step, extension)), content)
+    }
+}
+
+impl Observer for FrameDump {
+    fn step(&mut self, step: usize, frame: &dyn Frame) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.write(step, frame) {
+            Ok(()) => self.written += 1,
+            Err(e) => self.error = Some(e),
+        }
+    }
+}
+
+/// Encodes a frame as a binary PPM (P6) image
+pub fn ppm(frame: &dyn Frame, scale: usize) -> Vec<u8> {
+    let scale = scale.max(1);
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    image.reserve(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            image.extend(frame.color(x / scale, y / scale));
+        }
+    }
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use super::{ppm, render, DumpFormat, Frame, FrameDump, Observer, Terminal, TextFrame};
+
+    #[test]
+    fn test_text_frame() {
+        let frame = TextFrame::new("#.#\n.#\n");
+        assert_eq!((frame.width(), frame.height()), (3, 2));
+        assert_eq!(frame.cell(2, 1), ' ');
+        assert_eq!(render(&frame), "#.#\n.# \n");
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = ppm(&TextFrame::new("#."), 2);
+        assert!(image.starts_with(b"P6\n4 2\n255\n"));
+        assert_eq!(image.len(), "P6\n4 2\n255\n".len() + 4 * 2 * 3);
+        assert_eq!(&image[11..14], &[240, 240, 240]);
+    }
+
+    #[test]
+    fn test_terminal() {
+        let mut out = vec![];
+        Terminal::new(&mut out, Duration::ZERO).step(3, &TextFrame::new("v>"));
+        assert_eq!(String::from_utf8(out).unwrap(), "\x1b[2J\x1b[Hv>\nstep 3\n");
+    }
+
+    #[test]
+    fn test_frame_dump() {
+        let dir = env::temp_dir().join(format!("aoc_frames_test_{}", std::process::id()));
+        let mut dump = FrameDump::new(&dir, "day_25_", DumpFormat::Text);
+        dump.step(1, &TextFrame::new("v."));
+        dump.step(2, &TextFrame::new(".v"));
+        assert_eq!(dump.finish().unwrap(), 2);
+        assert_eq!(fs::read_to_string(dir.join("day_25_00002.txt")).unwrap(), ".v\n");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!("ppm".parse(), Ok(DumpFormat::Ppm { scale: 4 }));
+        assert!("gif".parse::<DumpFormat>().is_err());
+    }
+
+    #[test]
+    fn test_closure_observer() {
+        let mut steps = vec![];
+        let mut observer = |step: usize, frame: &dyn Frame| steps.push((step, render(frame)));
+        observer.step(1, &TextFrame::new("#"));
+        assert_eq!(steps, vec![(1, "#\n".to_string())]);
+    }
+}
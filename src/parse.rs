@@ -0,0 +1,94 @@
+//! Parsers for input formats which appear on several days.
+//!
+//! The functions take the whole `input` to report the position of an error,
+//! `part` is the section which is parsed and has to be a slice of `input`.
+
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::{error::parse_at, Error, Result};
+
+/// Parses values separated by `separator`, like `3,4,3,1,2`.
+/// Whitespace around the values is ignored, so `'\n'` parses one value per line
+pub fn list<T: FromStr>(input: &str, part: &str, separator: char) -> Result<Vec<T>> {
+    part.trim().split(separator).map(|value| parse_at(input, value.trim())).collect()
+}
+
+/// Parses values separated by any amount of whitespace, like `22 13 17 11  0`
+pub fn words<T: FromStr>(input: &str, part: &str) -> Result<Vec<T>> {
+    part.split_whitespace().map(|value| parse_at(input, value)).collect()
+}
+
+/// Splits `part` into blocks which are separated by blank lines.
+/// The blocks are slices of `part`, so they can be parsed further with the other functions
+pub fn blocks(part: &str) -> Vec<&str> {
+    let mut blocks = vec![];
+    let mut start = None;
+    let mut offset = 0;
+    for line in part.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                blocks.push(part[start..offset].trim_end());
+            }
+        } else if start.is_none() {
+            start = Some(offset);
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        blocks.push(part[start..].trim_end());
+    }
+
+    blocks
+}
+
+/// Parses a range like `x=-20..26`, where `prefix` is the text in front of the range (`x=`).
+/// The bounds are returned as they are written, a reversed range is empty unless the caller swaps its ends
+pub fn range<T: FromStr>(input: &str, part: &str, prefix: &str) -> Result<RangeInclusive<T>> {
+    let (start, end) = part.trim()
+        .strip_prefix(prefix)
+        .and_then(|range| range.split_once(".."))
+        .ok_or_else(|| Error::at(input, part, format!("expected a range like '{}1..2'", prefix)))?;
+
+    Ok(parse_at(input, start)?..=parse_at(input, end)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blocks, list, range, words};
+
+    #[test]
+    fn test_list() {
+        let input = "3,4, 3,1,2\n";
+        assert_eq!(list::<u8>(input, input, ',').unwrap(), vec![3, 4, 3, 1, 2]);
+        assert_eq!(list::<i32>("199\n-200\r\n208", "199\n-200\r\n208", '\n').unwrap(), vec![199, -200, 208]);
+
+        let input = "3,4,x";
+        assert_eq!(list::<u8>(input, input, ',').unwrap_err().to_string(), "line 1, column 5: can't parse 'x'");
+    }
+
+    #[test]
+    fn test_words() {
+        let input = "numbers\n22 13 17 11  0";
+        assert_eq!(words::<u8>(input, &input[8..]).unwrap(), vec![22, 13, 17, 11, 0]);
+        assert_eq!(words::<u8>(input, input).unwrap_err().to_string(), "line 1, column 1: can't parse 'numbers'");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "7,4,9\n\n22 13\n 8  2\n\r\n\n3 15\n";
+        assert_eq!(blocks(input), vec!["7,4,9", "22 13\n 8  2", "3 15"]);
+        assert!(blocks("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_range() {
+        let input = "on x=-20..26,y=-36..-47";
+        assert_eq!(range::<i32>(input, &input[3..12], "x=").unwrap(), -20..=26);
+        assert_eq!(range::<i32>(input, &input[13..], "y=").unwrap().into_inner(), (-36, -47));
+        assert_eq!(
+            range::<i32>(input, &input[13..], "z=").unwrap_err().to_string(),
+            "line 1, column 14: expected a range like 'z=1..2'"
+        );
+        assert_eq!(range::<u8>(input, &input[3..12], "x=").unwrap_err().to_string(), "line 1, column 6: can't parse '-20'");
+    }
+}
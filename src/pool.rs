@@ -0,0 +1,73 @@
+use std::{collections::BTreeMap, num::NonZeroUsize, sync::{atomic::{AtomicUsize, Ordering}, mpsc}, thread};
+
+/// Number of threads to use if the caller asks for all of them
+pub fn available_threads() -> usize {
+    thread::available_parallelism().map(NonZeroUsize::get).unwrap_or(1)
+}
+
+/// Runs `job` for every item on up to `threads` threads.
+///
+/// `done` is called on the calling thread with the results in the order of `items`,
+/// a result is passed on as soon as it and all results before it are finished.
+/// With one thread everything runs on the calling thread.
+pub fn run_ordered<T, R>(items: &[T], threads: usize, job: impl Fn(&T) -> R + Sync, mut done: impl FnMut(&T, R))
+where
+    T: Sync,
+    R: Send,
+{
+    let threads = threads.clamp(1, items.len().max(1));
+    if threads == 1 {
+        items.iter().for_each(|item| done(item, job(item)));
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            let sender = sender.clone();
+            let (next, job) = (&next, &job);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else { break };
+                if sender.send((i, job(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // results which arrived before one of their predecessors
+        let mut waiting = BTreeMap::new();
+        let mut printed = 0;
+        for (i, result) in receiver {
+            waiting.insert(i, result);
+            while let Some(result) = waiting.remove(&printed) {
+                done(&items[printed], result);
+                printed += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::run_ordered;
+
+    #[test]
+    fn test_run_ordered() {
+        let items = (0..20).collect::<Vec<u64>>();
+        for threads in [1, 4, 50] {
+            let mut results = vec![];
+            run_ordered(&items, threads, |n| {
+                // later items finish first
+                thread::sleep(Duration::from_millis(20 - n));
+                n * n
+            }, |n, square| results.push((*n, square)));
+            assert_eq!(results, items.iter().map(|n| (*n, n * n)).collect::<Vec<_>>());
+        }
+        run_ordered(&[] as &[u8], 4, |_| (), |_, _| panic!("there are no items"));
+    }
+}
@@ -0,0 +1,98 @@
+//! A small seeded random number generator for generating puzzle inputs.
+//!
+//! It's SplitMix64, which is fast and good enough for tests. The same seed always gives the
+//! same numbers, so a failing input can be reproduced from its seed.
+
+use std::ops::Range;
+
+/// Seeded random number generator
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `range`, which must not be empty
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "empty range {:?}", range);
+        let len = range.end.abs_diff(range.start);
+        range.start.wrapping_add((self.next_u64() % len) as i64)
+    }
+
+    /// Returns a number below `n`, which must not be 0
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "no number below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Returns true with a probability of `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        // the upper 53 bits fit into the mantissa
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// Returns a random element, None if `items` is empty
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.below(items.len())])
+        }
+    }
+
+    /// Shuffles `items` in place (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn test_same_seed_same_numbers() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(7);
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            let n = rng.range(-2..3);
+            assert!((-2..3).contains(&n));
+            seen[(n + 2) as usize] = true;
+        }
+        assert!(seen.iter().all(|seen| *seen));
+        assert!((0..1000).map(|_| rng.chance(0.25)).filter(|hit| *hit).count().abs_diff(250) < 60);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut items: Vec<usize> = (0..20).collect();
+        Rng::new(3).shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
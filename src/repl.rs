@@ -0,0 +1,355 @@
+//! An interactive session which keeps the parsed model of a day in memory, for trying things
+//! out on it without changing a test and compiling again.
+//!
+//! [`Session::execute`] runs one command and returns the state afterwards. The commands depend
+//! on the day, `help` lists them. `advent_of_code repl --day N` reads the commands from stdin.
+
+use std::fmt::Write;
+
+use crate::{
+    geometry::Point3,
+    grid::Grid,
+    parse::list,
+    y2021::{
+        day_11::{self, Octopuses},
+        day_13::{self, Fold, Paper, PaperIter},
+        day_16::{self, Content, Packet},
+        day_18::{self, SnailNumber},
+        day_22::{self, Instruction},
+        day_25::{self, SeaFloor},
+    },
+    Error,
+    Result,
+};
+
+/// The days which have a model to explore
+pub const DAYS: [usize; 6] = [11, 13, 16, 18, 22, 25];
+
+/// Wider paper is summarized instead of printed
+const MAX_PRINTED_WIDTH: usize = 120;
+
+/// The commands every day has
+const COMMON: &[(&str, &str)] = &[
+    ("show", "print the state"),
+    ("reset", "parse the input again"),
+    ("help", "print this message"),
+    ("quit", "end the session"),
+];
+
+/// What the session holds, together with how far it got
+enum Model {
+    Octopuses { octos: Octopuses, step: usize, flashes: usize },
+    Paper { paper: Paper, folds: PaperIter, folded: usize, dots: Option<Vec<Vec<bool>>> },
+    Packet(Packet),
+    SnailNumbers { numbers: Vec<SnailNumber>, sum: Option<SnailNumber>, added: usize },
+    Reactor(Vec<Instruction>),
+    SeaFloor { floor: SeaFloor, step: usize, settled: bool },
+}
+
+pub struct Session {
+    day: usize,
+    input: String,
+    model: Model,
+}
+
+impl Session {
+    /// Parses the input of a day of 2021, fails for days without a model in [`DAYS`]
+    pub fn load(day: usize, input: &str) -> Result<Self> {
+        Ok(Session { day, input: input.to_string(), model: parse(day, input)? })
+    }
+
+    pub fn day(&self) -> usize {
+        self.day
+    }
+
+    /// Runs a command like `step 10`, returns the state afterwards or why the command failed.
+    /// `quit` is left to the caller
+    pub fn execute(&mut self, line: &str) -> std::result::Result<String, String> {
+        let line = line.trim();
+        let (command, args) = line.split_once(char::is_whitespace).map_or((line, ""), |(c, a)| (c, a.trim()));
+        match (command, &mut self.model) {
+            ("show", _) => Ok(self.show()),
+            ("reset", _) => {
+                self.model = parse(self.day, &self.input).map_err(|e| e.to_string())?;
+                Ok(self.show())
+            },
+            ("help", _) => Ok(self.help()),
+            ("step", Model::Octopuses { octos, step, flashes }) => {
+                for _ in 0..count(args)? {
+                    *step += 1;
+                    *flashes += day_11::run_step(octos);
+                }
+                Ok(self.show())
+            },
+            ("step", Model::SeaFloor { floor, step, settled }) => {
+                for _ in 0..count(args)? {
+                    if *settled {
+                        break;
+                    }
+                    *step += 1;
+                    *settled = !floor.do_move();
+                }
+                Ok(self.show())
+            },
+            ("fold", Model::Paper { folds, folded, dots, .. }) => {
+                for _ in 0..count(args)? {
+                    match folds.next() {
+                        Some(next) => {
+                            *dots = Some(next);
+                            *folded += 1;
+                        },
+                        None => break,
+                    }
+                }
+                Ok(self.show())
+            },
+            ("add", Model::SnailNumbers { numbers, sum, added }) => {
+                let number = if args.is_empty() {
+                    let number = numbers.get(*added).ok_or("all numbers of the input are added, add one like `add [1,2]`")?;
+                    *added += 1;
+                    number.clone()
+                } else {
+                    args.parse::<SnailNumber>().map_err(|e| e.to_string())?
+                };
+                *sum = Some(match sum.take() {
+                    Some(sum) => &sum + &number,
+                    None => number,
+                });
+                Ok(self.show())
+            },
+            ("eval", Model::Packet(packet)) => {
+                let path = parse_path(args)?;
+                let packet = find_packet(packet, &path).ok_or_else(|| format!("there is no packet at {}", args))?;
+                Ok(describe_packet(packet, &path))
+            },
+            ("query", Model::Reactor(instructions)) => query(instructions, args),
+            _ => Err(format!("unknown command '{}', try `help`", command)),
+        }
+    }
+
+    /// Prints the state of the model
+    pub fn show(&self) -> String {
+        match &self.model {
+            Model::Octopuses { octos, step, flashes } => format!("{}\nstep {}, {} flashes", octos, step, flashes),
+            Model::Paper { paper, folded, dots, .. } => {
+                let (width, height, printed) = match dots {
+                    Some(dots) => {
+                        let width = dots.first().map_or(0, Vec::len);
+                        let grid = Grid::new(width, dots.concat()).map_or(String::new(), |grid| grid.to_string());
+                        (width, dots.len(), grid)
+                    },
+                    None => {
+                        let printed = paper.to_string();
+                        (printed.lines().next().map_or(0, str::len), printed.lines().count(), printed.trim_end().to_string())
+                    },
+                };
+                let dot_count = printed.matches('#').count();
+                let mut summary = format!("{}x{}, {} dots, {} of {} folds done", width, height, dot_count, folded, paper.folds().len());
+                if let Some(next) = paper.folds().get(*folded) {
+                    summary += &format!(", next {}", describe_fold(next));
+                }
+                if width > MAX_PRINTED_WIDTH {
+                    format!("{} (too wide to print)", summary)
+                } else {
+                    format!("{}\n{}", printed, summary)
+                }
+            },
+            Model::Packet(packet) => describe_packet(packet, &[]),
+            Model::SnailNumbers { numbers, sum, added } => {
+                let sum = match sum {
+                    Some(sum) => format!("{}\nmagnitude {}", sum, sum.magnitude()),
+                    None => "nothing added yet".to_string(),
+                };
+                format!("{}\n{} of {} numbers of the input added", sum, added, numbers.len())
+            },
+            Model::Reactor(instructions) => format!("{} reboot steps, `query x,y,z` tells if a cube is on", instructions.len()),
+            Model::SeaFloor { floor, step, settled } => {
+                let settled = if *settled { ", nothing moved" } else { "" };
+                format!("{}\nstep {}{}", floor, step, settled)
+            },
+        }
+    }
+
+    /// Lists the commands of the day
+    pub fn help(&self) -> String {
+        let commands: &[(&str, &str)] = match self.model {
+            Model::Octopuses { .. } => &[("step [N]", "run N steps (default 1)")],
+            Model::SeaFloor { .. } => &[("step [N]", "move the herds N times (default 1), stops when nothing moves")],
+            Model::Paper { .. } => &[("fold [N]", "do the next N folds (default 1)")],
+            Model::SnailNumbers { .. } => &[("add [NUMBER]", "add the next number of the input, or NUMBER like [[1,2],3]")],
+            Model::Packet(_) => &[("eval [PATH]", "evaluate the packet, or a sub packet like 0.2 (index of the sub packet on every level)")],
+            Model::Reactor(_) => &[("query X,Y,Z", "tell if the cube is on after the reboot and which step switched it")],
+        };
+        let mut help = String::new();
+        for (command, description) in commands.iter().chain(COMMON) {
+            let _ = writeln!(help, "  {:<14}{}", command, description);
+        }
+        help.trim_end().to_string()
+    }
+}
+
+fn parse(day: usize, input: &str) -> Result<Model> {
+    Ok(match day {
+        11 => Model::Octopuses { octos: day_11::parse(input)?, step: 0, flashes: 0 },
+        13 => {
+            let paper = day_13::parse(input)?;
+            Model::Paper { folds: paper.clone().into_iter(), paper, folded: 0, dots: None }
+        },
+        16 => Model::Packet(day_16::parse(input)?),
+        18 => {
+            let numbers = day_18::parse(input)?;
+            Model::SnailNumbers { sum: numbers.first().cloned(), added: numbers.len().min(1), numbers }
+        },
+        22 => Model::Reactor(day_22::parse(input)?),
+        25 => Model::SeaFloor { floor: day_25::parse(input)?, step: 0, settled: false },
+        _ => return Err(Error::invalid(format!("day {} has no model to explore, try day {:?}", day, DAYS))),
+    })
+}
+
+/// Parses the optional count of a command, 1 if it's missing
+fn count(args: &str) -> std::result::Result<usize, String> {
+    match args {
+        "" => Ok(1),
+        args => args.parse().map_err(|_| format!("expected a number of times, got '{}'", args)),
+    }
+}
+
+fn describe_fold(fold: &Fold) -> String {
+    match fold {
+        Fold::Up(y) => format!("fold along y={}", y),
+        Fold::Left(x) => format!("fold along x={}", x),
+    }
+}
+
+/// Parses a path to a sub packet like `0.2`, empty for the outermost packet
+fn parse_path(args: &str) -> std::result::Result<Vec<usize>, String> {
+    if args.is_empty() {
+        return Ok(vec![]);
+    }
+    args.split('.').map(|i| i.parse().map_err(|_| format!("expected a path like 0.2, got '{}'", args))).collect()
+}
+
+fn find_packet<'a>(packet: &'a Packet, path: &[usize]) -> Option<&'a Packet> {
+    match (path, packet.content()) {
+        ([], _) => Some(packet),
+        ([i, rest @ ..], Content::Operator(_, packets)) => find_packet(packets.get(*i)?, rest),
+        _ => None,
+    }
+}
+
+/// The packet and its sub packets, one line each
+fn describe_packet(packet: &Packet, path: &[usize]) -> String {
+    let name = |path: &[usize]| match path {
+        [] => "packet".to_string(),
+        path => format!("packet {}", path.iter().map(usize::to_string).collect::<Vec<String>>().join(".")),
+    };
+    let line = |packet: &Packet| match packet.content() {
+        Content::Literal => format!("literal, version {}, value {}", packet.version(), packet.value()),
+        Content::Operator(type_id, packets) => format!(
+            "{:?}, version {}, value {}, {} sub packets",
+            type_id, packet.version(), packet.value(), packets.len()
+        ),
+    };
+
+    let mut description = format!("{}: {}, version sum {}", name(path), line(packet), packet.version_sum());
+    if let Content::Operator(_, packets) = packet.content() {
+        for (i, sub_packet) in packets.iter().enumerate() {
+            let sub_path = [path, &[i]].concat();
+            let _ = write!(description, "\n  {}: {}", name(&sub_path), line(sub_packet));
+        }
+    }
+    description
+}
+
+/// The last step whose cuboid contains the cube decides whether it's on
+fn query(instructions: &[Instruction], args: &str) -> std::result::Result<String, String> {
+    let cube = match list::<i64>(args, args, ',').map_err(|e| e.to_string())?[..] {
+        [x, y, z] => Point3::new(x, y, z),
+        _ => return Err(format!("expected a cube like 10,-3,7, got '{}'", args)),
+    };
+    let last = instructions.iter().enumerate().rev().find(|(_, (_, cuboid))| cuboid.contains(cube));
+    Ok(match last {
+        Some((step, (true, cuboid))) => format!("{} is on, switched on by step {} {}", cube, step + 1, cuboid),
+        Some((step, (false, cuboid))) => format!("{} is off, switched off by step {} {}", cube, step + 1, cuboid),
+        None => format!("{} is off, no step switches it", cube),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Session;
+
+    fn load(day: usize) -> Session {
+        Session::load(day, &std::fs::read_to_string(format!("data/2021/day_{}_test.txt", day)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_step_octopuses() {
+        let mut session = load(11);
+        assert!(session.execute("step").unwrap().ends_with("step 1, 0 flashes"));
+        let state = session.execute("step 9").unwrap();
+        assert!(state.starts_with("0481112976\n"));
+        assert!(state.ends_with("step 10, 204 flashes"));
+        assert!(session.execute("step ten").is_err());
+        assert!(session.execute("reset").unwrap().ends_with("step 0, 0 flashes"));
+    }
+
+    #[test]
+    fn test_step_sea_floor() {
+        let mut session = load(25);
+        assert!(session.execute("step 100").unwrap().ends_with("step 58, nothing moved"));
+    }
+
+    #[test]
+    fn test_fold() {
+        let mut session = load(13);
+        assert!(session.show().ends_with("11x15, 18 dots, 0 of 2 folds done, next fold along y=7"));
+        assert!(session.execute("fold").unwrap().ends_with("11x7, 17 dots, 1 of 2 folds done, next fold along x=5"));
+        let state = session.execute("fold 5").unwrap();
+        assert!(state.starts_with("#####\n#...#\n"));
+        assert!(state.ends_with("5x7, 16 dots, 2 of 2 folds done"));
+    }
+
+    #[test]
+    fn test_add() {
+        let input = "[1,1]\n[2,2]\n";
+        let mut session = Session::load(18, input).unwrap();
+        assert_eq!(session.execute("add").unwrap(), "[[1,1],[2,2]]\nmagnitude 35\n2 of 2 numbers of the input added");
+        assert!(session.execute("add").is_err());
+        assert!(session.execute("add [3,3]").unwrap().starts_with("[[[1,1],[2,2]],[3,3]]\n"));
+        assert_eq!(session.execute("add [3,").unwrap_err(), "line 1, column 4: expected a number or '['");
+    }
+
+    #[test]
+    fn test_eval() {
+        let mut session = Session::load(16, "9C0141080250320F1802104A08").unwrap();
+        assert_eq!(
+            session.execute("eval").unwrap(),
+            "packet: Eq, version 4, value 1, 2 sub packets, version sum 20\n  \
+             packet 0: Sum, version 2, value 4, 2 sub packets\n  \
+             packet 1: Prod, version 6, value 4, 2 sub packets"
+        );
+        assert_eq!(session.execute("eval 1.0").unwrap(), "packet 1.0: literal, version 0, value 2, version sum 0");
+        assert!(session.execute("eval 2").is_err());
+        assert!(session.execute("eval 1.0.0").is_err());
+    }
+
+    #[test]
+    fn test_query() {
+        let mut session = Session::load(22, "on x=10..12,y=10..12,z=10..12\noff x=11..11,y=11..11,z=11..11\n").unwrap();
+        assert_eq!(session.execute("query 10,12,11").unwrap(), "10,12,11 is on, switched on by step 1 (10-12, 10-12, 10-12)");
+        assert_eq!(session.execute("query 11, 11, 11").unwrap(), "11,11,11 is off, switched off by step 2 (11-11, 11-11, 11-11)");
+        assert_eq!(session.execute("query 0,0,0").unwrap(), "0,0,0 is off, no step switches it");
+        assert!(session.execute("query 1,2").is_err());
+        assert!(session.execute("step").unwrap_err().starts_with("unknown command 'step'"));
+    }
+
+    #[test]
+    fn test_load_and_help() {
+        assert!(Session::load(1, "1\n2\n").is_err());
+        assert!(Session::load(25, "v.x\n").is_err());
+        let help = Session::load(25, "v.\n").unwrap().help();
+        assert!(help.starts_with("  step [N]      move the herds"));
+        assert!(help.ends_with("  quit          end the session"));
+    }
+}
@@ -0,0 +1,318 @@
+//! Searches for the cheapest path through a graph: Dijkstra, A* and breadth first search.
+//!
+//! A day describes its graph with a [`SearchProblem`], the searches return the cost and the path
+//! to the first goal they reach, together with some [`Stats`]. They check [`limit::stopped`]
+//! and return without a path if the solver is stopped.
+
+use std::{cmp::{Ordering, Reverse}, collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque}, hash::Hash};
+
+use crate::{event, limit};
+
+/// A graph which is explored from a start state until a goal is reached
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+
+    fn start(&self) -> Self::State;
+
+    /// The states which can be reached from `state` in one step, together with the cost of the step
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, usize)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// Estimated cost from `state` to the nearest goal, used by [`astar`].
+    /// It must never be larger than the actual cost, or the path isn't the cheapest
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+/// A path from the start to a goal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    /// Sum of the costs of all steps
+    pub cost: usize,
+    /// The states from the start to the goal, both included
+    pub states: Vec<S>,
+}
+
+/// How much work a search did
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States which were taken from the frontier and whose successors were generated
+    pub expanded: usize,
+    /// States which were added to the frontier
+    pub generated: usize,
+    /// Most states in the frontier at once
+    pub max_frontier: usize,
+}
+
+/// The result of a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Searched<S> {
+    /// None if no goal can be reached or the search was stopped
+    pub path: Option<Path<S>>,
+    pub stats: Stats,
+}
+
+impl<S> Searched<S> {
+    /// Returns the cost of the path, None if there is none
+    pub fn cost(&self) -> Option<usize> {
+        self.path.as_ref().map(|path| path.cost)
+    }
+}
+
+/// Finds the cheapest path, visiting the states in the order of their cost
+pub fn dijkstra<P: SearchProblem>(problem: &P) -> Searched<P::State> {
+    best_first(problem, false, &mut |_| ())
+}
+
+/// Finds the cheapest path, visiting the states in the order of their cost plus their heuristic
+pub fn astar<P: SearchProblem>(problem: &P) -> Searched<P::State> {
+    best_first(problem, true, &mut |_| ())
+}
+
+/// Like [`astar`], calls `visit` with every state which is expanded
+pub fn astar_visiting<P: SearchProblem>(problem: &P, visit: &mut dyn FnMut(&P::State)) -> Searched<P::State> {
+    best_first(problem, true, visit)
+}
+
+/// Finds the path with the fewest steps, which is only the cheapest if all steps cost the same
+pub fn bfs<P: SearchProblem>(problem: &P) -> Searched<P::State> {
+    let mut stats = Stats::default();
+    let mut states = Arena::default();
+    let start = problem.start();
+    let mut frontier = VecDeque::from([(states.insert(start.clone()), start)]);
+    stats.generated = 1;
+
+    while let Some((id, state)) = frontier.pop_front() {
+        if limit::stopped() {
+            break;
+        }
+        stats.max_frontier = stats.max_frontier.max(frontier.len() + 1);
+        if problem.is_goal(&state) {
+            return states.found(id, stats);
+        }
+        stats.expanded += 1;
+        for (next, step) in problem.successors(&state) {
+            // the first path to a state has the fewest steps
+            if !states.ids.contains_key(&next) {
+                let cost = states.nodes[id].cost + step;
+                frontier.extend(states.improve(next, cost, id));
+                stats.generated += 1;
+            }
+        }
+    }
+
+    Searched { path: None, stats }
+}
+
+fn best_first<P: SearchProblem>(problem: &P, heuristic: bool, visit: &mut dyn FnMut(&P::State)) -> Searched<P::State> {
+    let estimate = |state: &P::State| if heuristic { problem.heuristic(state) } else { 0 };
+    let mut stats = Stats::default();
+    let mut states = Arena::default();
+    let start = problem.start();
+    let priority = estimate(&start);
+    let mut frontier = BinaryHeap::from([Queued { priority, cost: 0, id: states.insert(start.clone()), state: start }]);
+    stats.generated = 1;
+
+    while let Some(Queued { cost, id, state, .. }) = frontier.pop() {
+        if limit::stopped() {
+            break;
+        }
+        // a cheaper path to the state was found after it was added
+        if cost > states.nodes[id].cost {
+            continue;
+        }
+        event!(Trace, "expand", cost = cost, frontier = frontier.len());
+        visit(&state);
+        if problem.is_goal(&state) {
+            event!(Debug, "found", cost = cost, expanded = stats.expanded, states = states.nodes.len());
+            return states.found(id, stats);
+        }
+        stats.expanded += 1;
+        for (next, step) in problem.successors(&state) {
+            let cost = cost + step;
+            let priority = cost + estimate(&next);
+            if let Some((id, state)) = states.improve(next, cost, id) {
+                frontier.push(Queued { priority, cost, id, state });
+                stats.generated += 1;
+            }
+        }
+        stats.max_frontier = stats.max_frontier.max(frontier.len());
+    }
+
+    Searched { path: None, stats }
+}
+
+/// A state in the frontier of a best first search, the one with the lowest priority comes first
+struct Queued<S> {
+    priority: usize,
+    cost: usize,
+    id: usize,
+    state: S,
+}
+
+impl<S> Queued<S> {
+    fn key(&self) -> Reverse<(usize, usize, usize)> {
+        Reverse((self.priority, self.cost, self.id))
+    }
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+struct Node {
+    /// Cost of the cheapest path found so far
+    cost: usize,
+    parent: Option<usize>,
+}
+
+/// The states which were reached and the cheapest paths to them.
+/// The states are only kept in the map, the frontiers hold a copy until they're expanded
+struct Arena<S> {
+    nodes: Vec<Node>,
+    ids: HashMap<S, usize>,
+}
+
+impl<S> Default for Arena<S> {
+    fn default() -> Self {
+        Arena { nodes: vec![], ids: HashMap::new() }
+    }
+}
+
+impl<S: Clone + Eq + Hash> Arena<S> {
+    /// Adds the start
+    fn insert(&mut self, state: S) -> usize {
+        let id = self.nodes.len();
+        self.ids.insert(state, id);
+        self.nodes.push(Node { cost: 0, parent: None });
+        id
+    }
+
+    /// Records a path to `state` if it's new or cheaper than the known one.
+    /// Returns the id of the state and a copy for the frontier if it was
+    fn improve(&mut self, state: S, cost: usize, parent: usize) -> Option<(usize, S)> {
+        match self.ids.entry(state) {
+            Entry::Occupied(entry) => {
+                let node = &mut self.nodes[*entry.get()];
+                if cost >= node.cost {
+                    return None;
+                }
+                node.cost = cost;
+                node.parent = Some(parent);
+                Some((*entry.get(), entry.key().clone()))
+            },
+            Entry::Vacant(entry) => {
+                let id = self.nodes.len();
+                self.nodes.push(Node { cost, parent: Some(parent) });
+                let state = entry.key().clone();
+                entry.insert(id);
+                Some((id, state))
+            },
+        }
+    }
+
+    /// Follows the parents from the goal back to the start
+    fn found(&self, goal: usize, stats: Stats) -> Searched<S> {
+        let mut by_id = vec![None; self.nodes.len()];
+        for (state, &id) in &self.ids {
+            by_id[id] = Some(state);
+        }
+        let mut states = vec![];
+        let mut next = Some(goal);
+        while let Some(id) = next {
+            states.extend(by_id[id].cloned());
+            next = self.nodes[id].parent;
+        }
+        states.reverse();
+        Searched { path: Some(Path { cost: self.nodes[goal].cost, states }), stats }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+
+    use super::{astar, bfs, dijkstra, SearchProblem};
+
+    /// Walks from the top left to the bottom right corner, entering a cell costs its digit, 0 is a wall
+    struct Walk(Grid<u8>);
+
+    impl SearchProblem for Walk {
+        type State = (usize, usize);
+
+        fn start(&self) -> Self::State {
+            (0, 0)
+        }
+
+        fn successors(&self, &(x, y): &Self::State) -> Vec<(Self::State, usize)> {
+            self.0.neighbours4(x, y).filter(|&next| self.0[next] != 0).map(|next| (next, self.0[next] as usize)).collect()
+        }
+
+        fn is_goal(&self, state: &Self::State) -> bool {
+            *state == (self.0.width() - 1, self.0.height() - 1)
+        }
+
+        fn heuristic(&self, &(x, y): &Self::State) -> usize {
+            (self.0.width() - 1 - x) + (self.0.height() - 1 - y)
+        }
+    }
+
+    fn create_walk(input: &str) -> Walk {
+        Walk(Grid::parse(input, input).unwrap())
+    }
+
+    // the shortest way along the bottom is expensive
+    const WINDING: &str = "10111\n10101\n11101\n90001\n99991";
+
+    #[test]
+    fn test_cheapest_path() {
+        let walk = create_walk(WINDING);
+        let searched = dijkstra(&walk);
+        let path = searched.path.unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.states.len(), 13);
+        assert_eq!(path.states[..6], [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1)]);
+        assert_eq!(path.states.last(), Some(&(4, 4)));
+        assert!(searched.stats.expanded > 0);
+        assert!(searched.stats.max_frontier > 0);
+        assert!(searched.stats.generated >= searched.stats.expanded);
+
+        let searched = astar(&walk);
+        assert_eq!(searched.cost(), Some(12));
+        assert!(searched.stats.expanded <= dijkstra(&walk).stats.expanded);
+    }
+
+    #[test]
+    fn test_fewest_steps() {
+        let path = bfs(&create_walk(WINDING)).path.unwrap();
+        assert_eq!(path.states.len(), 9);
+        assert_eq!(path.cost, 1 + 1 + 9 + 9 + 9 + 9 + 9 + 1);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let walk = create_walk("110\n101\n011");
+        assert!(dijkstra(&walk).path.is_none());
+        assert!(astar(&walk).path.is_none());
+        assert!(bfs(&walk).path.is_none());
+        assert_eq!(dijkstra(&create_walk("1")).cost(), Some(0));
+    }
+}
@@ -0,0 +1,434 @@
+//! A local HTTP server which runs the solvers for tools which aren't written in Rust.
+//!
+//! `POST /solve/<DAY>/<PART>` solves the puzzle input in the body, `?year=<YEAR>` picks another
+//! season than the latest. `GET /days` lists the days of every season. Everything is answered in JSON.
+//! [`handle`] answers a single request, so the routes can be tested without a socket, [`Server`]
+//! serves them over TCP. The `server` binary (built with `--features server`) runs one.
+
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    event,
+    http::{Method, Request, Response},
+    latest_year,
+    limit::{self, Limited, Limits, Stop, Token},
+    season,
+    Answer,
+    Error,
+    Result,
+    SEASONS,
+};
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:3021";
+
+pub const USAGE: &str = "\
+Usage: server [OPTIONS]
+
+Serves the solvers over HTTP:
+  POST /solve/<DAY>/<PART>[?year=<YEAR>]  Solves the input in the body
+  GET  /days                              Lists the days of every season
+
+Options:
+  -a, --address <ADDR>  Address to listen on (default 127.0.0.1:3021)
+  -v, --verbose         Trace every request to stderr, -vv adds parsing and solver summaries
+      --timeout <SECS>  Stop a part which runs longer, it's answered with TIMEOUT
+      --max-memory <MB> Stop a part which allocates more, it's answered with OUT OF MEMORY
+  -h, --help            Print this message";
+
+/// Larger bodies are refused, the puzzle inputs are a few KB
+const MAX_BODY: usize = 1 << 24;
+/// Time a client has to send its request
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config {
+    pub address: String,
+    /// Time and memory every part may use, unlimited by default
+    pub limits: Limits,
+    /// Number of -v, 0 doesn't trace anything
+    pub verbose: u8,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { address: DEFAULT_ADDRESS.to_string(), limits: Limits::default(), verbose: 0 }
+    }
+}
+
+/// Parses the arguments of the server binary, without its name. Returns None if help was asked for
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> std::result::Result<Option<Config>, String> {
+    let mut config = Config::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+        match arg.as_str() {
+            "-a" | "--address" => config.address = value()?,
+            "-v" | "--verbose" => config.verbose += 1,
+            "-vv" => config.verbose += 2,
+            "--timeout" => {
+                let value = value()?;
+                let timeout = value.parse().ok().and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+                match timeout.filter(|timeout| !timeout.is_zero()) {
+                    Some(timeout) => config.limits.timeout = Some(timeout),
+                    None => return Err(format!("--timeout has to be a positive number of seconds, got {}", value)),
+                }
+            },
+            "--max-memory" => {
+                let value = value()?;
+                match value.parse::<usize>() {
+                    Ok(megabytes) if megabytes > 0 => config.limits.memory = Some(megabytes.saturating_mul(1 << 20)),
+                    _ => return Err(format!("--max-memory has to be at least 1, got {}", value)),
+                }
+            },
+            "-h" | "--help" => return Ok(None),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+    Ok(Some(config))
+}
+
+/// Answers a request, its `url` is the path with the query like `/solve/1/2?year=2021`
+pub fn handle(request: &Request, limits: Limits) -> Response {
+    let (path, query) = request.url.split_once('?').unwrap_or((&request.url, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match (request.method, segments.as_slice()) {
+        (Method::Get, ["days"]) => days(),
+        (Method::Post, ["solve", day, part]) => solve(day, part, query, request.body.as_deref().unwrap_or_default(), limits),
+        (_, ["days"] | ["solve", _, _]) => error(405, "method not allowed"),
+        _ => error(404, format!("there is nothing at {}", path)),
+    }
+}
+
+/// `{"seasons": [{"year": 2021, "days": [1, 2, ...]}]}`
+fn days() -> Response {
+    let seasons = SEASONS
+        .iter()
+        .map(|season| {
+            let days = season.days().map(|(day, _)| day.to_string()).collect::<Vec<String>>();
+            object(&[("year", season.year.to_string()), ("days", format!("[{}]", days.join(", ")))])
+        })
+        .collect::<Vec<String>>();
+    Response { status: 200, body: object(&[("seasons", format!("[{}]", seasons.join(", ")))]) }
+}
+
+/// Runs one part, the answer is a string like the CLI prints it, or null if the day has no such part.
+/// Errors in the input are answered with 422 and the error, with its line and column if it has a position,
+/// a solver which panics with 500
+fn solve(day: &str, part: &str, query: &str, input: &str, limits: Limits) -> Response {
+    let year = match query.split('&').find_map(|pair| pair.strip_prefix("year=")) {
+        Some(year) => match year.parse() {
+            Ok(year) => year,
+            Err(_) => return error(400, format!("invalid year '{}'", year)),
+        },
+        None => latest_year(),
+    };
+    let Some(season) = season(year) else {
+        return error(404, format!("there are no solutions for {}", year));
+    };
+    let Some((day, solver)) = day.parse().ok().and_then(|day| Some((day, season.solver(day)?))) else {
+        return error(404, format!("day has to be between 1 and {}, got {}", season.solvers.len(), day));
+    };
+    let part = match part.parse() {
+        Ok(part @ (1 | 2)) => part,
+        _ => return error(404, format!("part has to be 1 or 2, got {}", part)),
+    };
+
+    let input: Arc<str> = input.into();
+    answer_part(year, day, part, limits, move || solver.solve(part, &input).unwrap_or(Ok(Answer::None)))
+}
+
+/// Runs `job` within the limits and answers with its answer, its error or the reason it was stopped
+fn answer_part(
+    year: u16,
+    day: usize,
+    part: u8,
+    limits: Limits,
+    job: impl FnOnce() -> Result<Answer> + Send + 'static,
+) -> Response {
+    let Limited { result, elapsed, memory } = limit::run(limits, Token::new(), job);
+
+    let mut fields = vec![
+        ("year", year.to_string()),
+        ("day", day.to_string()),
+        ("part", part.to_string()),
+        ("elapsed_us", elapsed.as_micros().to_string()),
+        ("memory_bytes", memory.map_or("null".to_string(), |bytes| bytes.to_string())),
+    ];
+    let status = match result {
+        Ok(Ok(Answer::None)) => {
+            fields.push(("answer", "null".to_string()));
+            200
+        },
+        Ok(Ok(answer)) => {
+            fields.push(("answer", string(&answer.to_string())));
+            200
+        },
+        Ok(Err(e)) => {
+            let (line, column) = match e {
+                Error::Parse { line, column, .. } => (line.to_string(), column.to_string()),
+                _ => ("null".to_string(), "null".to_string()),
+            };
+            fields.extend([("error", string(&e.to_string())), ("line", line), ("column", column)]);
+            422
+        },
+        Err(stop) => {
+            fields.push(("error", string(&stop.to_string())));
+            if matches!(stop, Stop::Panicked(_)) { 500 } else { 503 }
+        },
+    };
+    Response { status, body: object(&fields) }
+}
+
+fn error(status: u16, reason: impl Into<String>) -> Response {
+    Response { status, body: object(&[("error", string(&reason.into()))]) }
+}
+
+/// A JSON object from keys and values which are JSON already
+fn object(fields: &[(&str, String)]) -> String {
+    let fields = fields.iter().map(|(key, value)| format!("{}: {}", string(key), value)).collect::<Vec<String>>();
+    format!("{{{}}}", fields.join(", "))
+}
+
+/// A JSON string
+fn string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Listens for requests and answers them with [`handle`]
+pub struct Server {
+    listener: TcpListener,
+    limits: Limits,
+}
+
+impl Server {
+    /// Port 0 picks a free port, [`Server::url`] tells which
+    pub fn bind(address: impl ToSocketAddrs, limits: Limits) -> io::Result<Self> {
+        Ok(Server { listener: TcpListener::bind(address)?, limits })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Returns the url the routes are under, like `http://127.0.0.1:3021`
+    pub fn url(&self) -> io::Result<String> {
+        Ok(format!("http://{}", self.local_addr()?))
+    }
+
+    /// Answers requests until the listener fails, every connection on a thread of its own.
+    /// A connection is closed after one request
+    pub fn serve(self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+            let limits = self.limits;
+            thread::spawn(move || {
+                if let Err(e) = answer(stream, limits) {
+                    event!(Info, "connection failed", error = e);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+fn answer(mut stream: TcpStream, limits: Limits) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let response = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) => {
+            let response = handle(&request, limits);
+            event!(Info, "request", method = request.method, url = request.url, status = response.status);
+            response
+        },
+        Err(response) => response,
+    };
+    write_response(&mut stream, &response)
+}
+
+/// Reads the head and the body of a request, the url is only the path with the query.
+/// A request which can't be read is answered with the error response
+fn read_request(stream: &mut impl BufRead) -> std::result::Result<Request, Response> {
+    let bad_request = |reason: &str| error(400, reason);
+    let mut line = String::new();
+    stream.read_line(&mut line).map_err(|_| bad_request("unable to read the request"))?;
+    let mut parts = line.split_whitespace();
+    let method = match parts.next() {
+        Some("GET") => Method::Get,
+        Some("POST") => Method::Post,
+        Some(_) => return Err(error(405, "method not allowed")),
+        None => return Err(bad_request("empty request")),
+    };
+    let url = parts.next().ok_or_else(|| bad_request("missing path"))?.to_string();
+
+    let mut headers = vec![];
+    let mut length = 0;
+    loop {
+        line.clear();
+        stream.read_line(&mut line).map_err(|_| bad_request("unable to read the headers"))?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':').ok_or_else(|| bad_request("malformed header"))?;
+        let (name, value) = (name.trim(), value.trim());
+        if name.eq_ignore_ascii_case("content-length") {
+            length = value.parse().map_err(|_| bad_request("invalid Content-Length"))?;
+        }
+        headers.push((name.to_string(), value.to_string()));
+    }
+    if length > MAX_BODY {
+        return Err(error(413, format!("the body is larger than {} bytes", MAX_BODY)));
+    }
+
+    let mut body = vec![0; length];
+    stream.read_exact(&mut body).map_err(|_| bad_request("the body is shorter than its Content-Length"))?;
+    let body = String::from_utf8(body).map_err(|_| bad_request("the body isn't UTF-8"))?;
+    Ok(Request { method, url, headers, body: (method == Method::Post).then_some(body) })
+}
+
+fn write_response(stream: &mut impl Write, response: &Response) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status, reason, response.body.len(), response.body
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, time::Duration};
+
+    use crate::{http::{Method, Request}, limit::Limits};
+
+    use super::{answer_part, handle, parse_args, read_request, string, write_response, Config};
+
+    fn solve(url: &str, body: &str) -> (u16, String) {
+        let response = handle(&Request::post(url, body), Limits::default());
+        (response.status, response.body)
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let (status, body) = solve("/solve/1/1", input);
+        assert_eq!(status, 200);
+        assert!(body.starts_with("{\"year\": 2021, \"day\": 1, \"part\": 1, \"elapsed_us\": "));
+        assert!(body.ends_with(", \"answer\": \"7\"}"));
+        assert!(solve("/solve/1/2?year=2021", input).1.ends_with("\"answer\": \"5\"}"));
+        assert!(solve("/solve/25/2", "v.\n.>\n").1.ends_with("\"answer\": null}"));
+    }
+
+    #[test]
+    fn test_solve_invalid_input() {
+        let (status, body) = solve("/solve/1/1", "199\nx\n");
+        assert_eq!(status, 422);
+        assert!(body.ends_with("\"error\": \"line 2, column 1: can't parse 'x'\", \"line\": 2, \"column\": 1}"));
+
+        let (status, body) = solve("/solve/4/1", "");
+        assert_eq!(status, 422);
+        assert!(body.ends_with("\"line\": null, \"column\": null}"));
+    }
+
+    #[test]
+    fn test_solve_panic() {
+        let response = answer_part(2021, 1, 1, Limits::default(), || panic!("a bug in the solver"));
+        assert_eq!(response.status, 500);
+        assert!(response.body.ends_with("\"error\": \"PANICKED (a bug in the solver)\"}"));
+
+        let mut written = vec![];
+        write_response(&mut written, &response).unwrap();
+        assert!(written.starts_with(b"HTTP/1.1 500 Internal Server Error\r\n"));
+    }
+
+    #[test]
+    fn test_routes() {
+        assert_eq!(solve("/solve/26/1", "").0, 404);
+        assert_eq!(solve("/solve/one/1", "").0, 404);
+        assert_eq!(solve("/solve/1/3", "").0, 404);
+        assert_eq!(solve("/solve/1/1?year=2015", "").0, 404);
+        assert_eq!(solve("/solve/1/1?year=last", "").0, 400);
+        assert_eq!(solve("/solve/1", "").0, 404);
+        assert_eq!(handle(&Request::get("/solve/1/1"), Limits::default()).status, 405);
+
+        let response = handle(&Request::get("/days"), Limits::default());
+        assert_eq!(response.status, 200);
+        assert!(response.body.starts_with("{\"seasons\": [{\"year\": 2021, \"days\": [1, 2, 3,"));
+        assert!(response.body.ends_with(" 24, 25]}]}"));
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw = "POST /solve/1/2 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 4\r\n\r\n1\n2\nignored";
+        let request = read_request(&mut Cursor::new(raw)).unwrap();
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "/solve/1/2");
+        assert_eq!(request.body.as_deref(), Some("1\n2\n"));
+
+        assert_eq!(read_request(&mut Cursor::new("GET /days HTTP/1.1\r\n\r\n")).unwrap().body, None);
+        assert_eq!(read_request(&mut Cursor::new("DELETE /days HTTP/1.1\r\n\r\n")).unwrap_err().status, 405);
+        assert_eq!(read_request(&mut Cursor::new("POST / HTTP/1.1\r\nContent-Length: 9\r\n\r\n1")).unwrap_err().status, 400);
+        assert_eq!(read_request(&mut Cursor::new("POST / HTTP/1.1\r\nContent-Length: 99999999\r\n\r\n")).unwrap_err().status, 413);
+        assert_eq!(read_request(&mut Cursor::new(&b"POST / HTTP/1.1\r\nContent-Length: 1\r\n\r\n\xff"[..])).unwrap_err().status, 400);
+    }
+
+    #[test]
+    fn test_write_response() {
+        let mut written = vec![];
+        write_response(&mut written, &handle(&Request::get("/nowhere"), Limits::default())).unwrap();
+        let written = String::from_utf8(written).unwrap();
+        assert!(written.starts_with("HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: 41\r\n"));
+        assert!(written.ends_with("\r\n\r\n{\"error\": \"there is nothing at /nowhere\"}"));
+    }
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("a \"b\"\n\\c\u{1}"), "\"a \\\"b\\\"\\n\\\\c\\u0001\"");
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &str| parse_args(args.split_whitespace().map(|arg| arg.to_string()));
+        assert_eq!(parse(""), Ok(Some(Config::default())));
+        assert_eq!(parse("--help"), Ok(None));
+
+        let limits = Limits { timeout: Some(Duration::from_secs(5)), memory: Some(64 << 20) };
+        let expected = Config { address: "0.0.0.0:8080".to_string(), limits, verbose: 1 };
+        assert_eq!(parse("-a 0.0.0.0:8080 --timeout 5 --max-memory 64 -v"), Ok(Some(expected)));
+
+        assert!(parse("--address").is_err());
+        assert!(parse("--timeout 0").is_err());
+        assert!(parse("--max-memory none").is_err());
+        assert!(parse("--port 80").is_err());
+    }
+}
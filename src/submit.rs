@@ -0,0 +1,304 @@
+//! Submits answers to the website and keeps a ledger of all attempts,
+//! so answers which are known to be wrong aren't submitted again.
+
+use std::{fmt::Display, fs::{self, OpenOptions}, io::{self, Write}, path::{Path, PathBuf}, str::FromStr};
+
+use crate::{error::parse_at, fetch::Client, http::Request, Error, Result};
+
+/// File name of the ledger in an input directory
+pub const LEDGER_FILE: &str = "submissions.tsv";
+
+/// What the website said about an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction
+    Wrong,
+    /// An answer was submitted too recently, the answer wasn't checked
+    RateLimited,
+    /// The part is already solved or not unlocked yet, the answer wasn't checked
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Recognizes the outcome in the page the website returns after a submission
+    pub fn from_page(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Some(Outcome::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Outcome::TooLow)
+            } else {
+                Some(Outcome::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Some(Outcome::RateLimited)
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Outcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Returns if the answer was checked and is wrong
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let outcome = match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited => "rate limited",
+            Outcome::AlreadySolved => "already solved",
+        };
+        write!(f, "{}", outcome)
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too high" => Ok(Outcome::TooHigh),
+            "too low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            "rate limited" => Ok(Outcome::RateLimited),
+            "already solved" => Ok(Outcome::AlreadySolved),
+            _ => Err(format!("unknown outcome '{}'", s)),
+        }
+    }
+}
+
+/// The reply to a submission, `message` is the text of the page without markup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub outcome: Outcome,
+    pub message: String,
+}
+
+/// Posts an answer to the website
+pub fn submit(client: &Client, year: u16, day: usize, part: u8, answer: &str) -> Result<Submission> {
+    let url = client.url(&format!("/{}/day/{}/answer", year, day));
+    let response = client.send(Request::post_form(&url, format!("level={}&answer={}", part, url_encode(answer))))?;
+    if !response.is_success() {
+        return Err(Error::http(url, format!("status {}", response.status)));
+    }
+
+    let outcome = Outcome::from_page(&response.body).ok_or_else(|| Error::http(&url, "unknown reply to the answer"))?;
+    Ok(Submission { outcome, message: page_text(&response.body) })
+}
+
+fn url_encode(value: &str) -> String {
+    value.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+// the message is inside of <article>, all tags are removed and whitespace is collapsed
+fn page_text(page: &str) -> String {
+    let article = page.split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// One submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: usize,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// All submitted answers, stored as tab separated lines `year day part outcome answer`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// Reads the ledger at `path`, a missing file is an empty ledger
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(Error::from(e).in_file(path.display().to_string())),
+        };
+        let attempts = Self::parse(&content).map_err(|e| e.in_file(path.display().to_string()))?;
+        Ok(Ledger { path, attempts })
+    }
+
+    fn parse(content: &str) -> Result<Vec<Attempt>> {
+        content.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let fields = line.splitn(5, '\t').collect::<Vec<&str>>();
+                let [year, day, part, outcome, answer] = fields[..] else {
+                    return Err(Error::at(content, line, "expected 'year day part outcome answer' separated by tabs"));
+                };
+                Ok(Attempt {
+                    year: parse_at(content, year)?,
+                    day: parse_at(content, day)?,
+                    part: parse_at(content, part)?,
+                    outcome: parse_at(content, outcome)?,
+                    answer: answer.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Returns why `answer` shouldn't be submitted, None if it may be submitted.
+    /// An answer is refused if the part is solved, if it was wrong before,
+    /// or if it's outside of the bounds given by earlier too high or too low answers
+    pub fn refusal(&self, year: u16, day: usize, part: u8, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+        self.attempts
+            .iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part)
+            .find_map(|a| {
+                let other = a.answer.parse::<i128>().ok();
+                match a.outcome {
+                    Outcome::Correct => Some(format!("the part is already solved with {}", a.answer)),
+                    outcome if outcome.is_wrong() && a.answer == answer => Some(format!("{} was {} before", answer, outcome)),
+                    Outcome::TooHigh if number.zip(other).is_some_and(|(n, other)| n > other) => {
+                        Some(format!("{} is higher than {}, which was too high", answer, a.answer))
+                    },
+                    Outcome::TooLow if number.zip(other).is_some_and(|(n, other)| n < other) => {
+                        Some(format!("{} is lower than {}, which was too low", answer, a.answer))
+                    },
+                    _ => None,
+                }
+            })
+    }
+
+    /// Adds an attempt and appends it to the file
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        let line = format!("{}\t{}\t{}\t{}\t{}\n", attempt.year, attempt.day, attempt.part, attempt.outcome, attempt.answer);
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|e| Error::from(e).in_file(self.path.display().to_string()))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use crate::{fetch::Client, http::{stub, PlainHttp}};
+
+    use super::{page_text, submit, url_encode, Attempt, Ledger, Outcome};
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article></main>";
+
+    #[test]
+    fn test_outcome_from_page() {
+        assert_eq!(Outcome::from_page("<p>That's the right answer! You are one gold star closer.</p>"), Some(Outcome::Correct));
+        assert_eq!(Outcome::from_page(TOO_HIGH), Some(Outcome::TooHigh));
+        assert_eq!(Outcome::from_page("That's not the right answer; your answer is too low."), Some(Outcome::TooLow));
+        assert_eq!(Outcome::from_page("That's not the right answer."), Some(Outcome::Wrong));
+        assert_eq!(Outcome::from_page("You gave an answer too recently; you have 41s left to wait."), Some(Outcome::RateLimited));
+        assert_eq!(Outcome::from_page("You don't seem to be solving the right level."), Some(Outcome::AlreadySolved));
+        assert_eq!(Outcome::from_page("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_page_text() {
+        assert_eq!(
+            page_text(TOO_HIGH),
+            "That's not the right answer; your answer is too high. Please wait one minute before trying again."
+        );
+    }
+
+    #[test]
+    fn test_url_encode() {
+        assert_eq!(url_encode("-42"), "-42");
+        assert_eq!(url_encode("a b&c"), "a%20b%26c");
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, server) = stub::serve(vec![(200, TOO_HIGH), (200, "<p>That's the right answer!</p>")]);
+        let client = Client::new(Box::new(PlainHttp::default()), url, "secret", Duration::ZERO);
+        assert_eq!(submit(&client, 2021, 7, 2, "100").unwrap().outcome, Outcome::TooHigh);
+        assert_eq!(submit(&client, 2021, 7, 2, "42").unwrap().outcome, Outcome::Correct);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2021/day/7/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=100"));
+    }
+
+    #[test]
+    fn test_ledger() {
+        let path = env::temp_dir().join(format!("aoc_ledger_test_{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut ledger = Ledger::open(&path).unwrap();
+        assert_eq!(ledger.refusal(2021, 7, 2, "100"), None);
+        ledger.record(Attempt { year: 2021, day: 7, part: 2, answer: "100".to_string(), outcome: Outcome::TooHigh }).unwrap();
+        ledger.record(Attempt { year: 2021, day: 7, part: 2, answer: "10".to_string(), outcome: Outcome::TooLow }).unwrap();
+        ledger.record(Attempt { year: 2021, day: 7, part: 1, answer: "37".to_string(), outcome: Outcome::RateLimited }).unwrap();
+
+        let ledger = Ledger::open(&path).unwrap();
+        assert_eq!(ledger.attempts().len(), 3);
+        assert_eq!(ledger.refusal(2021, 7, 2, "100"), Some("100 was too high before".to_string()));
+        assert_eq!(ledger.refusal(2021, 7, 2, "101"), Some("101 is higher than 100, which was too high".to_string()));
+        assert_eq!(ledger.refusal(2021, 7, 2, "9"), Some("9 is lower than 10, which was too low".to_string()));
+        assert_eq!(ledger.refusal(2021, 7, 2, "42"), None);
+        assert_eq!(ledger.refusal(2021, 7, 1, "37"), None);
+        assert_eq!(ledger.refusal(2020, 7, 2, "100"), None);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_ledger_invalid() {
+        assert!(Ledger::parse("2021\t7\t2\ttoo high\t100\n").is_ok());
+        assert_eq!(
+            Ledger::parse("2021\t7\t2\tmaybe\t100").unwrap_err().to_string(),
+            "line 1, column 10: can't parse 'maybe'"
+        );
+        assert!(Ledger::parse("2021 7 2 correct 100").is_err());
+    }
+}
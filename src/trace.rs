@@ -0,0 +1,170 @@
+//! Spans and events which are written to stderr if the verbosity is high enough.
+//!
+//! The [`span!`](crate::span) and [`event!`](crate::event) macros check the level before
+//! anything else, so their fields aren't even evaluated while tracing is off (the default).
+//!
+//! ```
+//! use advent_of_code::{event, span};
+//!
+//! let queue = vec![1, 2, 3];
+//! let _span = span!(Debug, "search", start = (0, 0));
+//! event!(Trace, "pop", queue = queue.len());
+//! ```
+
+use std::{cell::Cell, fmt::{Debug, Write}, sync::{atomic::{AtomicU8, Ordering}, OnceLock}, time::{Duration, Instant}};
+
+/// How much is traced, every level includes the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Solving a part and reading its input
+    Info = 1,
+    /// Parsing and summaries of a solver, like the size of its search space
+    Debug = 2,
+    /// Every iteration of a solver, this is a lot of output
+    Trace = 3,
+}
+
+impl Level {
+    /// Returns the level of `-v` given `verbosity` times, None for 0
+    pub fn from_verbosity(verbosity: u8) -> Option<Level> {
+        match verbosity {
+            0 => None,
+            1 => Some(Level::Info),
+            2 => Some(Level::Debug),
+            _ => Some(Level::Trace),
+        }
+    }
+
+    fn letter(self) -> char {
+        match self {
+            Level::Info => 'I',
+            Level::Debug => 'D',
+            Level::Trace => 'T',
+        }
+    }
+}
+
+/// The highest enabled level, 0 is off
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+/// Times are relative to the moment tracing was turned on
+static START: OnceLock<Instant> = OnceLock::new();
+
+thread_local! {
+    /// Number of open spans of this thread, used to indent
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Turns tracing on up to `level`, None turns it off
+pub fn set_level(level: Option<Level>) {
+    START.get_or_init(Instant::now);
+    LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+/// Returns whether something of `level` is traced
+#[inline(always)]
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Field of a span or an event, the value is printed with its Debug format
+pub type Field<'a> = (&'static str, &'a dyn Debug);
+
+/// Formats a line of the trace, `at` is the time since tracing was turned on
+pub fn format_line(at: Duration, level: Level, depth: usize, name: &str, fields: &[Field]) -> String {
+    let mut line = format!("{:>10.3}ms {} {:indent$}{}", at.as_secs_f64() * 1000.0, level.letter(), "", name, indent = depth * 2);
+    for (key, value) in fields {
+        let _ = write!(line, " {}={:?}", key, value);
+    }
+    line
+}
+
+fn write_line(level: Level, name: &str, fields: &[Field]) {
+    let at = START.get_or_init(Instant::now).elapsed();
+    eprintln!("{}", format_line(at, level, DEPTH.with(Cell::get), name, fields));
+}
+
+/// Writes an event, use [`event!`](crate::event) instead, which checks the level first
+pub fn emit(level: Level, name: &str, fields: &[Field]) {
+    write_line(level, name, fields);
+}
+
+/// An open span, events of the same thread are indented until it's dropped.
+/// Dropping it writes how long it was open
+#[must_use = "the span is closed when it's dropped"]
+pub struct Span {
+    level: Level,
+    name: &'static str,
+    start: Instant,
+}
+
+impl Span {
+    /// Opens a span, use [`span!`](crate::span) instead, which checks the level first
+    pub fn enter(level: Level, name: &'static str, fields: &[Field]) -> Self {
+        write_line(level, name, fields);
+        DEPTH.with(|depth| depth.set(depth.get() + 1));
+        Span { level, name, start: Instant::now() }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get().saturating_sub(1)));
+        write_line(self.level, self.name, &[("done_in", &self.start.elapsed())]);
+    }
+}
+
+/// Writes an event if its level is enabled: `event!(Debug, "name", key = value, ...)`
+#[macro_export]
+macro_rules! event {
+    ($level:ident, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::emit($crate::trace::Level::$level, $name, &[$((stringify!($key), &$value)),*]);
+        }
+    };
+}
+
+/// Opens a span if its level is enabled: `let _span = span!(Debug, "name", key = value, ...);`.
+/// Returns an `Option<Span>`, which has to be kept until the span ends
+#[macro_export]
+macro_rules! span {
+    ($level:ident, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            Some($crate::trace::Span::enter($crate::trace::Level::$level, $name, &[$((stringify!($key), &$value)),*]))
+        } else {
+            None
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{enabled, format_line, Level};
+
+    #[test]
+    fn test_format_line() {
+        let at = Duration::from_micros(1500);
+        assert_eq!(format_line(at, Level::Info, 0, "solve", &[("day", &15), ("part", &2)]), "     1.500ms I solve day=15 part=2");
+        assert_eq!(format_line(at, Level::Trace, 2, "pop", &[("pos", &(1, 2))]), "     1.500ms T     pop pos=(1, 2)");
+    }
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Level::from_verbosity(0), None);
+        assert_eq!(Level::from_verbosity(2), Some(Level::Debug));
+        assert_eq!(Level::from_verbosity(7), Some(Level::Trace));
+        assert!(Level::Info < Level::Trace);
+        // nothing is traced unless the cli turns it on
+        assert!(!enabled(Level::Info));
+    }
+
+    #[test]
+    fn test_fields_not_evaluated() {
+        let mut evaluated = false;
+        crate::event!(Trace, "never", value = { evaluated = true; 1 });
+        let span = crate::span!(Debug, "never", value = { evaluated = true; 2 });
+        assert!(span.is_none());
+        assert!(!evaluated);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{parse::list, span, Answer, Error, Result, Solver};
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    solve_2(&parse(input)?)
+}
+
+/// Counts the measurements which are larger than the one before
+pub fn solve_1(measurements: &[i32]) -> usize {
+    let mut increases = 0;
+    for i in 1..measurements.len() {
+        if measurements[i] > measurements[i - 1] {
+            increases += 1;
+        }
+    }
+    increases
+}
+
+/// Counts the sums of three measurements which are larger than the one before
+pub fn solve_2(measurements: &[i32]) -> Result<usize> {
+    if measurements.len() < 3 {
+        return Err(Error::invalid("need at least three measurements"));
+    }
+    let mut increases = 0;
+    let mut sum_two = measurements[0] + measurements[1] + measurements[2];
+    for i in 3..measurements.len() {
+        let sum_one = sum_two;
+        sum_two = measurements[i] + measurements[i - 1] + measurements[i - 2];
+        if sum_two > sum_one {
+            increases += 1;
+        }
+    }
+
+    Ok(increases)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+/// Parses the depth measurements, one per line
+pub fn parse(input: &str) -> Result<Vec<i32>> {
+    let _span = span!(Debug, "parse");
+    list(input, input, '\n')
+}
\ No newline at end of file
@@ -0,0 +1,170 @@
+use crate::{common::lines, span, Answer, Error, Result, Solver};
+
+/// A line of the navigation subsystem, which only contains brackets
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line(String);
+
+impl Line {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Score of the first illegal closing bracket, None if the line isn't corrupted
+    pub fn syntax_error_score(&self) -> Option<usize> {
+        // build a stack of opening brackets
+        // push opening brackets, if closing bracket is found, compare
+        // if valid pop of stack, otherwise return
+        let mut stack = vec![];
+        for bracket in self.0.chars() {
+            if is_opening(bracket) {
+                stack.push(bracket);
+            } else if stack.last().is_some_and(|opening| is_valid_closing(*opening, bracket)) {
+                let _ = stack.pop();
+            } else {
+                return Some(get_bracket_score(bracket));
+            }
+        }
+        None
+    }
+
+    /// Score of the brackets which complete the line, None if the line is corrupted
+    pub fn completion_score(&self) -> Option<usize> {
+        discard_corrupted(vec![self.0.clone()]).pop().map(get_stack_score)
+    }
+}
+
+/// Parses the lines and checks that they only contain brackets
+pub fn parse(input: &str) -> Result<Vec<Line>> {
+    let _span = span!(Debug, "parse");
+    let lines = lines(input);
+    validate(&lines)?;
+    Ok(lines.into_iter().map(Line).collect())
+}
+
+pub fn solve_1(lines: &[Line]) -> usize {
+    lines.iter().filter_map(Line::syntax_error_score).sum()
+}
+
+/// Middle score of the incomplete lines
+pub fn solve_2(lines: &[Line]) -> Result<usize> {
+    let mut sums = lines.iter().filter_map(Line::completion_score).collect::<Vec<_>>();
+    sums.sort();
+    sums.get(sums.len() / 2).copied().ok_or_else(|| Error::invalid("no incomplete lines"))
+}
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    solve_2(&parse(input)?)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+/// Checks that the lines only contain brackets
+fn validate(lines: &[String]) -> Result<()> {
+    for (i, line) in lines.iter().enumerate() {
+        if let Some((pos, c)) = line.char_indices().find(|(_, c)| !"()[]{}<>".contains(*c)) {
+            return Err(Error::in_line(i, line, &line[pos..pos + c.len_utf8()], format!("invalid bracket '{}'", c)));
+        }
+    }
+    Ok(())
+}
+
+fn is_opening(bracket: char) -> bool {
+    bracket == '(' || bracket == '[' || bracket == '{' || bracket == '<'
+}
+
+fn is_valid_closing(opening: char, closing: char) -> bool {
+    match opening {
+        '(' => closing == ')',
+        '[' => closing == ']',
+        '{' => closing == '}',
+        '<' => closing == '>',
+        invalid => panic!("Got invalid bracket: {}", invalid)
+    }
+}
+
+fn get_bracket_score(bracket: char) -> usize {
+    match bracket {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        invalid => panic!("Got invalid bracket: {}", invalid)
+    }
+}
+
+// discard corrputed lines and return stacks of each line
+fn discard_corrupted(lines: Vec<String>) -> Vec<Vec<char>> {
+    let mut incomplete = vec![];
+    for line in &lines {
+        let mut stack = vec![];
+        let mut is_corrupted = false;
+        for bracket in line.chars() {
+            if is_opening(bracket) {
+                stack.push(bracket);
+            } else if stack.last().is_some_and(|opening| is_valid_closing(*opening, bracket)) {
+                let _ = stack.pop();
+            } else {
+                is_corrupted = true;
+            }
+        }
+
+        if !is_corrupted {
+            incomplete.push(stack);
+        }
+    } 
+
+    incomplete
+}
+
+
+fn get_stack_score(stack: Vec<char>) -> usize {
+    stack.iter().rev().fold(0, |sum, bracket| {
+        sum * 5 + match bracket {
+            '(' => 1,
+            '[' => 2,
+            '{' => 3,
+            '<' => 4,
+            invalid => panic!("Got invalid bracket: {}", invalid)
+        }
+    })
+
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::day_10::get_stack_score;
+
+    use super::{get_solution_1, get_solution_2};
+    #[test]
+    fn test_get_solution_1() {
+        let solution = get_solution_1(include_str!("../../data/2021/day_10_test.txt"));
+        assert_eq!(solution.unwrap(), 26397);
+    }
+
+    #[test]
+    fn test_get_stack_score() {
+        let stack = "[({([[{{".chars().collect::<Vec<char>>();
+        assert_eq!(get_stack_score(stack), 288957);
+    }
+
+    #[test]
+    fn test_get_solution_2() {
+        let solution = get_solution_2(include_str!("../../data/2021/day_10_test.txt"));
+        assert_eq!(solution.unwrap(), 288957);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,181 @@
+use crate::{event, grid::Grid, limit, observe::{NoObserver, Observer}, span, Answer, Error, Result, Solver};
+
+/// Energy levels of the octopuses
+pub type Octopuses = Grid<u8>;
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    Ok(solve_2(&parse(input)?))
+}
+
+pub fn solve_1(octos: &Octopuses) -> usize {
+    count_flashes(octos, 100, &mut NoObserver)
+}
+
+pub fn solve_2(octos: &Octopuses) -> usize {
+    first_sync(octos, &mut NoObserver)
+}
+
+/// Counts the flashes in some steps
+pub fn count_flashes(octos: &Octopuses, steps: usize, observer: &mut dyn Observer) -> usize {
+    let mut octos = octos.clone();
+    let mut flashes = 0;
+    for step in 1..=steps {
+        let flashed = run_step(&mut octos);
+        event!(Trace, "step", step = step, flashes = flashed);
+        flashes += flashed;
+        observer.step(step, &octos);
+    }
+
+    event!(Debug, "flashes", steps = steps, total = flashes);
+    flashes
+}
+
+/// Returns the first step in which all octopuses flash
+pub fn first_sync(octos: &Octopuses, observer: &mut dyn Observer) -> usize {
+    let mut octos = octos.clone();
+    let mut step = 0;
+    while !limit::stopped() {
+        step += 1;
+        let flashes = run_step(&mut octos);
+        event!(Trace, "step", step = step, flashes = flashes);
+        observer.step(step, &octos);
+        if flashes == octos.len() {
+            break;
+        }
+    }
+
+    event!(Debug, "synchronized", step = step);
+    step
+}
+
+/// Parses the 10x10 energy levels, one digit per octopus
+pub fn parse(input: &str) -> Result<Octopuses> {
+    let _span = span!(Debug, "parse");
+    parse_numbers(input)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+
+    fn observe(&self, part: u8, input: &str, observer: &mut dyn Observer) -> Option<Result<Answer>> {
+        match part {
+            1 => Some(parse(input).map(|octos| count_flashes(&octos, 100, observer).into())),
+            2 => Some(parse(input).map(|octos| first_sync(&octos, observer).into())),
+            _ => None,
+        }
+    }
+}
+
+fn parse_numbers(input: &str) -> Result<Octopuses> {
+    let octos = Grid::parse(input, input)?;
+    if octos.width() != 10 || octos.height() != 10 {
+        return Err(Error::invalid("expected 10x10 octopuses"));
+    }
+
+    Ok(octos)
+}
+
+/// Increases all energy levels by one and lets the octopuses flash, returns how many flashed
+pub fn run_step(octos: &mut Octopuses) -> usize {
+    // octopuses which flashed are set to 0, the ones which flash later in the step don't increase them again
+    for octo in octos.iter_mut() {
+        *octo += 1;
+    }
+
+    let mut flashes = 0;
+    for (x, y) in octos.positions() {
+        if octos[(x, y)] > 9 {
+            octos[(x, y)] = 0;
+            flash(octos, x, y, &mut flashes);
+        }
+    }
+
+    flashes
+}
+
+fn flash(octos: &mut Octopuses, x: usize, y: usize, flashes: &mut usize) {
+    *flashes += 1;
+    for (k, l) in octos.neighbours8(x, y) {
+        let n = &mut octos[(k, l)];
+        if *n == 0 {
+            continue;
+        }
+        *n += 1;
+        if *n > 9 {
+            *n = 0;
+            flash(octos, k, l, flashes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{grid::Grid, observe::NoObserver};
+
+    use super::{count_flashes, parse_numbers, Octopuses};
+
+    fn create_map() -> Octopuses {
+        parse_numbers(include_str!("../../data/2021/day_11_test.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_octo_get_some() {
+        let octos = create_map();
+        assert_eq!(octos.get(2, 2), Some(&6));
+    }
+
+    #[test]
+    fn test_octo_get_none() {
+        let octos = create_map();
+        assert!(octos.offset((0, 0), (-1, 0)).is_none());
+        assert!(octos.offset((0, 0), (0, -1)).is_none());
+        assert!(octos.get(10, 0).is_none());
+        assert!(octos.get(0, 10).is_none());
+    }
+
+    #[test]
+    fn test_one_flash() {
+        let input = "11111\n19991\n19191\n19991\n11111";
+        let octos = Grid::parse(input, input).unwrap();
+        // let expected_one = [
+        //     [3, 4, 5, 4, 3],
+        //     [4, 0, 0, 0, 4],
+        //     [5, 0, 0, 0, 5],
+        //     [4, 0, 0, 0, 4],
+        //     [3, 4, 5, 4, 3],
+        // ];
+
+        // let expected_two = [
+        //     [4, 5, 6, 5, 4],
+        //     [5, 1, 1, 1, 5],
+        //     [6, 1, 1, 1, 6],
+        //     [5, 1, 1, 1, 5],
+        //     [4, 5, 6, 5, 4],
+        // ];
+        
+        let result = count_flashes(&octos, 1, &mut NoObserver);
+        assert_eq!(result, 9)  
+    }
+    
+    #[test]
+    fn test_day_11_test() {
+        assert_eq!(super::get_solution_1(include_str!("../../data/2021/day_11_test.txt")).unwrap(), 1656);
+    }
+
+    #[test]
+    fn test_run_sync() {
+        assert_eq!(super::get_solution_2(include_str!("../../data/2021/day_11_test.txt")).unwrap(), 195);
+    }
+}
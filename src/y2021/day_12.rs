@@ -31,35 +31,97 @@
 // all other will be added sequentially by inserting at n > 1
 // use counter to add nodes
 
-pub fn get_solution_1() -> usize {
-    let lines = parse_lines("data/day_12.txt");
-    let nodes = parse_input(lines);
-    let g = Graph::new(nodes, 1);
-    let mut paths = vec![];
-    Graph::build_paths(0, g.adj_matrix, &g.l_table, &mut paths, vec!["start"]);
-        // println!("{:?}", paths);
-    paths.len()
+/// The cave system, a list of connected caves. Caves with upper case names are big
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaveSystem {
+    edges: Vec<(String, String)>,
+}
+
+impl CaveSystem {
+    pub fn edges(&self) -> &[(String, String)] {
+        &self.edges
+    }
+
+    /// Builds the adjacency matrix, every connection may be used `num_visits` times
+    pub fn graph(&self, num_visits: i8) -> Graph {
+        Graph::new(self.edges.clone(), num_visits)
+    }
+
+    /// Counts the paths from start to end which visit small caves at most once
+    pub fn count_paths(&self) -> usize {
+        let g = self.graph(1);
+        let mut paths = vec![];
+        Graph::build_paths(0, g.adj_matrix, &g.l_table, &mut paths, vec!["start"]);
+        paths.len()
+    }
+
+    /// Counts the paths from start to end, if a single small cave may be visited twice
+    pub fn count_paths_with_revisit(&self) -> usize {
+        let g = self.graph(2);
+        let mut paths = vec![];
+        Graph::build_paths_2(0, g.adj_matrix, &g.l_table, &mut paths, vec!["start"], HashSet::new(), 0);
+        paths.len()
+    }
+}
+
+/// Parses one connection like "start-A" per line
+pub fn parse(input: &str) -> Result<CaveSystem> {
+    let _span = span!(Debug, "parse");
+    Ok(CaveSystem { edges: parse_input(lines(input))? })
+}
+
+pub fn solve_1(caves: &CaveSystem) -> usize {
+    caves.count_paths()
+}
+
+pub fn solve_2(caves: &CaveSystem) -> usize {
+    caves.count_paths_with_revisit()
+}
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    Ok(solve_2(&parse(input)?))
 }
 
-pub fn get_solution_2() -> usize {
-    let lines = parse_lines("data/day_12.txt");
-    let nodes = parse_input(lines);
-    let g = Graph::new(nodes, 2);
-    let mut paths = vec![];
-    Graph::build_paths_2(0, g.adj_matrix, &g.l_table, &mut paths, vec!["start"], HashSet::new(), 0);
-    paths.len()
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
 }
 
 use std::collections::{HashMap, HashSet};
 
-use crate::parse_lines;
+use crate::{common::lines, limit, span, Answer, Error, Result, Solver};
 
-struct Graph {
+/// Adjacency matrix of the caves, start is cave 0 and end cave 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Graph {
     adj_matrix: Vec<Vec<i8>>,
     l_table: HashMap<usize, String>,
 }
 
 impl Graph {
+    /// Row i holds how often the connections from cave i may still be used, 0 if there is none
+    pub fn adj_matrix(&self) -> &[Vec<i8>] {
+        &self.adj_matrix
+    }
+
+    /// Returns the name of cave i
+    pub fn cave(&self, i: usize) -> Option<&str> {
+        self.l_table.get(&i).map(String::as_str)
+    }
+
     // first find all nodes
     // look at each touple and add them to temp HashSet
     // create l_table from HashSet
@@ -112,6 +174,9 @@ impl Graph {
         if n == 1 {
             return paths.push(path);
         }
+        if limit::stopped() {
+            return;
+        }
         
         let is_upper = is_upper_string(l_table.get(&n).unwrap());
 
@@ -124,10 +189,7 @@ impl Graph {
                 let mut new_path = path.clone();
                 if let Some(node) = l_table.get(&i) {
                     if !is_upper {
-                        for j in 0..new_adj_matrix.len() {
-                            new_adj_matrix[n][j] -= 1;
-                            new_adj_matrix[j][n] -= 1;
-                        }
+                        decrease_visits(&mut new_adj_matrix, n);
                     }
                     new_path.push(node);
                     Graph::build_paths(i, new_adj_matrix, l_table, paths, new_path);
@@ -147,6 +209,9 @@ impl Graph {
         if n == 1 {
             return paths.push(path);
         }
+        if limit::stopped() {
+            return;
+        }
 
         // so messy...
         let node = l_table.get(&n).unwrap();
@@ -167,10 +232,7 @@ impl Graph {
                 let mut new_path = path.clone();
                 if let Some(node) = l_table.get(&i) {
                     if !is_upper {
-                        for j in 0..new_adj_matrix.len() {
-                            new_adj_matrix[n][j] -= 1;
-                            new_adj_matrix[j][n] -= 1;
-                        }
+                        decrease_visits(&mut new_adj_matrix, n);
                     }
                     new_path.push(node);
                     Graph::build_paths_2(i, new_adj_matrix, l_table, paths, new_path, visited.clone(), new_threshold);
@@ -181,6 +243,16 @@ impl Graph {
 
 }
 
+// small caves can only be visited a limited amount of times, so every edge to it loses one visit
+fn decrease_visits(adj_matrix: &mut [Vec<i8>], n: usize) {
+    for visits in adj_matrix[n].iter_mut() {
+        *visits -= 1;
+    }
+    for row in adj_matrix.iter_mut() {
+        row[n] -= 1;
+    }
+}
+
 fn is_upper_string(s: &str) -> bool {
     for c in s.chars() {
         if c.is_lowercase() {
@@ -191,15 +263,27 @@ fn is_upper_string(s: &str) -> bool {
     true
 }
 
-fn parse_input(lines: Vec<String>) -> Vec<(String, String)> {
+fn parse_input(lines: Vec<String>) -> Result<Vec<(String, String)>> {
     let mut nodes = vec![];
-    for line in lines {
+    for (i, line) in lines.iter().enumerate() {
         let splits = line.split('-').collect::<Vec<&str>>();
-        assert_eq!(2, splits.len());
+        if splits.len() != 2 || splits.iter().any(|node| node.is_empty()) {
+            return Err(Error::in_line(i, line, line, "expected an edge like 'start-A'"));
+        }
+        // a path could go back and forth between them forever
+        if is_upper_string(splits[0]) && is_upper_string(splits[1]) {
+            return Err(Error::in_line(i, line, line, "two big caves can't be connected"));
+        }
         nodes.push((splits[0].to_string(), splits[1].to_string()));
     }
 
-    nodes
+    for node in ["start", "end"] {
+        if !nodes.iter().any(|(left, right)| left == node || right == node) {
+            return Err(Error::invalid(format!("missing node '{}'", node)));
+        }
+    }
+
+    Ok(nodes)
 }
 
 #[cfg(test)]
@@ -208,7 +292,7 @@ mod tests {
     use super::Graph;
     use super::is_upper_string;
     use super::parse_input;
-    use crate::parse_lines;
+    use crate::common::parse_lines;
 
     fn init_graph(num_visits: i8) -> Graph {
         let edges = vec![
@@ -240,6 +324,12 @@ mod tests {
         assert!(!is_upper_string("AAAa"));
     }
 
+    #[test]
+    fn test_connected_big_caves() {
+        let error = super::parse("start-A\nA-end\nA-B").err().unwrap();
+        assert_eq!(error.to_string(), "line 3, column 1: two big caves can't be connected");
+    }
+
     #[test]
     fn test_paths() {
         let g = init_graph(1);
@@ -250,8 +340,8 @@ mod tests {
 
     #[test]
     fn test_paths_2() {
-        let lines = parse_lines("data/day_12_test_2.txt");
-        let nodes = parse_input(lines);
+        let lines = parse_lines("data/2021/day_12_test_2.txt").unwrap();
+        let nodes = parse_input(lines).unwrap();
         let g = Graph::new(nodes, 1);
         let mut paths = vec![];
         Graph::build_paths(0, g.adj_matrix, &g.l_table, &mut paths, vec!["start"]);
@@ -261,8 +351,8 @@ mod tests {
 
     #[test]
     fn test_paths_3() {
-        let lines = parse_lines("data/day_12_test_3.txt");
-        let nodes = parse_input(lines);
+        let lines = parse_lines("data/2021/day_12_test_3.txt").unwrap();
+        let nodes = parse_input(lines).unwrap();
         let g = Graph::new(nodes, 1);
         let mut paths = vec![];
         Graph::build_paths(0, g.adj_matrix, &g.l_table, &mut paths, vec!["start"]);
@@ -282,8 +372,8 @@ mod tests {
 
     #[test]
     fn test_paths_p_2() {
-        let lines = parse_lines("data/day_12_test_2.txt");
-        let nodes = parse_input(lines);
+        let lines = parse_lines("data/2021/day_12_test_2.txt").unwrap();
+        let nodes = parse_input(lines).unwrap();
         let g = Graph::new(nodes, 2);
         let mut paths = vec![];
         Graph::build_paths_2(0, g.adj_matrix, &g.l_table, &mut paths, vec!["start"], HashSet::new(), 0);
@@ -295,8 +385,8 @@ mod tests {
 
     #[test]
     fn test_paths_p_3() {
-        let lines = parse_lines("data/day_12_test_3.txt");
-        let nodes = parse_input(lines);
+        let lines = parse_lines("data/2021/day_12_test_3.txt").unwrap();
+        let nodes = parse_input(lines).unwrap();
         let g = Graph::new(nodes, 2);
         let mut paths = vec![];
         Graph::build_paths_2(0, g.adj_matrix, &g.l_table, &mut paths, vec!["start"], HashSet::new(), 0);
@@ -1,13 +1,22 @@
 use std::fmt::Display;
 
-use crate::parse_lines;
-
-pub fn get_solution_1() -> usize{
-    let lines = parse_lines("data/day_13.txt");
-    let (points, folds) = prepare_input(lines);
-    let p = Paper::new(points, folds);
-    let mut p_iter = p.into_iter();
-    let first_fold = p_iter.next().unwrap();
+use crate::{common::lines, error::parse_in_line, observe::{NoObserver, Observer, TextFrame}, span, Answer, Error, Result, Solver};
+
+/// A dot as x, y
+pub type Point = (usize, usize);
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<String> {
+    Ok(solve_2(&parse(input)?))
+}
+
+/// Counts the dots after the first fold
+pub fn solve_1(paper: &Paper) -> usize {
+    let mut p_iter = paper.clone().into_iter();
+    let first_fold = p_iter.next().unwrap(); // there is at least one fold, checked by prepare_input
     let mut sum = 0;
     for line in first_fold {
         sum += line.iter().filter(|is_point| **is_point).count();
@@ -16,23 +25,52 @@ pub fn get_solution_1() -> usize{
     sum
 }
 
-pub fn get_solution_2() -> usize {
-    let lines = parse_lines("data/day_13.txt");
-    let (points, folds) = prepare_input(lines);
-    let p = Paper::new(points, folds);
-    let mut _final_pattern = vec![];
-    for points in p.into_iter() {
-        _final_pattern = points;
+pub fn solve_2(paper: &Paper) -> String {
+    fold(paper, &mut NoObserver)
+}
+
+/// Does all folds, returns the final pattern
+pub fn fold(paper: &Paper, observer: &mut dyn Observer) -> String {
+    let mut final_pattern = vec![];
+    for (step, points) in paper.clone().into_iter().enumerate() {
+        final_pattern = points;
+        observer.step(step + 1, &TextFrame::new(&pattern_to_string(&final_pattern)));
+    }
+    pattern_to_string(&final_pattern)
+}
+
+/// Parses the dots and the folds, there has to be at least one fold
+pub fn parse(input: &str) -> Result<Paper> {
+    let _span = span!(Debug, "parse");
+    let (points, folds) = prepare_input(lines(input))?;
+    Paper::new(points, folds)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+
+    fn observe(&self, part: u8, input: &str, observer: &mut dyn Observer) -> Option<Result<Answer>> {
+        match part {
+            2 => Some(parse(input).map(|paper| fold(&paper, observer).into())),
+            _ => None,
+        }
     }
-    // print_pattern(final_pattern);
-    0
 }
 
-fn _print_pattern(pattern: Vec<Vec<bool>>) {
+// the letters of the code are drawn with '#', the answer is read off from this
+fn pattern_to_string(pattern: &[Vec<bool>]) -> String {
     let mut s = String::new();
     for line in pattern {
         for point in line {
-            if point {
+            if *point {
                 s += "#";
             } else {
                 s += ".";
@@ -40,46 +78,51 @@ fn _print_pattern(pattern: Vec<Vec<bool>>) {
         }
         s += "\n";
     }
-    println!("{}", s);
+    s
 }
 
-fn prepare_input(lines: Vec<String>) -> (Vec<(usize, usize)>, Vec<Fold>) {
+fn prepare_input(lines: Vec<String>) -> Result<(Vec<Point>, Vec<Fold>)> {
     let mut points = vec![];
     let mut folds = vec![];
-    for line in lines {
-        if line.contains(',') {
-            let result: Vec<usize> = line.split(',').map(|s| s.parse::<usize>().unwrap()).collect();
-            assert_eq!(result.len(), 2);
-            points.push((result[0], result[1]));
-        } else if line.contains('=') {
-            let result = line.split('=').collect::<Vec<&str>>();
-            assert_eq!(result.len(), 2);
-            let fold = match &result[0][result[0].len() - 1..] {
-                "x" => Fold::Left(result[1].parse::<usize>().unwrap()),
-                "y" => Fold::Up(result[1].parse::<usize>().unwrap()),
-                _ => panic!("Invalid direction")
+    for (i, line) in lines.iter().enumerate() {
+        if let Some((x, y)) = line.split_once(',') {
+            points.push((parse_in_line(i, line, x)?, parse_in_line(i, line, y)?));
+        } else if let Some((direction, n)) = line.split_once('=') {
+            let fold = match direction.strip_prefix("fold along ") {
+                Some("x") => Fold::Left(parse_in_line(i, line, n)?),
+                Some("y") => Fold::Up(parse_in_line(i, line, n)?),
+                _ => return Err(Error::in_line(i, line, direction, "expected 'fold along x' or 'fold along y'")),
             };
             // let line = result[1].parse::<usize>().unwrap();
             folds.push(fold);
+        } else if !line.is_empty() {
+            return Err(Error::in_line(i, line, line, "expected a point or a fold"));
         }
     }
-    (points, folds)
+    if folds.is_empty() {
+        return Err(Error::invalid("missing folds"));
+    }
+    Ok((points, folds))
 } 
 
-#[derive(Debug)]
-enum Fold {
+/// A fold along a line, `Up` folds along y and `Left` along x
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Fold {
     Up(usize),
     Left(usize),
 }
 
-#[derive(Debug)]
-struct Paper {
+/// The transparent paper, iterating over it does one fold after another and yields the dots
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Paper {
     points: Vec<Vec<bool>>,
     folds: Vec<Fold>,
 }
 
 impl Paper {
-    pub fn new(points: Vec<(usize, usize)>, folds: Vec<Fold>) -> Self {
+    pub fn new(points: Vec<Point>, folds: Vec<Fold>) -> Result<Self> {
         // determine size of paper
         let mut x_dim = 0;
         let mut y_dim = 0;
@@ -89,15 +132,28 @@ impl Paper {
                 Fold::Left(l) => x_dim = l * 2 + 1,
             }
         }
-        assert_ne!(x_dim, 0);
-        assert_ne!(y_dim, 0);
+        if x_dim == 0 || y_dim == 0 {
+            return Err(Error::invalid("the first two folds have to be along x and y"));
+        }
 
         let mut m_points = vec![vec![false; x_dim]; y_dim];
         for (x, y) in points {
+            if x >= x_dim || y >= y_dim {
+                return Err(Error::invalid(format!("point {},{} is outside of the paper", x, y)));
+            }
             m_points[y][x] = true;
         }
 
-        Paper { points: m_points, folds }
+        Ok(Paper { points: m_points, folds })
+    }
+
+    pub fn folds(&self) -> &[Fold] {
+        &self.folds
+    }
+
+    /// Counts the dots before folding
+    pub fn count_dots(&self) -> usize {
+        self.points.iter().flatten().filter(|is_point| **is_point).count()
     }
 }
 
@@ -111,7 +167,7 @@ impl IntoIterator for Paper {
     }
 }
 
-struct PaperIter {
+pub struct PaperIter {
     points: Vec<Vec<bool>>,
     folds: Vec<Fold>,
     index: usize,
@@ -182,13 +238,13 @@ impl Display for Paper {
 
 #[cfg(test)]
 mod tests {
-    use crate::parse_lines;
+    use crate::common::parse_lines;
 
-    use super::{prepare_input, Fold, Paper};
+    use super::{prepare_input, Fold, Paper, Point};
 
-    fn get_test_data() -> (Vec<(usize, usize)>, Vec<Fold>) {
-        let lines = parse_lines("data/day_13_test.txt");
-        prepare_input(lines)
+    fn get_test_data() -> (Vec<Point>, Vec<Fold>) {
+        let lines = parse_lines("data/2021/day_13_test.txt").unwrap();
+        prepare_input(lines).unwrap()
     }
 
     #[test]
@@ -201,7 +257,7 @@ mod tests {
     #[test]
     fn test_paper_new() {
         let (points, folds) = get_test_data();
-        let paper = Paper::new(points, folds);
+        let paper = Paper::new(points, folds).unwrap();
         let expected_layout = "...#..#..#.
 ....#......
 ...........
@@ -231,7 +287,7 @@ mod tests {
     #[test]
     fn test_fold_test_data() {
         let (points, folds) = get_test_data();
-        let p = Paper::new(points, folds);
+        let p = Paper::new(points, folds).unwrap();
         let mut iter = p.into_iter();
         let points = iter.next().unwrap();
         let mut sum = 0;
@@ -0,0 +1,292 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{common::lines, event, span, Answer, Error, Result, Solver};
+
+type PolyMap = HashMap<String, String>;
+type GrowthMap = HashMap<String, u128>;
+type CountMap = HashMap<String, u128>;
+
+/// The polymer template and the pair insertion rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polymer {
+    template: String,
+    rules: PolyMap,
+}
+
+impl Polymer {
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Returns the element which is inserted between a pair like "CH"
+    pub fn rule(&self, pair: &str) -> Option<&str> {
+        self.rules.get(pair).map(String::as_str)
+    }
+
+    /// Builds the polymer after some steps, it doubles in length with every step
+    pub fn grow(&self, steps: usize) -> String {
+        let mut poly = self.template.clone();
+        for step in 1..=steps {
+            poly = grow(&self.rules, poly);
+            event!(Trace, "step", step = step, length = poly.len());
+        }
+        poly
+    }
+
+    /// Quantity of the most common element minus the quantity of the least common one after some steps
+    pub fn difference_after(&self, steps: usize) -> u128 {
+        grow_polymer(self, steps)
+    }
+}
+
+pub fn get_solution_1(input: &str) -> Result<u128> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<u128> {
+    Ok(solve_2(&parse(input)?))
+}
+
+pub fn solve_1(polymer: &Polymer) -> u128 {
+    let count_map = create_count_map(&polymer.grow(10));
+    let most_common = get_most_common(&count_map);
+    let least_common = get_least_common(&count_map);
+
+    most_common - least_common
+}
+
+pub fn solve_2(polymer: &Polymer) -> u128 {
+    polymer.difference_after(40)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+/// Grows the polymer by counting pairs instead of building the string
+fn grow_polymer(polymer: &Polymer, iterations: usize) -> u128 {
+    let (poly, poly_map) = (&polymer.template, &polymer.rules);
+    let mut growth_map = poly_to_growth_map(poly);
+    let mut count_map = create_count_map(poly);
+    for step in 1..=iterations {
+        growth_map = grow_map(growth_map, poly_map, &mut count_map);
+        event!(Trace, "step", step = step, pairs = growth_map.len());
+        // poly = grow(&map, poly);
+    }
+
+
+
+    // let count_map = count_map_from_growth_map(growth_map);
+    let most_common = get_most_common(&count_map);
+    let least_common = get_least_common(&count_map);
+
+    most_common - least_common
+}
+
+/// Parses the polymer template and the insertion rules.
+/// Every pair of elements has to have a rule, otherwise the polymer couldn't grow
+pub fn parse(input: &str) -> Result<Polymer> {
+    let _span = span!(Debug, "parse");
+    let lines = lines(input);
+    let poly = match lines.first() {
+        Some(poly) if !poly.is_empty() && poly.chars().all(|c| c.is_ascii_uppercase()) => poly.to_string(),
+        Some(poly) => return Err(Error::in_line(0, poly, poly, "template has to consist of elements A-Z")),
+        None => return Err(Error::invalid("missing polymer template")),
+    };
+    let map = get_poly_map(lines.get(2..).unwrap_or_default())?;
+
+    let elements: BTreeSet<char> = poly.chars().chain(map.values().flat_map(|v| v.chars())).collect();
+    for a in &elements {
+        for b in &elements {
+            if !map.contains_key(&format!("{}{}", a, b)) {
+                return Err(Error::invalid(format!("missing insertion rule for {}{}", a, b)));
+            }
+        }
+    }
+
+    Ok(Polymer { template: poly, rules: map })
+}
+
+// expects the lines after the template, which start at line index 2
+fn get_poly_map(lines: &[String]) -> Result<PolyMap> {
+    let mut map = HashMap::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let (pair, element) = line.split_once("->")
+            .ok_or_else(|| Error::in_line(i + 2, line, line, "expected a rule like 'AB -> C'"))?;
+        let (pair, element) = (pair.trim(), element.trim());
+        if pair.len() != 2 || !pair.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(Error::in_line(i + 2, line, pair, format!("invalid pair '{}'", pair)));
+        }
+        if element.len() != 1 || !element.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(Error::in_line(i + 2, line, element, format!("invalid element '{}'", element)));
+        }
+        map.insert(pair.to_owned(), element.to_owned());
+    }
+    Ok(map)
+}
+
+// for solution 1
+fn grow(poly_map: &PolyMap, poly: String) -> String {
+    let mut new_poly = poly[0..1].to_string();
+    for i in 0..poly.len() - 1 {
+        let to_insert = poly_map.get(&poly[i..i + 2]).unwrap();
+        new_poly += to_insert;
+        new_poly += &poly[i + 1..i + 2];
+    }
+
+    new_poly
+}
+
+// checks the pairs contained in a polymer and creates a map contianing the pairs as keys and the 
+// number of occurences of them as value
+fn poly_to_growth_map(poly: &str) -> GrowthMap {
+    let mut growth_map = HashMap::new();
+    for i in 0..poly.len() - 1 {
+        let entry = growth_map.entry(poly[i..i + 2].to_string()).or_insert(0);
+        *entry += 1;
+    }
+
+    growth_map
+}
+
+// "grows" the growth map
+fn grow_map(growth_map: GrowthMap, poly_map: &PolyMap, count_map: &mut CountMap) -> GrowthMap {
+    let mut new_growth_map = growth_map.clone();
+    for (k, v) in growth_map.iter() {
+        if *v == 0 {
+            continue;
+        } 
+        // determine letter to insert
+        let to_insert = poly_map.get(k).unwrap();
+
+        // update count of letters in count map
+        let count_entry = count_map.entry(to_insert.to_owned()).or_insert(0);
+        *count_entry += *v;
+
+        // create new pairs (NN creates NC and CN for example)
+        let first = k[0..1].to_string() + to_insert;
+        // second new pair generated
+        let second = to_insert.to_string() + &k[1..2];
+
+        // if there are n pairs of NN in poly, n paris of NC and CN get generated
+        let first_entry = new_growth_map.entry(first).or_insert(0);
+        *first_entry += *v;
+        let second_entry = new_growth_map.entry(second).or_insert(0);
+        *second_entry += *v;
+
+        // since NN -> NCN, need to delete old NN pairs
+        let old_entry = new_growth_map.get_mut(k).unwrap();
+        *old_entry -= *v;
+    }
+
+    new_growth_map
+}
+
+fn create_count_map(poly: &str) -> HashMap::<String, u128> {
+    let mut map = HashMap::new();
+    for c in poly.chars() {
+        let entry = map.entry(c.to_string()).or_insert(0);
+        *entry += 1;
+    }
+    map
+}
+
+fn get_most_common(count_map: &HashMap::<String, u128>) -> u128 {
+    let mut max = 0;
+    for v in count_map.values() {
+        if *v > max {
+            max = *v;
+        }
+    }
+
+    max
+}
+
+fn get_least_common(count_map: &HashMap::<String, u128>) -> u128 {
+    let mut min = u128::MAX;
+    for v in count_map.values() {
+        if *v < min {
+            min = *v;
+        }
+    }
+
+    min
+}
+
+#[cfg(test)]
+mod tests {
+    
+
+    use crate::y2021::day_14::create_count_map;
+
+    use super::{parse, grow, get_solution_1, poly_to_growth_map, grow_map, PolyMap};
+    
+    fn get_test_data() -> (String, PolyMap) {
+        let polymer = parse(include_str!("../../data/2021/day_14_test.txt")).unwrap();
+        (polymer.template, polymer.rules)
+    }
+
+    #[test]
+    fn test_grow() {
+        let (poly, poly_map) = get_test_data();
+        println!("{:?}", poly_map);
+        println!("{}", poly); 
+        grow(&poly_map, poly);
+    }
+
+    #[test]
+    fn test_solution_1() {
+        let result = get_solution_1(include_str!("../../data/2021/day_14_test.txt"));
+        assert_eq!(1588, result.unwrap());
+    }
+
+    #[test]
+    fn check_growth_map() {
+        // let result = super::get_solution_2(true, 3);
+        let (poly, poly_map) = get_test_data();
+        let mut growth_map = poly_to_growth_map(&poly);
+        let mut count_map = create_count_map(&poly);
+        println!("Template:     NNCB");
+        println!("{:?}", growth_map);
+        growth_map = grow_map(growth_map, &poly_map, &mut count_map);
+        println!("After step 1: NCNBCHB");
+        println!("{:?}", growth_map);
+        growth_map = grow_map(growth_map, &poly_map, &mut count_map);
+        println!("After step 2: NBCCNBBBCBHCB");
+        println!("{:?}", growth_map);
+        growth_map = grow_map(growth_map, &poly_map, &mut count_map);
+        println!("After step 3: NBBBCNCCNBBNBNBBCHBHHBCHB");
+        println!("{:?}", growth_map);
+        growth_map = grow_map(growth_map, &poly_map, &mut count_map);
+        println!("After step 4: NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB");
+        println!("{:?}", growth_map);
+    }
+
+    #[test]
+    fn test_solution_1_from_growth_map() {
+        let result = super::grow_polymer(&parse(include_str!("../../data/2021/day_14_test.txt")).unwrap(), 10);
+        assert_eq!(result, 1588);
+
+        let result = super::grow_polymer(&parse(include_str!("../../data/2021/day_14.txt")).unwrap(), 10);
+        assert_eq!(result, 3306)
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse("NNCB\n\nCH -> B\nHH => N").unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 1: expected a rule like 'AB -> C'");
+
+        let error = parse("NNCB\n\nNN -> C").unwrap_err();
+        assert_eq!(error.to_string(), "invalid input: missing insertion rule for BB");
+    }
+}
\ No newline at end of file
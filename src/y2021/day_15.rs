@@ -0,0 +1,160 @@
+use crate::{grid::Grid, search::{self, SearchProblem}, span, Answer, Error, Result, Solver};
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    solve_1(&parse(input)?)
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    solve_2(&parse(input)?)
+}
+
+pub fn solve_1(cave: &Cave) -> Result<usize> {
+    cave.lowest_risk().ok_or_else(stopped)
+}
+
+/// Lowest risk through the cave, which is five times larger in both directions
+pub fn solve_2(cave: &Cave) -> Result<usize> {
+    cave.enlarge(5).lowest_risk().ok_or_else(stopped)
+}
+
+// every position can be reached, the search only ends without a path if it's stopped
+fn stopped() -> Error {
+    Error::invalid("the search was stopped before it reached the bottom right")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+/// Parses the risk levels, one digit from 1 to 9 per position
+pub fn parse(input: &str) -> Result<Cave> {
+    let _span = span!(Debug, "parse");
+    let risks = Grid::<u8>::parse(input, input)?.map(|&risk| usize::from(risk));
+    // the heuristic assumes a risk of at least 1, a 0 in the parsed input is a digit of the grid
+    if let Some(i) = input.find('0') {
+        return Err(Error::at(input, &input[i..i + 1], "risk levels have to be between 1 and 9"));
+    }
+
+    Ok(Cave { risks })
+}
+
+/// Risk levels of the positions in the cave.
+/// Searching it finds the safest path from the top left to the bottom right, its states are (x, y) positions
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cave {
+    risks: Grid<usize>,
+}
+
+impl Cave {
+    pub fn risks(&self) -> &Grid<usize> {
+        &self.risks
+    }
+
+    /// Returns the number of rows and columns
+    pub fn dim(&self) -> (usize, usize) {
+        (self.risks.height(), self.risks.width())
+    }
+
+    /// Returns the risk level of a position
+    pub fn get(&self, row: usize, col: usize) -> Option<usize> {
+        self.risks.get(col, row).copied()
+    }
+
+    /// Lowest total risk of a path from the top left to the bottom right, None if the search was stopped
+    pub fn lowest_risk(&self) -> Option<usize> {
+        search::astar(self).cost()
+    }
+
+    /// Repeats the cave `scale_factor` times in both directions, the risk increases by one with every repetition
+    pub fn enlarge(&self, scale_factor: usize) -> Cave {
+        enlarge_cave(self, scale_factor)
+    }
+}
+
+/// Entering a position costs its risk level, the start isn't entered
+impl SearchProblem for Cave {
+    type State = (usize, usize);
+
+    fn start(&self) -> Self::State {
+        (0, 0)
+    }
+
+    fn successors(&self, &(x, y): &Self::State) -> Vec<(Self::State, usize)> {
+        self.risks.neighbours4(x, y).map(|next| (next, self.risks[next])).collect()
+    }
+
+    fn is_goal(&self, state: &Self::State) -> bool {
+        *state == (self.risks.width() - 1, self.risks.height() - 1)
+    }
+
+    // manhattan distance, every position has a risk of at least 1
+    fn heuristic(&self, &(x, y): &Self::State) -> usize {
+        (self.risks.width() - 1 - x) + (self.risks.height() - 1 - y)
+    }
+}
+
+#[test]
+fn test_parse() {
+    let c = parse(include_str!("../../data/2021/day_15_test.txt")).unwrap();
+    assert_eq!(c.dim(), (10, 10));
+    assert_eq!(c.risks.len(), 100);
+}
+
+#[test]
+fn test_reject_zero_risk() {
+    let error = parse("19\n10").unwrap_err();
+    assert_eq!(error.to_string(), "line 2, column 2: risk levels have to be between 1 and 9");
+    assert!(parse("19\n1a").is_err());
+}
+
+#[test]
+fn test_get() {
+    let c = parse(include_str!("../../data/2021/day_15_test.txt")).unwrap();
+    let v99 = c.get(9, 9);
+    assert_eq!(Some(1), v99);
+    let v_010 = c.get(0, 10);
+    assert_eq!(None, v_010);
+}
+
+#[test]
+fn with_test_data() {
+    let c = parse(include_str!("../../data/2021/day_15_test.txt")).unwrap();
+
+    let path = search::astar(&c).path.unwrap();
+    assert_eq!(path.cost, 40);
+    assert_eq!(path.states.first(), Some(&(0, 0)));
+    assert_eq!(path.states.last(), Some(&(9, 9)));
+    assert_eq!(path.states[1..].iter().map(|&pos| c.risks[pos]).sum::<usize>(), 40);
+    assert_eq!(search::dijkstra(&c).cost(), Some(40));
+}
+
+#[test]
+fn test_heuristic_saves_work() {
+    let c = parse(include_str!("../../data/2021/day_15_test.txt")).unwrap().enlarge(5);
+
+    let astar = search::astar(&c);
+    let dijkstra = search::dijkstra(&c);
+    assert_eq!(astar.cost(), Some(315));
+    assert_eq!(dijkstra.cost(), Some(315));
+    assert!(astar.stats.expanded <= dijkstra.stats.expanded);
+}
+
+
+fn enlarge_cave(c: &Cave, scale_factor: usize) -> Cave {
+    let (width, height) = (c.risks.width(), c.risks.height());
+    // every repetition to the right or down increases the risk by one, 9 wraps around to 1
+    let risks = Grid::from_fn(width * scale_factor, height * scale_factor, |x, y| {
+        (c.risks[(x % width, y % height)] + x / width + y / height - 1) % 9 + 1
+    });
+
+    Cave { risks }
+}
@@ -0,0 +1,322 @@
+use std::ops::Range;
+
+use crate::{span, Answer, Error, Result, Solver};
+
+// indicates where the body of a package starts
+const HEADER_LEN: usize = 6;
+
+enum PackageType {
+    Operator(TypeID),
+    Literal,
+}
+
+/// Operation of an operator packet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TypeID {
+    Sum,
+    Prod,
+    Min,
+    Max,
+    GT,
+    LT,
+    Eq,
+}
+
+impl TryFrom<u8> for TypeID {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(TypeID::Sum),
+            1 => Ok(TypeID::Prod),
+            2 => Ok(TypeID::Min),
+            3 => Ok(TypeID::Max),
+            5 => Ok(TypeID::GT),
+            6 => Ok(TypeID::LT),
+            7 => Ok(TypeID::Eq),
+            _ => Err(Error::invalid(format!("got invalid type id {} in transmission", val))),
+        }
+    }
+}
+
+/// A packet of the transmission with its sub packets
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Packet {
+    version: u32,
+    value: u64,
+    content: Content,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Content {
+    Literal,
+    Operator(TypeID, Vec<Packet>),
+}
+
+impl Packet {
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The value of a literal, or the result of an operator applied to its sub packets
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// Sum of the versions of this packet and all packets in it
+    pub fn version_sum(&self) -> u32 {
+        match &self.content {
+            Content::Literal => self.version,
+            Content::Operator(_, packets) => self.version + packets.iter().map(Packet::version_sum).sum::<u32>(),
+        }
+    }
+}
+
+/// Decodes the hexadecimal transmission into its outermost packet
+pub fn parse(input: &str) -> Result<Packet> {
+    let _span = span!(Debug, "parse");
+    let transmission = into_binary(input.trim())?;
+    Ok(parse_package(&transmission, 0)?.0)
+}
+
+pub fn solve_1(packet: &Packet) -> u32 {
+    packet.version_sum()
+}
+
+pub fn solve_2(packet: &Packet) -> u64 {
+    packet.value()
+}
+
+pub fn get_solution_1(input: &str) -> Result<u32> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<u64> {
+    Ok(solve_2(&parse(input)?))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+fn into_binary(transmission: &str) -> Result<String> {
+    let mut bin_transmission = String::new();
+    for (i, ch) in transmission.char_indices() {
+        bin_transmission += match ch {
+            '0' => "0000",
+            '1' => "0001",
+            '2' => "0010",
+            '3' => "0011",
+            '4' => "0100",
+            '5' => "0101",
+            '6' => "0110",
+            '7' => "0111",
+            '8' => "1000",
+            '9' => "1001",
+            'A' => "1010",
+            'B' => "1011",
+            'C' => "1100",
+            'D' => "1101",
+            'E' => "1110",
+            'F' => "1111",
+            _ => {
+                let part = &transmission[i..i + ch.len_utf8()];
+                return Err(Error::at(transmission, part, format!("'{}' is not a hex digit", ch)));
+            },
+        };
+    }
+
+    Ok(bin_transmission)
+}
+
+// returns the bits in range, fails if the transmission is too short
+fn bits(pkg: &str, range: Range<usize>) -> Result<&str> {
+    pkg.get(range).ok_or_else(|| Error::invalid("transmission ends in the middle of a package"))
+}
+
+fn parse_package(pkg: &str, offset: usize) -> Result<(Packet, usize)> {
+    let (version, p_type) = parse_header(bits(pkg, offset..pkg.len())?)?;
+
+    match p_type {
+        PackageType::Operator(id) => {
+            let (packets, len) = parse_operator(&pkg[offset..])?;
+            let value = compute_operator(packets.iter().map(Packet::value).collect(), id)?;
+            Ok((Packet { version, value, content: Content::Operator(id, packets) }, len))
+        },
+        PackageType::Literal => {
+            let (value, len) = parse_literal(&pkg[offset..])?;
+            Ok((Packet { version, value, content: Content::Literal }, len))
+        },
+    }
+}
+
+fn parse_header(pkg: &str) -> Result<(u32, PackageType)> {
+    // only contains 0 and 1, so parsing can't fail
+    let version = u32::from_str_radix(bits(pkg, 0..3)?, 2).unwrap();
+    match u8::from_str_radix(bits(pkg, 3..6)?, 2).unwrap() {
+        4 => Ok((version, PackageType::Literal)),
+        id => Ok((version, PackageType::Operator(id.try_into()?))),
+    }
+}
+
+fn parse_literal(pkg: &str) -> Result<(u64, usize)> {
+    let mut cursor = HEADER_LEN;
+    let mut n = String::new();
+
+    loop {
+        n += bits(pkg, cursor + 1..cursor + 5)?;
+        if bits(pkg, cursor..cursor + 1)? == "0" {
+            break;
+        }
+        cursor += 5;
+    };
+
+    let value = u64::from_str_radix(&n, 2).map_err(|_| Error::invalid("literal doesn't fit into 64 bits"))?;
+    Ok((value, cursor + 5))
+}
+
+// cursor points to first bit after header
+fn parse_operator(pkg: &str) -> Result<(Vec<Packet>, usize)> {
+    let mut packets = vec![];
+
+    // switch up length types, so we can calculate the subpackages left later
+    let (mut offset, len_type) = if bits(pkg, HEADER_LEN..HEADER_LEN + 1)? == "0" { (16, 1) } else { (12, 0) };
+    
+    offset += HEADER_LEN;
+    
+    let mut subpackages = u16::from_str_radix(bits(pkg, HEADER_LEN + 1..offset)?, 2).unwrap() as usize;
+
+    while subpackages > 0 {
+        let (packet, parsed_bits) = parse_package(pkg, offset)?;
+
+        packets.push(packet);
+        subpackages = subpackages
+            .checked_sub(parsed_bits.pow(len_type)) //basically this means: if len_type == 1 { parsed_bits } else { 1 };
+            .ok_or_else(|| Error::invalid("subpackages exceed the length of their operator"))?;
+        offset += parsed_bits;
+    }
+    
+    Ok((packets, offset))
+}
+
+fn compute_operator(values: Vec<u64>, id: TypeID) -> Result<u64> {
+    let value = match (id, values.as_slice()) {
+        (TypeID::Sum, _) => values.iter().sum(),
+        (TypeID::Prod, _) => values.iter().product(),
+        (TypeID::Min, _) => *values.iter().min().ok_or_else(|| Error::invalid("minimum of no subpackages"))?,
+        (TypeID::Max, _) => *values.iter().max().ok_or_else(|| Error::invalid("maximum of no subpackages"))?,
+        (TypeID::GT, [a, b]) => if a > b { 1 } else { 0 },
+        (TypeID::LT, [a, b]) => if a < b { 1 } else { 0 },
+        (TypeID::Eq, [a, b]) => if a == b { 1 } else { 0 },
+        _ => return Err(Error::invalid("comparison packages need exactly two subpackages")),
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{into_binary, parse_literal, parse_operator, parse_package};
+    
+    #[test]
+    fn test_into_binary() {
+        let s1 = "AC";
+        let expected1 = "10101100".to_string();
+        assert_eq!(into_binary(s1).unwrap(), expected1);
+
+        let s2 = "0123";
+        let expected2 = "0000000100100011".to_string();
+        assert_eq!(into_binary(s2).unwrap(), expected2);
+
+        let s3 = "ABCD04215467899FFE";
+        let expected3 = "101010111100110100000100001000010101010001100111100010011001111111111110".to_string();
+        assert_eq!(into_binary(s3).unwrap(), expected3);
+
+        assert_eq!(into_binary("AG").unwrap_err().to_string(), "line 1, column 2: 'G' is not a hex digit");
+    }
+
+    #[test]
+    fn test_parse_literal() {
+        let (actual_val, actual_cur) = parse_literal("110100101111111000101000").unwrap();
+
+        assert_eq!(actual_val, 2021);
+        assert_eq!(actual_cur, 21);
+    }
+
+    #[test]
+    fn test_parse_operator() {
+        let pkg1 = "00111000000000000110111101000101001010010001001000000000";
+        let (_, actual_cursor1) = parse_operator(pkg1).unwrap();
+
+        assert_eq!(actual_cursor1, 49);
+
+        let pkg2 = "11101110000000001101010000001100100000100011000001100000";
+        let (_, actual_cursor2) = parse_operator(pkg2).unwrap();
+
+        assert_eq!(actual_cursor2, 51);
+    }
+
+    #[test]
+    fn test_compute_operator() {
+        let pkg = into_binary("C200B40A82").unwrap();
+        let actual_value = parse_package(&pkg, 0).unwrap();
+        assert_eq!(actual_value.0.value(), 3);
+        
+
+        let pkg = into_binary("04005AC33890").unwrap();
+        let actual_value = parse_package(&pkg, 0).unwrap();
+        
+        assert_eq!(actual_value.0.value(), 54);
+
+        let pkg = into_binary("880086C3E88112").unwrap();
+        let actual_value = parse_package(&pkg, 0).unwrap();
+        
+        assert_eq!(actual_value.0.value(), 7);
+
+        let pkg = into_binary("CE00C43D881120").unwrap();
+        let actual_value = parse_package(&pkg, 0).unwrap();
+        
+        assert_eq!(actual_value.0.value(), 9);
+
+        let pkg = into_binary("D8005AC2A8F0").unwrap();
+        let actual_value = parse_package(&pkg, 0).unwrap();
+        
+        assert_eq!(actual_value.0.value(), 1);
+
+        let pkg = into_binary("F600BC2D8F").unwrap();
+        let actual_value = parse_package(&pkg, 0).unwrap();
+        
+        assert_eq!(actual_value.0.value(), 0);
+
+        let pkg = into_binary("9C005AC2F8F0").unwrap();
+        let actual_value = parse_package(&pkg, 0).unwrap();
+        
+        assert_eq!(actual_value.0.value(), 0);
+
+        let pkg = into_binary("9C0141080250320F1802104A08").unwrap();
+        let actual_value = parse_package(&pkg, 0).unwrap();
+        
+        assert_eq!(actual_value.0.value(), 1);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        // the example operator package 38006F45291200 cut off in the second literal
+        let error = super::get_solution_2("38006F4529").unwrap_err();
+        assert_eq!(error.to_string(), "invalid input: transmission ends in the middle of a package");
+    }
+}
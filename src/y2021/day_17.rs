@@ -1,20 +1,69 @@
-use std::{collections::{HashSet, HashMap}, ops::Range};
+use std::{collections::{HashSet, HashMap}, ops::{Range, RangeInclusive}};
 
-pub fn get_solution_1() -> i32 {
-    let y_range = -124..-69;
-    let min_y = y_range.start + 1;
+use crate::{parse::range, span, Answer, Error, Result, Solver};
+
+/// The target area, the ends of the ranges are inclusive
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Target {
+    x: Range<i32>,
+    y: Range<i32>,
+}
+
+impl Target {
+    pub fn x(&self) -> RangeInclusive<i32> {
+        self.x.start..=self.x.end
+    }
+
+    pub fn y(&self) -> RangeInclusive<i32> {
+        self.y.start..=self.y.end
+    }
+
+    pub fn contains(&self, (x, y): (i32, i32)) -> bool {
+        self.x().contains(&x) && self.y().contains(&y)
+    }
+
+    /// Simulates a probe launched from 0,0, returns whether it's in the area after any step
+    pub fn hits(&self, (mut vel_x, mut vel_y): (i32, i32)) -> bool {
+        let mut pos = (0, 0);
+        // the target is right of and below the start, once the probe is past it it can't come back
+        while pos.0 <= self.x.end && pos.1 >= self.y.start {
+            pos = (pos.0 + vel_x, pos.1 + vel_y);
+            if self.contains(pos) {
+                return true;
+            }
+            vel_y -= 1;
+            vel_x -= vel_x.signum();
+        }
+        false
+    }
+}
+
+pub fn get_solution_1(input: &str) -> Result<i32> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    Ok(solve_2(&parse(input)?))
+}
+
+/// Highest y position of a probe which hits the target
+pub fn solve_1(target: &Target) -> i32 {
+    let y_range = &target.y;
+    // the probe comes back down to y = 0 with -(vel_y + 1), so the highest velocity is |min_y| - 1
+    let min_y = y_range.start;
     min_y * (min_y + 1) / 2
 }
 
-pub fn get_solution_2() -> usize {
-    let target_x = 211..232;
-    let target_y = -124..-69; 
-    let solve_vel_x_less_n = |delim: f64| (-0.5 + f64::sqrt(0.25 + 4. * delim * 0.5));
+/// Counts the initial velocities which hit the target
+pub fn solve_2(target: &Target) -> usize {
+    let (target_x, target_y) = (&target.x, &target.y);
+    let solve_vel_x_less_n = |delim: f64| -0.5 + f64::sqrt(0.25 + 4. * delim * 0.5);
     let x_range_any = solve_vel_x_less_n(target_x.start.into()).ceil() as i32..solve_vel_x_less_n((target_x.end + 1).into()).ceil() as i32;
 
     let mut vel_xs_for_steps_map = HashMap::new();
     let mut count = 0;
-    for vel_y in get_y_vels(target_y.start, target_y.end) {
+    for vel_y in get_y_vels(target_y.start) {
         let mut vel_x_set = HashSet::new();
         for n in determine_n_steps_to_target(vel_y.into(), target_y.end.into(), target_y.start.into()) {
             let vel_xs = vel_xs_for_steps_map
@@ -24,17 +73,51 @@ pub fn get_solution_2() -> usize {
                             );
             vel_x_set = vel_x_set.union(vel_xs).copied().collect();
         }
-        if !vel_x_set.is_empty() {
-            count += vel_x_set.len();
-        }
+        count += vel_x_set.len();
+    }
+
+    count
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
     }
 
-    count + ((target_y.end - target_y.start + 1) * (target_x.end - target_x.start + 1)) as usize
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+/// Parses the target area like "target area: x=20..30, y=-10..-5".
+/// The solution only works for targets right of and below the start
+pub fn parse(input: &str) -> Result<Target> {
+    let _span = span!(Debug, "parse");
+    let parse_range = |part: &str, axis: &str| -> Result<Range<i32>> {
+        let range = range::<i32>(input, part, axis)?;
+        if range.is_empty() {
+            return Err(Error::at(input, part, "start of range is larger than its end"));
+        }
+        Ok(*range.start()..*range.end())
+    };
+    let (x, y) = input.trim()
+                      .strip_prefix("target area: ")
+                      .and_then(|area| area.split_once(", "))
+                      .ok_or_else(|| Error::invalid("expected 'target area: x=A..B, y=C..D'"))?;
+
+    let (x, y) = (parse_range(x, "x=")?, parse_range(y, "y=")?);
+    if x.start <= 0 || y.end >= 0 {
+        return Err(Error::invalid("target area has to be right of and below the start"));
+    }
+    Ok(Target { x, y })
 }
 
-/// Returns an upper and lower bound for velocities in y direction which could hit the target area
-fn get_y_vels(min_y_range: i32, max_y_range: i32) -> Range<i32> {
-    max_y_range + 1..min_y_range.abs()
+/// Returns an upper and lower bound for velocities in y direction which could hit the target area.
+/// Velocities aiming into the target can still hit it after the first step, if the target is tall enough
+fn get_y_vels(min_y_range: i32) -> Range<i32> {
+    min_y_range..min_y_range.abs()
 }
 
 /// Calculates the number of steps to hit the target area, for a given velocity in y direction,
@@ -73,32 +156,11 @@ fn determine_x_vels_from_n_steps(n: f64, min_x: f64, max_x: f64, range_any: &Ran
     min_vel_x..max_vel_x
 }
 
-/// Helper function for verifying if calculated velocities actually land in the target area
-fn _simulate((mut vel_x, mut vel_y): (i32, i32), range_x: Range<i32>, range_y: Range<i32>) -> usize {
-    let mut pos = (0, 0);
-    let mut steps = 0;
-
-    while pos.0 < range_x.end || pos.1 > range_y.end {
-        pos = (pos.0 + vel_x, pos.1 + vel_y);
-        steps += 1;
-        if range_x.contains(&pos.0) && range_y.contains(&pos.1) {
-            return steps
-        }
-        vel_y -= 1;
-        if vel_x > 0 { 
-            vel_x -= 1;
-        }
-    }
-
-    0
-}
-
-
 #[cfg(test)]
 pub mod tests {
     use std::{collections::HashSet, ops::Range};
 
-    use super::{determine_n_steps_to_target, determine_x_vels_from_n_steps};
+    use super::{determine_n_steps_to_target, determine_x_vels_from_n_steps, parse, get_solution_1, get_solution_2, Target};
 
     #[test]
     fn test_x_vels() {
@@ -107,7 +169,7 @@ pub mod tests {
             assert_eq!(split.len(), 2);
             (split[0].parse::<i32>().unwrap(), split[1].parse::<i32>().unwrap())
         };
-        let mut tuples: Vec<(i32, i32)> = include_str!("../data/day_17_sol.txt")
+        let mut tuples: Vec<(i32, i32)> = include_str!("../../data/2021/day_17_sol.txt")
                                     .split_ascii_whitespace()
                                     .map(split_to_tuple)
                                     .collect();
@@ -189,12 +251,12 @@ pub mod tests {
 
     #[test]
     fn test_determine_n_x_vels() {
-        let solve_vel_x_less_n = |delim: f64| (-0.5 + f64::sqrt(0.25 + 4. * delim * 0.5));
+        let solve_vel_x_less_n = |delim: f64| -0.5 + f64::sqrt(0.25 + 4. * delim * 0.5);
     
         let min_x = 20.;
         let max_x = 30.;
 
-        let min_vel_x_less = solve_vel_x_less_n(min_x.into()).ceil() as i32;
+        let min_vel_x_less = solve_vel_x_less_n(min_x).ceil() as i32;
         let max_vel_x_less = solve_vel_x_less_n(max_x + 1.).ceil() as i32;    
         let range_any = min_vel_x_less..max_vel_x_less;
         
@@ -215,13 +277,13 @@ pub mod tests {
         // n = 2
         assert_eq!(two_actual, HashSet::from([11, 12, 13, 14, 15]));
         // n = 2, 3
-        assert_eq!(two_actual.union(&three_actual).map(|x| *x).collect::<HashSet<_>>(), HashSet::from([8, 9, 10, 11, 12, 13, 14, 15]));
+        assert_eq!(two_actual.union(&three_actual).copied().collect::<HashSet<_>>(), HashSet::from([8, 9, 10, 11, 12, 13, 14, 15]));
         // n = 3, 4
-        assert_eq!(three_actual.union(&four_actual).map(|x| *x).collect::<HashSet<_>>(), HashSet::from([7, 8, 9, 10, 11]));
+        assert_eq!(three_actual.union(&four_actual).copied().collect::<HashSet<_>>(), HashSet::from([7, 8, 9, 10, 11]));
         // n = 4, 5
-        assert_eq!(four_actual.union(&five_actual).map(|x| *x).collect::<HashSet<_>>(), HashSet::from([6, 7, 8, 9]));
+        assert_eq!(four_actual.union(&five_actual).copied().collect::<HashSet<_>>(), HashSet::from([6, 7, 8, 9]));
         // n = 5, 6
-        assert_eq!(five_actual.union(&six_actual).map(|x| *x).collect::<HashSet<_>>(), HashSet::from([6, 7, 8]));
+        assert_eq!(five_actual.union(&six_actual).copied().collect::<HashSet<_>>(), HashSet::from([6, 7, 8]));
         // n = 7
         assert_eq!(seven_actual, HashSet::from([6, 7]));
         // n = 9
@@ -237,7 +299,7 @@ pub mod tests {
     fn test_correct_x_vels() {
         fn determine_n_x_vels_dbg(n: f64, min_x: f64, max_x: f64) -> (Range<i32>, Range<i32>) {
             let solve_vel_x = |delim: f64, n: f64| (2. * delim + n.powi(2) - n) / (2. * n);
-            let solve_vel_x_less_n = |delim: f64| (-0.5 + f64::sqrt(0.25 + 4. * delim * 0.5));
+            let solve_vel_x_less_n = |delim: f64| -0.5 + f64::sqrt(0.25 + 4. * delim * 0.5);
             let min_vel_x = solve_vel_x(min_x, n).ceil() as i32;
             let max_vel_x = solve_vel_x(max_x + 1., n).ceil() as i32;
         
@@ -294,4 +356,31 @@ pub mod tests {
         let twenty_steps = determine_n_x_vels_dbg(20., min_x.into(), max_x.into());
         println!("20 {:?}", twenty_steps);
     }
+
+    #[test]
+    fn test_parse() {
+        let Target { x, y } = parse(include_str!("../../data/2021/day_17_test.txt")).unwrap();
+        assert_eq!(x, 20..30);
+        assert_eq!(y, -10..-5);
+
+        let error = parse("target area: x=20..30, y=-10..a").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 31: can't parse 'a'");
+        assert!(parse("target area: x=-30..-20, y=-10..-5").is_err());
+    }
+
+    #[test]
+    fn test_get_solutions() {
+        assert_eq!(get_solution_1(include_str!("../../data/2021/day_17_test.txt")).unwrap(), 45);
+        assert_eq!(get_solution_2(include_str!("../../data/2021/day_17_test.txt")).unwrap(), 112);
+    }
+
+    #[test]
+    fn test_hits() {
+        let target = parse(include_str!("../../data/2021/day_17_test.txt")).unwrap();
+        assert!(target.hits((6, 9)));
+        assert!(!target.hits((17, -4)));
+        // simulating every velocity has to agree with the closed form of part 2
+        let hits = (0..=30).flat_map(|x| (-10..10).map(move |y| (x, y))).filter(|vel| target.hits(*vel)).count();
+        assert_eq!(hits, super::solve_2(&target));
+    }
 }
\ No newline at end of file
@@ -1,22 +1,32 @@
-use std::{ops::{Deref, DerefMut, Add}, fmt::Display};
-use std::fmt::Write as _;
+use std::{ops::{Deref, DerefMut, Add}, fmt::Display, str::FromStr};
 
-pub fn get_solution_1() -> u32 {
-    let mut lines = include_str!("../data/day_18.txt").lines();
-    let mut sn: SnailNumber = lines.next().unwrap().into();
+use crate::{span, Answer, Error, Result, Solver};
 
-    for next in lines {
-        sn += next.into();
+pub fn get_solution_1(input: &str) -> Result<u32> {
+    solve_1(&parse(input)?)
+}
+
+pub fn get_solution_2(input: &str) -> Result<u32> {
+    Ok(solve_2(&parse(input)?))
+}
+
+/// Magnitude of the sum of all numbers
+pub fn solve_1(numbers: &[SnailNumber]) -> Result<u32> {
+    let mut numbers = numbers.iter().cloned();
+    let mut sn = numbers.next().ok_or_else(|| Error::invalid("no snail numbers"))?;
+
+    for next in numbers {
+        sn += next;
     }
 
-    sn.magnitude()
+    Ok(sn.magnitude())
 }
 
-pub fn get_solution_2() -> u32 {
-    let numbers = include_str!("../data/day_18.txt").lines().map(|line| line.into()).collect::<Vec<SnailNumber>>();
+/// Largest magnitude of the sum of two different numbers
+pub fn solve_2(numbers: &[SnailNumber]) -> u32 {
     let mut max = 0;
-    for n_outer in &numbers {
-        for n_inner in &numbers {
+    for n_outer in numbers {
+        for n_inner in numbers {
             if n_outer != n_inner {
                 let n = n_inner + n_outer;
                 let mag = n.magnitude();
@@ -30,8 +40,30 @@ pub fn get_solution_2() -> u32 {
     max
 }
 
-#[derive(Debug)]
-struct SnailNumber {
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+/// Parses one snail number per line
+pub fn parse(input: &str) -> Result<Vec<SnailNumber>> {
+    let _span = span!(Debug, "parse");
+    input.lines().enumerate().map(|(i, line)| SnailNumber::parse(i, line)).collect()
+}
+
+/// A snail number like `[[1,2],3]`, stored as its regular numbers from left to right with their depth.
+///
+/// Adding two numbers reduces the sum, `&a + &b` keeps the operands and `a += b` reuses them
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnailNumber {
     value: Vec<Number>
 }
 
@@ -41,7 +73,45 @@ impl SnailNumber {
         SnailNumber { value: vec![] }
     }
 
-    fn reduce(&mut self) -> bool {
+    /// Checks that the line is a pair like `[[1,2],3]` before converting it
+    fn parse(line_index: usize, line: &str) -> Result<Self> {
+        // 'E' stands for an element, which is either a regular number or another pair
+        let describe = |expected: char| match expected {
+            'E' => "a number or '['".to_string(),
+            c => format!("'{}'", c),
+        };
+        let mut chars = line.char_indices().filter(|(_, c)| !c.is_whitespace());
+        let mut expected = vec![']', 'E', ',', 'E', '['];
+        while let Some(next) = expected.pop() {
+            let (pos, c) = match chars.next() {
+                Some(found) => found,
+                None => return Err(Error::in_line(line_index, line, &line[line.len()..], format!("expected {}", describe(next)))),
+            };
+            match (next, c) {
+                ('E', '[') => expected.extend([']', 'E', ',', 'E']),
+                ('E', c) if c.is_ascii_digit() => (),
+                (next, c) if next == c && next != 'E' => (),
+                (next, c) => {
+                    let part = &line[pos..pos + c.len_utf8()];
+                    return Err(Error::in_line(line_index, line, part, format!("expected {}, got '{}'", describe(next), c)));
+                },
+            }
+        }
+        if let Some((pos, c)) = chars.next() {
+            let part = &line[pos..pos + c.len_utf8()];
+            return Err(Error::in_line(line_index, line, part, format!("unexpected '{}' after the number", c)));
+        }
+
+        Ok(line.into())
+    }
+
+    /// Fully reduces the number by applying explode and split until nothing changes
+    fn reduce(&mut self) {
+        while !self.reduce_step() {}
+    }
+
+    /// Applies a single explode or split, returns true if the number was already reduced
+    fn reduce_step(&mut self) -> bool {
         for (i, n) in self.iter_mut().enumerate() {
             if n.level > 4 {
                 self.explode(i);
@@ -108,7 +178,12 @@ impl SnailNumber {
         self.insert(i + 1, right);
     }
 
-    fn magnitude(mut self) -> u32 {
+    /// The magnitude of a pair is 3 times the magnitude of its left plus 2 times the one of its right element
+    pub fn magnitude(&self) -> u32 {
+        self.clone().reduce_magnitude()
+    }
+
+    fn reduce_magnitude(mut self) -> u32 {
         while self.len() > 1 {
             // build a new number and assign it to the old one
             let mut i = 0;
@@ -138,19 +213,29 @@ impl SnailNumber {
     }
 }
 
+/// Prints the number like `[[1,2],3]`, the depths of the regular numbers determine the pairs
 impl Display for SnailNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut _level = 0;
-        let mut string = String::new();
-        for n in self.iter() {
-            let _ = write!(string, "{:<2},", n.value);
-        }
-        string += "\n";
+        // for every open pair, whether its left element is complete
+        let mut open: Vec<bool> = vec![];
         for n in self.iter() {
-            let _ = write!(string, "{:<2},", n.level);
+            while open.len() < n.level as usize {
+                write!(f, "[")?;
+                open.push(false);
+            }
+            write!(f, "{}", n.value)?;
+            while let Some(left_done) = open.last_mut() {
+                if *left_done {
+                    open.pop();
+                    write!(f, "]")?;
+                } else {
+                    *left_done = true;
+                    write!(f, ",")?;
+                    break;
+                }
+            }
         }
-
-        write!(f, "{}\n", string)
+        Ok(())
     }
 }
 
@@ -168,7 +253,7 @@ impl Add for &SnailNumber {
             n.level += 1;
         }
         
-        while !sn.reduce() {}
+        sn.reduce();
 
         sn
     }
@@ -176,23 +261,24 @@ impl Add for &SnailNumber {
 
 impl std::ops::AddAssign for SnailNumber {
     fn add_assign(&mut self, other: Self) {
-        self.value.extend(other.value.into_iter());
+        self.value.extend(other.value);
         for n in self.value.iter_mut() {
             n.level += 1;
         }
-        while !self.reduce() {}
+        self.reduce();
     }
 }
 
 /// Works only on reduced snail numbers
+/// Converts without checking the input, use `parse` for untrusted input
 impl From<&str> for SnailNumber {
     fn from(input: &str) -> Self {
         let mut value = Vec::new();
-        let mut level = 0;
+        let mut level: u8 = 0;
         for c in input.chars() {
             match c {
-                '[' => level += 1,
-                ']' => level -= 1,
+                '[' => level = level.saturating_add(1),
+                ']' => level = level.saturating_sub(1),
                 c => if let Some(n) = c.to_digit(10) {
                     value.push(Number { value: n, level })
                 },
@@ -222,12 +308,33 @@ impl PartialEq for SnailNumber {
     }
 }
 
+impl FromStr for SnailNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        SnailNumber::parse(0, s)
+    }
+}
+
+/// A regular number of a snail number
 #[derive(Debug, Clone, Copy)]
-struct Number {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Number {
     value: u32,
     level: u8,
 }
 
+impl Number {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Number of pairs the number is nested in
+    pub fn depth(&self) -> u8 {
+        self.level
+    }
+}
+
 impl PartialEq for Number {
     fn eq(&self, other: &Self) -> bool {
         self.value == other.value && self.level == other.level
@@ -236,7 +343,7 @@ impl PartialEq for Number {
 
 #[cfg(test)]
 pub mod tests {
-    use crate::day_18::SnailNumber;
+    use crate::y2021::day_18::SnailNumber;
 
     #[test]
     fn test_snail_number_from_str_ref() {
@@ -417,17 +524,44 @@ pub mod tests {
 
     #[test]
     fn test_with_test_data() {
-        let mut lines = include_str!("../data/day_18_test.txt").lines();
+        let mut lines = include_str!("../../data/2021/day_18_test.txt").lines();
         let mut sn: SnailNumber = lines.next().unwrap().into();
 
-        while let Some(next) = lines.next() {
+        for next in lines {
             sn += next.into();
         }
 
         assert_eq!(sn.magnitude(), 4140);
     }
+
+    #[test]
+    fn test_snail_number_parse() {
+        assert_eq!(SnailNumber::parse(0, "[[1,2],3]").unwrap(), "[[1,2],3]".into());
+        assert_eq!(
+            SnailNumber::parse(2, "[[1,2],3,4]").unwrap_err().to_string(),
+            "line 3, column 9: expected ']', got ','"
+        );
+        assert_eq!(SnailNumber::parse(0, "[1,").unwrap_err().to_string(), "line 1, column 4: expected a number or '['");
+        assert!(SnailNumber::parse(0, "[1,2]]").is_err());
+        assert!(SnailNumber::parse(0, "7").is_err());
+        assert!(SnailNumber::parse(0, "[7,E]").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let sn: SnailNumber = "[[1,2],3]".into();
+        assert_eq!(sn.to_string(), "[[1,2],3]");
+
+        let mut lines = include_str!("../../data/2021/day_18_test.txt").lines();
+        let mut sn: SnailNumber = lines.next().unwrap().into();
+        for next in lines {
+            sn += next.into();
+        }
+        assert_eq!(sn.to_string(), "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]");
+    }
 }
 
+#[allow(dead_code)]
 mod binary_tree_implementation_not_finished {
     use std::ops::Add;
 
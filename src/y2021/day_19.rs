@@ -0,0 +1,245 @@
+use std::collections::{HashSet, HashMap};
+
+use crate::{event, geometry::{Point3, Rotation}, parse::{blocks, list}, span, Answer, Error, Result, Solver};
+
+/// A beacon relative to the scanner which detects it
+pub type Beacon = Point3;
+/// A position relative to the first scanner
+pub type Position = Point3;
+/// The beacons one scanner detects
+pub type Scanner = Vec<Beacon>;
+
+/// The scanners, rotated and moved to be relative to the first scanner
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Map {
+    scanners: Vec<(Scanner, Position)>,
+}
+
+impl Map {
+    /// Aligns the scanners by finding 12 common beacons between pairs of them.
+    /// Fails if a scanner doesn't overlap with any of the others
+    pub fn align(scanners: &[Scanner]) -> Result<Self> {
+        if scanners.is_empty() {
+            return Err(Error::invalid("no scanners"));
+        }
+        Ok(Map { scanners: determine_beacons_and_scanners(scanners.to_vec())? })
+    }
+
+    /// Returns all beacons, relative to the first scanner
+    pub fn beacons(&self) -> HashSet<Beacon> {
+        collect_beacons(self.scanners.iter().map(|(scanner, _)| scanner.clone()).collect())
+    }
+
+    pub fn scanner_positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.scanners.iter().map(|(_, pos)| *pos)
+    }
+
+    /// Largest manhattan distance between two scanners
+    pub fn largest_distance(&self) -> i64 {
+        let scanner_positions: Vec<Position> = self.scanner_positions().collect();
+        let mut max = 0;
+        for (i, pos_left) in scanner_positions.iter().enumerate() {
+            for pos_right in scanner_positions.iter().skip(i + 1) {
+                let distance = pos_left.manhattan(pos_right);
+                if max < distance {
+                    max = distance;
+                }
+            }
+        }
+
+        max
+    }
+}
+
+/// Parses the scanners, each is a header like "--- scanner 0 ---" followed by one beacon per line
+pub fn parse(input: &str) -> Result<Vec<Scanner>> {
+    let _span = span!(Debug, "parse");
+    parse_input(input)
+}
+
+/// Counts the beacons
+pub fn solve_1(scanners: &[Scanner]) -> Result<usize> {
+    Ok(Map::align(scanners)?.beacons().len())
+}
+
+/// Largest distance between two scanners
+pub fn solve_2(scanners: &[Scanner]) -> Result<i64> {
+    Ok(Map::align(scanners)?.largest_distance())
+}
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    solve_1(&parse(input)?)
+}
+
+pub fn get_solution_2(input: &str) -> Result<i64> {
+    solve_2(&parse(input)?)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+/// Fails if a scanner doesn't overlap with any of the others
+fn determine_beacons_and_scanners(mut scanners: Vec<Scanner>) -> Result<Vec<(Scanner, Position)>> {
+    let mut calculated_scanners = vec![(scanners.remove(0), Point3::ORIGIN)];
+
+    // do slow solution first (compare everything with everything)
+    while !scanners.is_empty() {
+        let mut found = None;
+        let mut comparisons = 0;
+
+        for (left, _) in &calculated_scanners {
+            'outer: for (i, right) in scanners.iter().enumerate() {
+                for rotation in Rotation::all() {
+                    comparisons += 1;
+                    let rotated = rotate(right, rotation);
+                    let positions_map = create_positions_map(left, &rotated);
+                    if let Some(pos) = try_get_position(positions_map) {
+                        found = Some((i, move_by(rotated, pos), pos));
+                        break 'outer;
+                    }
+                }
+            }
+            if found.is_some() {
+                break;
+            }
+        }
+        let Some((i, aligned, pos)) = found else {
+            return Err(Error::invalid(format!("{} scanners don't overlap with the others", scanners.len())));
+        };
+        scanners.remove(i);
+        event!(Trace, "aligned", position = pos, comparisons = comparisons, left = scanners.len());
+        calculated_scanners.push((aligned, pos));
+    }
+    
+    event!(Debug, "aligned", scanners = calculated_scanners.len());
+    Ok(calculated_scanners)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Scanner>> {
+    let mut scanners = vec![];
+    for block in blocks(input) {
+        let mut lines = block.lines();
+        match lines.next() {
+            Some(header) if header.starts_with("---") => (),
+            _ => return Err(Error::at(input, block, "expected a scanner header like '--- scanner 0 ---'")),
+        }
+        let mut scanner = vec![];
+        for line in lines {
+            match list::<i64>(input, line, ',')?[..] {
+                [x, y, z] => scanner.push(Point3::new(x, y, z)),
+                _ => return Err(Error::at(input, line, "expected a beacon like 'x,y,z'")),
+            }
+        }
+        scanners.push(scanner);
+    }
+    if scanners.is_empty() {
+        return Err(Error::invalid("no scanners"));
+    }
+
+    Ok(scanners)
+}
+
+fn rotate(scanner: &Scanner, rotation: Rotation) -> Scanner {
+    scanner.iter().map(|beacon| rotation.apply(*beacon)).collect()
+}
+
+fn create_positions_map(left: &Scanner, right: &Scanner) -> HashMap<Position, usize>{
+    let mut positions_map = HashMap::new();
+    for left_beacon in left {
+        for right_beacon in right {
+            let entry = positions_map.entry(*left_beacon - *right_beacon).or_insert(0);
+            *entry += 1;
+        }
+    }
+    positions_map
+}
+
+fn try_get_position(positions_map: HashMap<Position, usize>) -> Option<Position> {
+    for (position, count) in positions_map {
+        if count >= 12 {
+            return Some(position);
+        }
+    }
+
+    None
+}
+
+fn move_by(scanner: Scanner, pos: Position) -> Scanner {
+    scanner.into_iter().map(|beacon| beacon + pos).collect()
+}
+
+fn collect_beacons(scanners: Vec<Scanner>) -> HashSet<Beacon> {
+    let mut beacons = HashSet::new();
+
+    for scanner in scanners {
+        for beacon in scanner {
+            beacons.insert(beacon);
+        }
+    }
+
+    beacons
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::{Point3, Rotation}, y2021::day_19::try_get_position};
+
+    use super::{parse_input, create_positions_map, rotate, Map};
+
+    #[test]
+    fn test_parse_input() {
+        let scanners = parse_input(include_str!("../../data/2021/day_19_test.txt")).unwrap();
+        assert_eq!(scanners.len(), 5);
+        assert_eq!(scanners[0][0], Point3::new(404, -588, -901));
+        assert_eq!(scanners[1][1], Point3::new(605, 423, 415));
+        assert_eq!(scanners[2][2], Point3::new(-784, 533, -524));
+        assert_eq!(scanners[3][3], Point3::new(-660, 373, 557));
+        assert_eq!(scanners[4][25], Point3::new(30, -46, -14));
+
+        let error = parse_input("--- scanner 0 ---\n1,2,3\n4,5\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected a beacon like 'x,y,z'");
+    }
+
+    #[test]
+    fn test_positions_map() {
+        let mut scanners = parse_input(include_str!("../../data/2021/day_19_test.txt")).unwrap();
+        scanners[1].iter_mut().for_each(|beacon| { beacon.x *= -1; beacon.z *= -1 });
+
+        let map = create_positions_map(&scanners[0], &scanners[1]);
+        let actual = try_get_position(map);
+
+        assert!(actual.is_some());
+        assert_eq!(actual.unwrap(), Point3::new(68, -1246, -43));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let scanner = vec![Point3::new(1, 2, 3)];
+        let mut seen = vec![];
+        for rotation in Rotation::all() {
+            let rotated = rotate(&scanner, rotation);
+            assert_eq!(rotate(&rotated, rotation.inverse()), scanner);
+            assert!(!seen.contains(&rotated[0]));
+            seen.push(rotated[0]);
+        }
+    }
+
+    #[test]
+    fn test_align() {
+        let scanners = parse_input(include_str!("../../data/2021/day_19_test.txt")).unwrap();
+        let map = Map::align(&scanners).unwrap();
+        assert!(map.scanner_positions().any(|pos| pos == Point3::new(68, -1246, -43)));
+        assert_eq!(map.beacons().len(), 79);
+        assert_eq!(map.largest_distance(), 3621);
+    }
+}
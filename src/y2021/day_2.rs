@@ -0,0 +1,83 @@
+use crate::{error::parse_in_line, span, Answer, Error, Result, Solver};
+
+/// A command of the submarine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Command {
+    Forward(u32),
+    Up(u32),
+    Down(u32),
+}
+
+/// Follows the commands, up and down change the depth
+pub fn solve_1(commands: &[Command]) -> u32 {
+    let mut horizontal = 0;
+    let mut depth = 0;
+
+    for command in commands {
+        match *command {
+            Command::Forward(unit) => { horizontal += unit; },
+            Command::Up(unit) => { depth -= unit; },
+            Command::Down(unit) => { depth += unit; },
+        };
+    }
+    horizontal * depth
+}
+
+/// Follows the commands, up and down change the aim
+pub fn solve_2(commands: &[Command]) -> u32 {
+    let mut horizontal = 0;
+    let mut depth = 0;
+    let mut aim = 0;
+
+    for command in commands {
+        match *command {
+            Command::Forward(unit) => { 
+                horizontal += unit; 
+                depth += unit * aim;
+            },
+            Command::Up(unit) => { aim -= unit; },
+            Command::Down(unit) => { aim += unit; },
+        };
+    }
+    horizontal * depth
+}
+
+/// Parses commands like "forward 5", one per line
+pub fn parse(input: &str) -> Result<Vec<Command>> {
+    let _span = span!(Debug, "parse");
+    input.lines().enumerate().map(|(i, line)| parse_command(i, line)).collect()
+}
+
+fn parse_command(i: usize, line: &str) -> Result<Command> {
+    let Some((direction, unit)) = line.split_once(' ') else {
+        return Err(Error::in_line(i, line, line, "expected a direction and a unit"));
+    };
+    let unit = parse_in_line(i, line, unit)?;
+    match direction {
+        "forward" => Ok(Command::Forward(unit)),
+        "up" => Ok(Command::Up(unit)),
+        "down" => Ok(Command::Down(unit)),
+        direction => Err(Error::in_line(i, line, direction, format!("invalid direction '{}'", direction))),
+    }
+}
+
+pub fn get_solution_1(input: &str) -> Result<u32> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<u32> {
+    Ok(solve_2(&parse(input)?))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt::Display;
+
+use crate::{event, grid::Grid, observe::{Frame, NoObserver, Observer}, parse::blocks, span, Answer, Error, Result, Solver};
+
+static N_ENHANCEMENTS_1: isize = 2;
+static N_ENHANCEMENTS_2: isize = 50;
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    Ok(solve_2(&parse(input)?))
+}
+
+pub fn solve_1(image: &Image) -> usize {
+    enhance(image, N_ENHANCEMENTS_1, &mut NoObserver)
+}
+
+pub fn solve_2(image: &Image) -> usize {
+    enhance(image, N_ENHANCEMENTS_2, &mut NoObserver)
+}
+
+/// Enhances the image `times` times, returns the number of lit pixels
+pub fn enhance(image: &Image, times: isize, observer: &mut dyn Observer) -> usize {
+    let mut image = image.clone();
+
+    for step in 1..=times {
+        image.enhance();
+        event!(Trace, "enhanced", step = step, width = image.pixels.lit.width(), lit = image.pixels.count_lit_pixels());
+        observer.step(step as usize, &image.pixels);
+    }
+    
+    image.pixels.count_lit_pixels()
+}
+
+/// Parses the enhancement algorithm (512 pixels), an empty line and the image
+pub fn parse(input: &str) -> Result<Image> {
+    let _span = span!(Debug, "parse");
+    let (algorithm, lit) = get_input(input)?;
+    Ok(Image { algorithm, pixels: Pixels { lit, outside: false } })
+}
+
+/// An image and the algorithm to enhance it
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Image {
+    /// 512 pixels in a single row
+    algorithm: Grid<bool>,
+    pixels: Pixels,
+}
+
+impl Image {
+    pub fn algorithm(&self) -> &Grid<bool> {
+        &self.algorithm
+    }
+
+    pub fn pixels(&self) -> &Pixels {
+        &self.pixels
+    }
+
+    /// Enhances the image once, it grows by one pixel in every direction
+    pub fn enhance(&mut self) {
+        self.pixels.enhance_image(&self.algorithm);
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+
+    fn observe(&self, part: u8, input: &str, observer: &mut dyn Observer) -> Option<Result<Answer>> {
+        let times = match part {
+            1 => N_ENHANCEMENTS_1,
+            2 => N_ENHANCEMENTS_2,
+            _ => return None,
+        };
+        Some(parse(input).map(|image| enhance(&image, times, observer).into()))
+    }
+}
+
+/// The pixels of an image, lit pixels are true.
+/// The pixels outside of it are all lit or all dark
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pixels {
+    lit: Grid<bool>,
+    outside: bool, // value of the infinite pixels outside of the image
+}
+
+impl Pixels {
+    pub fn grid(&self) -> &Grid<bool> {
+        &self.lit
+    }
+
+    /// Returns whether the pixel at (x, y) is lit, including the infinite pixels outside
+    pub fn is_lit(&self, x: isize, y: isize) -> bool {
+        if x < 0 || y < 0 {
+            return self.outside;
+        }
+        self.lit.get(x as usize, y as usize).copied().unwrap_or(self.outside)
+    }
+
+    pub fn count_lit_pixels(&self) -> usize {
+        self.lit.iter().filter(|lit| **lit).count()
+    }
+
+    fn enhance_image(&mut self, algorithm: &Grid<bool>) {
+        // the new image is larger by one pixel in every direction
+        self.lit = Grid::from_fn(self.lit.width() + 2, self.lit.height() + 2, |x, y| {
+            algorithm[(self.calculate_algorithm_index(x as isize - 1, y as isize - 1), 0)]
+        });
+        // the infinite pixels are all lit or all dark, so they map to the first or last entry of the algorithm
+        self.outside = algorithm[(if self.outside { 511 } else { 0 }, 0)];
+    }
+
+    // (x, y) = coordinate of center in window
+    fn calculate_algorithm_index(&self, x: isize, y: isize) -> usize {
+        let mut index = 0;
+
+        for row in y - 1..y + 2 {
+            for col in x - 1..x + 2 {
+                index = (index << 1) | self.is_lit(col, row) as usize;
+            }
+        }
+        index
+    }
+}
+
+/// Prints the image without the pixels outside
+impl Display for Pixels {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.lit)
+    }
+}
+
+impl Frame for Pixels {
+    fn width(&self) -> usize {
+        self.lit.width()
+    }
+
+    fn height(&self) -> usize {
+        self.lit.height()
+    }
+
+    fn cell(&self, x: usize, y: usize) -> char {
+        self.lit.cell(x, y)
+    }
+}
+
+/// Returns (algorithm, Image)
+fn get_input(input: &str) -> Result<(Grid<bool>, Grid<bool>)> {
+    let [algorithm, image] = blocks(input)[..] else {
+        return Err(Error::invalid("expected the algorithm and the image, separated by an empty line"));
+    };
+    let algorithm_pixels = Grid::parse(input, algorithm)?;
+    if algorithm_pixels.len() != 512 || algorithm_pixels.height() != 1 {
+        return Err(Error::at(input, algorithm, "algorithm has to have 512 pixels"));
+    }
+
+    Ok((algorithm_pixels, Grid::parse(input, image)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+
+    use super::Pixels;
+
+    fn get_test_pixels(input: &str) -> Pixels {
+        Pixels { lit: Grid::parse(input, input).unwrap(), outside: false }
+    }
+
+    fn get_test_string() -> &'static str {
+"#..#.
+#....
+##..#
+..#..
+..###"
+    }
+
+    #[test]
+    fn test_pixels_from_string() {
+        let pixels = get_test_pixels(get_test_string());
+        assert_eq!((pixels.lit.width(), pixels.lit.height()), (5, 5));
+    }
+
+    #[test]
+    fn test_pixels_is_lit() {
+        let pixels = get_test_pixels(get_test_string());
+        assert!(pixels.is_lit(0, 0));
+        assert!(!pixels.is_lit(1, 0));
+        assert!(pixels.is_lit(4, 2));
+        assert!(!pixels.is_lit(4, 0));
+        assert!(pixels.is_lit(4, 4));
+    }
+
+    #[test]
+    fn test_pixels_outside() {
+        let mut pixels = get_test_pixels(get_test_string());
+        assert!(!pixels.is_lit(4, 5));
+        assert!(!pixels.is_lit(-1, 0));
+        pixels.outside = true;
+        assert!(pixels.is_lit(5, 0));
+        assert!(pixels.is_lit(0, -1));
+    }
+
+    #[test]
+    fn test_calculate_window() {
+        let pixels = get_test_pixels(get_test_string());
+        assert_eq!(pixels.calculate_algorithm_index(2, 2), 34);
+        // the window of a corner reaches outside
+        assert_eq!(pixels.calculate_algorithm_index(0, 0), 0b000_010_010);
+    }
+
+    #[test]
+    fn test_image_count_lit_pixels() {
+        let image = super::parse(include_str!("../../data/2021/day_20_test.txt")).unwrap();
+        assert_eq!(image.pixels().count_lit_pixels(), 10);
+    }
+
+    #[test]
+    fn test_get_solutions() {
+        assert_eq!(super::get_solution_1(include_str!("../../data/2021/day_20_test.txt")).unwrap(), 35);
+        assert_eq!(super::get_solution_2(include_str!("../../data/2021/day_20_test.txt")).unwrap(), 3351);
+
+        let error = super::get_solution_1("#.#\n\n#.\n.#").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: algorithm has to have 512 pixels");
+    }
+
+    #[test]
+    fn test_single_row() {
+        let pixels = get_test_pixels("#.#");
+        assert_eq!(pixels.to_string(), "#.#");
+        assert_eq!(pixels.count_lit_pixels(), 2);
+    }
+}
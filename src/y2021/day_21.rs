@@ -1,21 +1,44 @@
+use crate::{error::parse_in_line, span, Answer, Error, Result, Solver};
+
 static BOARD_SIZE: usize = 10;
 static COUNTS_PER_ROLL: [usize; 7] = [1, 3, 6, 7, 6, 3, 1]; 
 
-fn get_input() -> Vec<Player> {
-    include_str!("../data/day_21.txt")
-        .split('\n')
-        .map(|l| l[l.find(':').unwrap() + 1..].trim().parse::<usize>().unwrap().into())
-        .collect()
+/// Expects two lines like `Player 1 starting position: 4`
+fn get_input(input: &str) -> Result<Vec<Player>> {
+    let players = input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            let (_, position) = l.split_once(':')
+                .ok_or_else(|| Error::in_line(i, l, l, "expected 'Player N starting position: P'"))?;
+            let position = position.trim();
+            match parse_in_line::<usize>(i, l, position)? {
+                n @ 1..=10 => Ok(n.into()),
+                _ => Err(Error::in_line(i, l, position, "position has to be between 1 and 10")),
+            }
+        })
+        .collect::<Result<Vec<Player>>>()?;
+
+    if players.len() != 2 {
+        return Err(Error::invalid(format!("expected 2 players, got {}", players.len())));
+    }
+    Ok(players)
+}
+
+/// Parses the starting positions of the two players
+pub fn parse(input: &str) -> Result<Vec<Player>> {
+    let _span = span!(Debug, "parse");
+    get_input(input)
 }
 
-pub fn get_solution_1() -> usize {
-    let players = get_input();
-    let (losing_score, turns) = play(players, Dice::default());
+/// Score of the losing player times the number of rolls of the deterministic dice
+pub fn solve_1(players: &[Player]) -> usize {
+    let (losing_score, turns) = play(players.to_vec(), Dice::default());
     losing_score * turns
 }
 
-pub fn get_solution_2() -> usize {
-    let players = get_input();
+/// Number of universes in which the player who wins more often wins with the dirac dice
+pub fn solve_2(players: &[Player]) -> usize {
     let mut prev_wins = 0;
     let mut cur_wins = 0;
     play_dirac(
@@ -27,12 +50,44 @@ pub fn get_solution_2() -> usize {
     std::cmp::max(prev_wins, cur_wins)
 }
 
-#[derive(Clone, Copy)]
-struct Player {
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    Ok(solve_2(&parse(input)?))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Player {
     score: usize,
     position: usize,
 }
 
+impl Player {
+    /// Position on the board, from 1 to 10
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn score(&self) -> usize {
+        self.score
+    }
+}
+
 impl From<usize> for Player {
     fn from(position: usize) -> Self {
         Player { score: 0, position }
@@ -69,7 +124,7 @@ impl Dice {
         self.count += 3;
         (self.count - 2) % self.sides +
         (self.count - 1) % self.sides +
-        (self.count - 0) % self.sides
+        self.count % self.sides
     }
 }
 impl Default for Dice {
@@ -102,10 +157,7 @@ mod test {
     use super::*;
 
     fn get_test_input() -> Vec<Player> {
-        include_str!("../data/day_21_test.txt")
-            .split('\n')
-            .map(|l| l[l.find(':').unwrap() + 1..].trim().parse::<usize>().unwrap().into())
-            .collect()
+        get_input(include_str!("../../data/2021/day_21_test.txt")).unwrap()
     }
 
     #[test]
@@ -181,4 +233,11 @@ mod test {
             1);
         assert_eq!(std::cmp::max(prev_wins, cur_wins), 444356092776315);
     }
+
+    #[test]
+    fn test_get_input_invalid() {
+        let error = get_input("Player 1 starting position: 4\nPlayer 2 starting position: 11").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 29: position has to be between 1 and 10");
+        assert!(get_input("Player 1 starting position: 4").is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,124 @@
+// parse input into range objects
+use crate::{event, geometry::{Box3, Point3}, parse::range, span, Answer, Error, Result, Solver};
+
+#[macro_export]
+macro_rules! cub {
+    ($x:expr, $y:expr, $z:expr) => {
+        $crate::geometry::Box3::from([$x, $y, $z])
+    };
+}
+
+/// The cubes of a reboot step, both ends of the ranges are part of it
+pub type Cuboid = Box3;
+/// Whether the cuboid is turned on or off
+pub type Instruction = (bool, Cuboid);
+type Grid = Vec<Cuboid>;
+
+pub fn get_solution_1(input: &str) -> Result<i64> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<i64> {
+    Ok(solve_2(&parse(input)?))
+}
+
+/// Parses the reboot steps, one per line
+pub fn parse(input: &str) -> Result<Vec<Instruction>> {
+    parse_data(input)
+}
+
+/// Cubes which are on after the initialization procedure, which only considers the region -50..50
+pub fn solve_1(instructions: &[Instruction]) -> i64 {
+    let region = cub!([-50, 50], [-50, 50], [-50, 50]);
+    let initialization: Vec<Instruction> = instructions.iter()
+                                                       .filter_map(|(turns_on, cuboid)| Some((*turns_on, cuboid.intersection(&region)?)))
+                                                       .collect();
+    reboot(&initialization)
+}
+
+pub fn solve_2(instructions: &[Instruction]) -> i64 {
+    reboot(instructions)
+}
+
+/// Runs the steps, returns the number of cubes which are on
+pub fn reboot(instructions: &[Instruction]) -> i64 {
+    lit_cuboids(instructions).iter().map(Cuboid::volume).sum()
+}
+
+/// Runs the steps, returns the cubes which are on as cuboids which don't overlap
+pub fn lit_cuboids(instructions: &[Instruction]) -> Vec<Cuboid> {
+    let mut grid = vec![];
+    for (step, (turns_on, cuboid)) in instructions.iter().cloned().enumerate() {
+        grid = parse_instruction(turns_on, cuboid, grid);
+        event!(Trace, "instruction", step = step, turns_on = turns_on, cuboids = grid.len());
+    }
+    event!(Debug, "cuboids", total = grid.len());
+    grid
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+fn parse_instruction(turns_on: bool, cuboid: Cuboid, mut grid: Grid) -> Grid{
+    if turns_on {
+        let mut added = vec![];
+        add_to_grid(cuboid, &mut grid, &mut added);
+        grid.append(&mut added);
+        grid
+    } else {
+        let mut new_grid = vec![];
+        remove_from_grid(cuboid, grid, &mut new_grid);
+        new_grid
+    }
+}
+
+fn add_to_grid(cuboid: Cuboid, grid: &mut [Cuboid], added: &mut Vec<Cuboid>) {
+    match grid.first() {
+        Some(other_cuboid) => cuboid.subtract(other_cuboid)
+                                    .into_iter()
+                                    .for_each(|cuboid| add_to_grid(cuboid, &mut grid[1..], added)),
+        None => added.push(cuboid)
+    }
+}
+
+fn remove_from_grid(cuboid: Cuboid, grid: Grid, new_grid: &mut Grid) {
+    for grid_cuboid in grid {
+        new_grid.append(&mut grid_cuboid.subtract(&cuboid));
+    }
+}
+
+/// Expects lines like `on x=-20..26,y=-36..17,z=-47..7`
+fn parse_data(input: &str) -> Result<Vec<Instruction>> {
+    let _span = span!(Debug, "parse");
+    input
+        .lines()
+        .map(|line| {
+            let (turns_on, ranges) = match line.split_once(' ') {
+                Some(("on", ranges)) => (true, ranges),
+                Some(("off", ranges)) => (false, ranges),
+                _ => return Err(Error::at(input, line, "expected 'on' or 'off'")),
+            };
+            let bounds = ranges
+                            .split(',')
+                            .zip(["x=", "y=", "z="])
+                            .map(|(part, axis)| Ok(range::<i64>(input, part, axis)?.into_inner()))
+                            .collect::<Result<Vec<(i64, i64)>>>()?;
+            match bounds[..] {
+                // a range like x=3..1 is the same as x=1..3, the ends are swapped
+                [x, y, z] if ranges.split(',').count() == 3 => {
+                    Ok((turns_on, Cuboid::from_corners(Point3::new(x.0, y.0, z.0), Point3::new(x.1, y.1, z.1))))
+                },
+                _ => Err(Error::at(input, ranges, "expected ranges for x, y and z")),
+            }
+        })
+        .collect()
+}
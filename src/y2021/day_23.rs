@@ -0,0 +1,756 @@
+use std::{ops::Deref, fmt::{Display, Formatter}, collections::{VecDeque, HashMap}, hash::{Hash, Hasher}};
+use Type::*;
+
+use crate::{observe::{Frame, NoObserver, Observer}, search::{self, SearchProblem}, span, Answer, Error, Result, Solver};
+
+const BOARD_WIDTH: usize = 13;
+
+macro_rules! pod {
+    ($p_type:expr, $x:expr, $y:expr) => {
+        Amphipod::new($p_type, ($x, $y))
+    };
+}
+
+// rows that get inserted into the diagram for part 2
+const ADDITIONAL_ROWS: [&str; 2] = ["  #D#C#B#A#", "  #D#B#A#C#"];
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    solve_1(&parse(input)?)
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    solve_2(&parse(input)?)
+}
+
+pub fn solve_1(diagram: &Diagram) -> Result<usize> {
+    organize(diagram, &mut NoObserver)
+}
+
+pub fn solve_2(diagram: &Diagram) -> Result<usize> {
+    organize(&diagram.unfolded(), &mut NoObserver)
+}
+
+/// Returns the least energy needed to organize the pods, an error if they can't be organized
+/// (deep rooms may have too few places in the hallway) or the search was stopped.
+/// The observer sees the burrow after every move of the cheapest solution, once it's found
+pub fn organize(diagram: &Diagram, observer: &mut dyn Observer) -> Result<usize> {
+    let (initial, home) = diagram.pods();
+    Burrow::organize_pods(initial, home, observer).ok_or_else(|| Error::invalid("the amphipods can't be organized"))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+
+    fn observe(&self, part: u8, input: &str, observer: &mut dyn Observer) -> Option<Result<Answer>> {
+        match part {
+            1 => Some(parse(input).and_then(|diagram| organize(&diagram, observer)).map(Answer::from)),
+            2 => Some(parse(input).and_then(|diagram| organize(&diagram.unfolded(), observer)).map(Answer::from)),
+            _ => None,
+        }
+    }
+}
+
+type Pods = Vec<Amphipod>;
+
+/// Burrows are equal if their pods are drawn at the same places
+#[derive(Clone)]
+struct Burrow {
+    pods: Vec<Amphipod>,
+    home: Vec<Amphipod>,
+    home_size: usize,
+}
+
+/// Moving the pods around until none of them is left outside of its room
+struct Organizing {
+    start: Burrow,
+}
+
+impl SearchProblem for Organizing {
+    type State = Burrow;
+
+    fn start(&self) -> Burrow {
+        self.start.clone()
+    }
+
+    fn successors(&self, burrow: &Burrow) -> Vec<(Burrow, usize)> {
+        burrow.get_neighbours()
+    }
+
+    fn is_goal(&self, burrow: &Burrow) -> bool {
+        burrow.pods.is_empty()
+    }
+
+    fn heuristic(&self, burrow: &Burrow) -> usize {
+        Burrow::calculate_heuristic(&burrow.pods, &burrow.home, burrow.home_size)
+    }
+}
+
+impl Burrow {
+
+    fn new(pods: Pods, home: Pods) -> Self {
+        // every room has space for all pods of one type
+        let home_size = (pods.len() + home.len()) / 4;
+        Burrow { pods, home, home_size }
+    }
+
+    fn organize_pods(initial: Pods, home: Pods, observer: &mut dyn Observer) -> Option<usize> {
+        let organizing = Organizing { start: Burrow::new(initial, home) };
+        let path = search::astar(&organizing).path?;
+        // the moves of the solution are replayed, the states the search expanded aren't interesting
+        for (step, burrow) in path.states.iter().enumerate().skip(1) {
+            observer.step(step, burrow);
+        }
+        Some(path.cost)
+    }
+
+    fn calculate_heuristic(pods: &Pods, home: &Pods, home_size: usize) -> usize {
+
+        let mut pod_homes = HashMap::from([
+            (Amber, Amphipod::calculate_home(Amber, home_size)),
+            (Bronze, Amphipod::calculate_home(Bronze, home_size)),
+            (Copper, Amphipod::calculate_home(Copper, home_size)),
+            (Desert, Amphipod::calculate_home(Desert, home_size)),
+        ]);
+        
+        // filter homes:
+        for pod in home.iter() {
+            let home =  pod_homes.get_mut(&pod.typ).unwrap();
+            if let Some(index) = home.iter().position(|pos| &pod.pos == pos) {
+                home.remove(index);
+            }
+        }
+
+        let mut cost = 0;
+        for pod in pods {
+            let home = pod_homes.get_mut(&pod.typ).unwrap();
+            let home_pos = home.pop().unwrap();
+            if home_pos.0 == pod.pos.0 {
+                cost += (home_pos.1 + pod.pos.1 + 2) * pod.cost();
+            } else {
+                cost += pod.calculate_move_cost(&home_pos);
+            }
+        }
+
+        cost
+    }
+
+    // returns the burrows after every possible move, together with the energy of the move
+    fn get_neighbours(&self) -> Vec<(Self, usize)> {
+        // first check if pods that haven't moved so far can get home (since this will lead to a minimal score)
+        for (i, pod) in self.pods.iter().enumerate() {
+            if let Some(position) = pod.can_get_home(&self.pods, &self.home, self.home_size) {
+                // remove from initial and add to home
+                let cost = pod.calculate_move_cost(&position);
+                let (mut pods, mut home) = (self.pods.clone(), self.home.clone());
+                let mut home_pod = pods.remove(i);
+                home_pod.pos = position;
+                home.push(home_pod);
+
+                return vec![(Burrow::new(pods, home), cost)];
+            }
+        }
+
+        // move pods
+        let mut neighbours = Vec::new();
+        for (i, pod) in self.pods.iter().enumerate() {
+            for position in pod.get_possible_moves(&self.pods).into_iter() {
+                // assign pod new position
+                let mut new_pods = self.pods.clone();
+                new_pods[i].pos = position;
+                neighbours.push((Burrow::new(new_pods, self.home.clone()), pod.calculate_move_cost(&position)));
+            }
+        }
+        neighbours
+    }
+
+    fn draw(&self) -> String {
+        let mut burrow_string = String::from("#############\n#...........#\n###.#.#.#.###\n");
+        for _ in 1..self.home_size {
+            burrow_string.push_str("  #.#.#.#.#  \n");
+        }
+        burrow_string.push_str("  #########");
+        for p in self.pods.iter().chain(&self.home) {
+            let index = 1 + p.pos.0 + (p.pos.1 + 1) * BOARD_WIDTH + (p.pos.1 + 1);
+            burrow_string.replace_range(index..index + 1, p.typ.into());
+        }
+        burrow_string
+    }
+}
+
+impl Display for Burrow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.draw())
+    }
+}
+
+// same picture as draw, without building a string
+impl Frame for Burrow {
+    fn width(&self) -> usize {
+        BOARD_WIDTH
+    }
+
+    fn height(&self) -> usize {
+        self.home_size + 3
+    }
+
+    fn cell(&self, x: usize, y: usize) -> char {
+        // the hallway is at y = 1 and starts at x = 1
+        let pos = (x.wrapping_sub(1), y.wrapping_sub(1));
+        if let Some(pod) = self.pods.iter().chain(&self.home).find(|pod| pod.pos == pos) {
+            return <&str>::from(pod.typ).chars().next().unwrap_or('?');
+        }
+        let row = match y {
+            0 => "#############",
+            1 => "#...........#",
+            2 => "###.#.#.#.###",
+            y if y == self.home_size + 2 => "  #########  ",
+            _ => "  #.#.#.#.#  ",
+        };
+        row.as_bytes()[x] as char
+    }
+}
+
+impl PartialEq for Burrow {
+    fn eq(&self, other: &Self) -> bool {
+        self.draw() == other.draw()
+    }
+}
+
+impl Eq for Burrow {}
+
+impl Hash for Burrow {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.draw().hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
+struct Amphipod {
+    typ: Type,
+    pos: (usize, usize),
+}
+
+impl Amphipod {
+    fn new(p_type: Type, position: (usize, usize)) -> Self {
+        Self {typ: p_type, pos: position }
+    }
+
+    fn cost(&self) -> usize {
+        **self as usize
+    }
+
+    // only gets called on pod that is in intial
+    fn calculate_move_cost(&self, position: &(usize, usize)) -> usize {
+        let x_diff = self.pos.0.abs_diff(position.0);
+        let y_diff = self.pos.1 + position.1;
+
+        (x_diff + y_diff) * self.cost()
+    }
+
+    // only gets called on pod that is in intial
+    fn get_possible_moves(&self, pods: &Pods) -> Vec<(usize, usize)> {
+        // pod will always move into hallway
+        // if pod is at y == 2, check if there is a pod above him, if so, return empty vec (pod cannot move)
+        if self.pos.1 == 0 {
+            return vec![];
+        }
+
+        if self.pos.1 >= 2 && pods.iter().any(|pod| pod.pos.0 == self.pos.0 && pod.pos.1 < self.pos.1) {
+            return vec![]
+        }
+        let mut right_positions = VecDeque::from([(0, 0), (1, 0), (3, 0), (5, 0), (7, 0), (9, 0), (10, 0)]);
+        
+        // get adjacent positions and verify if they are free
+        // only pods in the moved set can be in the hallway
+        let idx = right_positions.iter().position(|pos| pos.0 > self.pos.0).unwrap(); // unwrap is safe, since rooms are between 2 and 8 on x
+        let mut left_positions = right_positions.drain(..idx).collect::<Vec<(usize, usize)>>();
+        let mut possible_positions = vec![];
+
+        while !right_positions.is_empty() || !left_positions.is_empty() {
+            if let Some(left_pos) = left_positions.pop() {
+                if pods.iter().any(|pod| pod.pos == left_pos) {
+                    left_positions = vec![];
+                } else {
+                    possible_positions.push(left_pos);
+                }
+            }
+            if let Some(right_pos) = right_positions.pop_front() {
+                if pods.iter().any(|pod| pod.pos == right_pos) {
+                    right_positions = VecDeque::new();
+                } else {
+                    possible_positions.push(right_pos);
+                }
+            }
+        }
+
+        // sorted from left to right
+        possible_positions.sort();
+        possible_positions
+    }
+
+    fn calculate_home(typ: Type, home_size: usize) -> Vec<(usize, usize)> {
+        let x = match typ {
+            Amber => 2,
+            Bronze => 4,
+            Copper => 6,
+            Desert => 8,
+        };
+        (1..home_size + 1).map(|y| (x, y)).collect::<Vec<(usize, usize)>>()
+    }
+
+    fn get_pod_home(&self, home_size: usize) -> Vec<(usize, usize)> {
+        Self::calculate_home(self.typ, home_size)
+    }
+
+    fn can_get_home(&self, pods: &Pods, home: &Pods, home_size: usize) -> Option<(usize, usize)> {
+        // 1. check if pod can move:
+        if self.pos.0 > 1 && pods.iter().any(|other| other.pos.0 == self.pos.0 && other.pos.1 < self.pos.1) {
+            return None;
+        }
+
+        // 2. check if pod_home is free
+        let pod_home = self.get_pod_home(home_size);
+
+        // check if there are other than own podtypes in home
+        if pods.iter()
+            .chain(home.iter().filter(|other| self.typ != other.typ))
+            .any(|other| pod_home.contains(&other.pos)) {
+            return None
+        }
+
+        let y_pos = home_size - home.iter()
+                                    .filter(|other| self.typ == other.typ)
+                                    .count();
+
+        let home_pos = match self.typ {
+            Amber => (2, y_pos),
+            Bronze => (4, y_pos),
+            Copper => (6, y_pos),
+            Desert => (8, y_pos),
+        };
+
+        // check if there are any pods on path to pod_home in hallway
+        let lower_bound = self.pos.0.min(home_pos.0);
+        let upper_bound = self.pos.0.max(home_pos.0);
+
+        if pods.iter().any(|pod| pod.pos.1 == 0 && pod.pos.0 < upper_bound && pod.pos.0 > lower_bound) {
+            return None
+        }
+
+        Some(home_pos)
+    }           
+}
+
+impl Deref for Amphipod {
+    type Target = Type;
+
+    fn deref(&self) -> &Self::Target {
+        &self.typ
+    }
+}
+
+impl Display for Amphipod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+       let pod = match self.typ {
+        Type::Amber => "A",
+        Type::Bronze => "B",
+        Type::Copper => "C",
+        Type::Desert => "D",
+    };
+
+        write!(f, "{}", pod)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Hash, Eq, PartialOrd)]
+enum Type {
+    Amber = 1,
+    Bronze = 10,
+    Copper = 100,
+    Desert = 1000,
+}
+
+impl Type {
+    const ALL: [Type; 4] = [Amber, Bronze, Copper, Desert];
+}
+
+/// The rows of the rooms, every one holds one amphipod of each type
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagram {
+    rows: Vec<String>,
+}
+
+impl Diagram {
+    /// The rows of the rooms from top to bottom, drawn like in the puzzle
+    pub fn rows(&self) -> impl Iterator<Item = &str> {
+        self.rows.iter().map(String::as_str)
+    }
+
+    /// Returns the diagram with the two folded rows of part 2 inserted
+    pub fn unfolded(&self) -> Diagram {
+        let mut rows = self.rows.clone();
+        rows.splice(1..1, ADDITIONAL_ROWS.map(String::from));
+        Diagram { rows }
+    }
+
+    /// Returns (pods which need to move, pods which are already home)
+    fn pods(&self) -> (Pods, Pods) {
+        let mut pods = vec![];
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                if let Ok(typ) = Type::try_from(c) {
+                    pods.push(pod!(typ, x - 1, y + 1));
+                }
+            }
+        }
+
+        // pods at the bottom of their own room never have to move
+        let mut home = vec![];
+        for typ in Type::ALL {
+            for pos in Amphipod::calculate_home(typ, self.rows.len()).into_iter().rev() {
+                match pods.iter().position(|pod| pod.pos == pos && pod.typ == typ) {
+                    Some(i) => home.push(pods.remove(i)),
+                    None => break,
+                }
+            }
+        }
+
+        (pods, home)
+    }
+}
+
+/// Printed like in the puzzle, with an empty hallway
+impl Display for Diagram {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "#############\n#...........#")?;
+        for row in &self.rows {
+            writeln!(f, "{}", row)?;
+        }
+        write!(f, "  #########")
+    }
+}
+
+/// Parses the diagram of the burrow, the hallway has to be empty
+pub fn parse(input: &str) -> Result<Diagram> {
+    let _span = span!(Debug, "parse");
+    let mut rows = vec![];
+    let mut counts = HashMap::new();
+    for (i, line) in input.lines().enumerate() {
+        let mut row_pods = vec![];
+        for (x, c) in line.char_indices().filter(|(_, c)| c.is_alphanumeric()) {
+            let part = &line[x..x + c.len_utf8()];
+            let typ = Type::try_from(c).map_err(|e| Error::in_line(i, line, part, e))?;
+            if ![3, 5, 7, 9].contains(&x) {
+                return Err(Error::in_line(i, line, part, "amphipods have to start in a room"));
+            }
+            *counts.entry(typ).or_insert(0) += 1;
+            row_pods.push(c.to_string());
+        }
+        match row_pods.len() {
+            0 => (),
+            // whatever else is drawn around the amphipods, the rows are kept like in the puzzle
+            4 if rows.is_empty() => rows.push(format!("###{}###", row_pods.join("#"))),
+            4 => rows.push(format!("  #{}#", row_pods.join("#"))),
+            _ => return Err(Error::in_line(i, line, line, "every room has to be filled")),
+        }
+    }
+    if rows.is_empty() || Type::ALL.iter().any(|typ| counts.get(typ) != Some(&rows.len())) {
+        return Err(Error::invalid("every type of amphipod has to fill exactly one room"));
+    }
+
+    Ok(Diagram { rows })
+}
+
+impl TryFrom<char> for Type {
+    type Error = String;
+
+    fn try_from(c: char) -> std::result::Result<Self, String> {
+        match c {
+            'A' => Ok(Type::Amber),
+            'B' => Ok(Type::Bronze),
+            'C' => Ok(Type::Copper),
+            'D' => Ok(Type::Desert),
+            _ => Err(format!("'{}' is not an amphipod", c)),
+        }
+    }
+}
+
+impl From<Type> for &str {
+    fn from(pod: Type) -> Self {
+        match pod {
+            Type::Amber => "A",
+            Type::Bronze => "B",
+            Type::Copper => "C",
+            Type::Desert => "D",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{observe::{render, Frame, NoObserver}, search};
+
+    use super::{organize, parse, get_solution_1, solve_1};
+    use super::Amphipod;
+    use super::Burrow;
+    use super::Organizing;
+    use super::Pods;
+    use super::Type::*;
+
+    // do this manually
+    fn create_test_data() -> (Pods, Pods) {
+        (
+            vec![
+                Amphipod::new(Bronze, (6, 1)), Amphipod::new(Bronze, (2, 1)), Amphipod::new(Copper, (4, 1)), Amphipod::new(Desert, (8, 1)),
+                Amphipod::new(Desert, (4, 2)), Amphipod::new(Amber, (8, 2))
+            ],
+            vec![Amphipod::new(Amber, (2, 2)), Amphipod::new(Copper, (6, 2))],
+        )
+    }
+
+    fn create_test_data_2() -> (Pods, Pods) {
+        (
+            vec![
+                Amphipod::new(Bronze, (2, 1)), Amphipod::new(Copper, (4, 1)), Amphipod::new(Bronze, (6, 1)), Amphipod::new(Desert, (8, 1)),
+                Amphipod::new(Desert, (2, 2)), Amphipod::new(Copper, (4, 2)), Amphipod::new(Bronze, (6, 2)), Amphipod::new(Amber, (8, 2)),
+                Amphipod::new(Desert, (2, 3)), Amphipod::new(Bronze, (4, 3)), Amphipod::new(Amber, (6, 3)), Amphipod::new(Copper, (8, 3)),
+                Amphipod::new(Desert, (4, 4)), Amphipod::new(Amber, (8, 4))
+            ],
+            vec![Amphipod::new(Amber, (2, 4)), Amphipod::new(Copper, (6, 4))]
+        )
+    }
+
+    fn create_actual_data() -> Pods {
+        vec![
+            Amphipod::new(Copper, (2, 1)), Amphipod::new(Amber, (4, 1)), Amphipod::new(Bronze, (6, 1)), Amphipod::new(Desert, (8, 1)),
+            Amphipod::new(Copper, (2, 2)), Amphipod::new(Amber, (4, 2)), Amphipod::new(Desert, (6, 2)), Amphipod::new(Bronze, (8, 2)),
+        ]
+    }
+
+    #[test]
+    fn test_amphi_cost() {
+        let (amber, bronze, copper, desert) = (Amphipod::new(Amber, (0, 0)), Amphipod::new(Bronze, (0, 0)), Amphipod::new(Copper, (0, 0)), Amphipod::new(Desert, (0, 0)));
+        assert_eq!(amber.cost(), 1);
+        assert_eq!(bronze.cost(), 10);
+        assert_eq!(copper.cost(), 100);
+        assert_eq!(desert.cost(), 1000);
+    }
+
+    #[test]
+    fn test_print_burrow() {
+        let pods = vec![
+            Amphipod::new(Amber, (0, 0)),
+            Amphipod::new(Amber, (2, 2)),
+            Amphipod::new(Bronze, (2, 1)),
+            Amphipod::new(Bronze, (4, 2)),
+            Amphipod::new(Copper, (3, 0)),
+            Amphipod::new(Copper, (5, 0)),
+            Amphipod::new(Desert, (8, 1)),
+            Amphipod::new(Desert, (10, 0)),
+            ];
+        
+        Burrow::new(pods, vec![]);
+    }
+
+    #[test]
+    fn test_calculate_move_costs() {
+        let bronze = Amphipod::new(Bronze, (0, 0)); // right
+        let copper = Amphipod::new(Copper, (2, 2)); // up right
+        let desert = Amphipod::new(Desert, (5, 0)); // right down
+        let amber = Amphipod::new(Amber, (8, 2));   // up left down
+
+        let right = (9, 0);
+        let up_right = (3, 0);
+        let right_down = (8, 1);
+        let up_left_down = (2, 2);
+
+        assert_eq!(bronze.calculate_move_cost(&right), 90);
+        assert_eq!(copper.calculate_move_cost(&up_right), 300);
+        assert_eq!(desert.calculate_move_cost(&right_down), 4000);
+        assert_eq!(amber.calculate_move_cost(&up_left_down), 10);
+    }
+
+    #[test]
+    fn test_get_possible_moves() {
+        let test_pod = Amphipod::new(Amber, (6, 2));
+        
+        // no other pods, should return all possible moves
+        assert_eq!(
+            test_pod.get_possible_moves(&vec![]), 
+            vec![(0, 0), (1, 0), (3, 0), (5, 0), (7, 0), (9, 0), (10, 0)]
+        );
+
+        // above is no space, should not return any moves
+        let initial = vec![Amphipod::new(Desert, (6, 1))];
+        assert_eq!(test_pod.get_possible_moves(&initial), vec![]);
+
+        // only left positions are free
+        let moved = vec![Amphipod::new(Desert, (7, 0))];
+        assert_eq!(
+            test_pod.get_possible_moves(&moved),
+            vec![(0, 0), (1, 0), (3, 0), (5, 0)]
+        );
+
+        // only right positions are free
+        let moved = vec![Amphipod::new(Desert, (5, 0))];
+        assert_eq!(
+            test_pod.get_possible_moves(&moved),
+            vec![(7, 0), (9, 0), (10, 0)]
+        );
+
+        // some left and some right are free
+        let moved = vec![
+            Amphipod::new(Desert, (0, 0)),
+            Amphipod::new(Desert, (3, 0)),
+            Amphipod::new(Desert, (10, 0)),
+        ];
+        assert_eq!(
+            test_pod.get_possible_moves(&moved),
+            vec![(5, 0), (7, 0), (9, 0)]
+        );
+    }
+
+    #[test]
+    fn test_can_get_home() {
+        fn verify_result(actual: Option<(usize, usize)>, expected: (usize, usize), is_some: bool) {
+            if is_some {
+                assert!(actual.is_some());
+                assert_eq!(actual.unwrap(), expected);
+            } else {
+                assert!(actual.is_none());
+            }
+        }
+        let test_pod_initial = pod!(Amber, 8, 2);
+        
+        // no other pods, can get home
+        verify_result(test_pod_initial.can_get_home(&vec![], &vec![], 2), (2, 2), true);
+
+        // one pod already in home
+        verify_result(test_pod_initial.can_get_home(&vec![], &vec![pod!(Amber, 2, 2)], 2), (2, 1), true);
+    
+        // pod with different type in home
+        verify_result(test_pod_initial.can_get_home(&vec![], &vec![pod!(Bronze, 2, 2)], 2), (2, 1), false);
+
+        // pod is in initial and can't move outside
+        verify_result(test_pod_initial.can_get_home(&vec![pod!(Bronze, 8, 1)], &vec![], 2), (2, 1), false);
+
+        // some pods in hallway and initial but not in the way
+        let test_pod_initial = pod!(Bronze, 8, 2);
+        let pods = vec![pod!(Bronze, 9, 0), pod!(Amber, 0, 1), pod!(Bronze, 6, 2), pod!(Desert, 6, 1)];
+        verify_result(test_pod_initial.can_get_home(&pods, &vec![], 2), (4, 2), true);
+
+        // pods in the way
+        let moved = vec![
+            pod!(Bronze, 7, 0)
+        ];
+        verify_result(test_pod_initial.can_get_home(&moved, &vec![], 2), (4, 2), false);
+
+    }
+
+    #[test]
+    fn test_parse() {
+        let (expected_pods, expected_home) = create_test_data();
+        let (pods, home) = parse(include_str!("../../data/2021/day_23_test.txt")).unwrap().pods();
+        assert_eq!(pods.len(), expected_pods.len());
+        assert!(pods.iter().all(|pod| expected_pods.contains(pod)));
+        assert_eq!(home.len(), expected_home.len());
+        assert!(home.iter().all(|pod| expected_home.contains(pod)));
+
+        let (expected_pods, expected_home) = create_test_data_2();
+        let (pods, home) = parse(include_str!("../../data/2021/day_23_test.txt")).unwrap().unfolded().pods();
+        assert_eq!(pods.len(), expected_pods.len());
+        assert!(pods.iter().all(|pod| expected_pods.contains(pod)));
+        assert_eq!(home.len(), expected_home.len());
+        assert!(home.iter().all(|pod| expected_home.contains(pod)));
+
+        let diagram = parse("#...........#\n   B C B D\n  #A#D#C#A#  ").unwrap();
+        assert_eq!(diagram.to_string(), include_str!("../../data/2021/day_23_test.txt").trim_end());
+        assert_eq!(parse(&diagram.to_string()).ok(), Some(diagram));
+
+        let error = parse("#...........#\n###B#C#E#D###\n  #A#D#C#A#").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 8: 'E' is not an amphipod");
+        let error = parse("#...........#\n###B#C#B#D###\n  #A#D#C#B#").unwrap_err();
+        assert_eq!(error.to_string(), "invalid input: every type of amphipod has to fill exactly one room");
+    }
+
+    #[test]
+    fn test_get_solution_1() {
+        assert_eq!(get_solution_1(include_str!("../../data/2021/day_23_test.txt")).unwrap(), 12521);
+    }
+
+    #[test]
+    fn test_deep_rooms() {
+        // the example after unfolding, its rooms are already 4 rows deep
+        let deep = "#############\n#...........#\n###B#C#B#D###\n  #D#C#B#A#\n  #D#B#A#C#\n  #A#D#C#A#\n  #########";
+        let diagram = parse(deep).unwrap();
+        assert_eq!(diagram, parse(include_str!("../../data/2021/day_23_test.txt")).unwrap().unfolded());
+        assert_eq!(solve_1(&diagram).unwrap(), 44169);
+
+        let (initial, home) = diagram.unfolded().pods();
+        let burrow = Burrow::new(initial, home);
+        assert_eq!(burrow.draw().lines().count(), 9);
+        assert_eq!(render(&burrow).trim_end(), burrow.draw());
+    }
+
+    #[test]
+    fn test_organize_pods() {
+        let (initial, home) = create_test_data_2();
+        // let initial = create_actual_data();
+        // let home = vec![];
+        // println!("{}", Burrow::new(initial, vec![], home, 0));
+        println!("{:?}", Burrow::organize_pods(initial, home, &mut NoObserver));
+    }
+
+    #[test]
+    fn test_burrow_frame() {
+        let (initial, home) = create_test_data_2();
+        let burrow = Burrow::new(initial, home);
+        assert_eq!(render(&burrow).trim_end(), burrow.draw());
+
+        let mut frames = vec![];
+        let diagram = parse(include_str!("../../data/2021/day_23_test.txt")).unwrap();
+        let cost = organize(&diagram, &mut |step, frame: &dyn Frame| frames.push((step, render(frame)))).unwrap();
+        assert_eq!(cost, 12521);
+        // one frame per move of the solution, which ends with every pod at home
+        let (initial, home) = diagram.pods();
+        let path = search::astar(&Organizing { start: Burrow::new(initial, home) }).path.unwrap();
+        assert_eq!(frames.len(), path.states.len() - 1);
+        assert_eq!(frames.last().unwrap().0, frames.len());
+        assert!(frames.last().unwrap().1.contains("#A#B#C#D#"));
+    }
+
+    #[test]
+    fn test_organizing_path() {
+        let (initial, home) = create_test_data();
+        let organizing = Organizing { start: Burrow::new(initial, home) };
+        let searched = search::astar(&organizing);
+        let path = searched.path.unwrap();
+        assert_eq!(path.cost, 12521);
+        assert!(path.states[0] == organizing.start);
+        assert!(path.states.last().unwrap().pods.is_empty());
+        // every pod which isn't home moves at least once
+        assert!(path.states.len() > organizing.start.pods.len());
+        assert!(searched.stats.expanded > 0);
+
+        // the heuristic is only an optimization
+        assert_eq!(search::dijkstra(&organizing).cost(), Some(12521));
+    }
+
+    #[test]
+    fn test_burrow_equality() {
+        let (initial, home) = create_test_data();
+        let mut reordered = initial.clone();
+        reordered.reverse();
+        assert!(Burrow::new(initial.clone(), home.clone()) == Burrow::new(reordered, home.clone()));
+        assert!(Burrow::new(initial, home.clone()) != Burrow::new(create_actual_data(), home));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{event, limit, span, Answer, Error, Result, Solver};
+
+type Instruction = Vec<String>;
+
+// order in which digits are tried, the first serial number found is the largest/smallest one
+const DIGITS_DESC: [isize; 9] = [9, 8, 7, 6, 5, 4, 3, 2, 1];
+const DIGITS_ASC: [isize; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    solve_1(&parse(input)?)
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    solve_2(&parse(input)?)
+}
+
+/// Largest accepted model number
+pub fn solve_1(monad: &Monad) -> Result<usize> {
+    monad.find(&DIGITS_DESC).ok_or_else(|| Error::invalid("no model number is accepted"))
+}
+
+/// Smallest accepted model number
+pub fn solve_2(monad: &Monad) -> Result<usize> {
+    monad.find(&DIGITS_ASC).ok_or_else(|| Error::invalid("no model number is accepted"))
+}
+
+/// Parses the program, it has to check 14 digits in blocks of 18 instructions
+pub fn parse(input: &str) -> Result<Monad> {
+    Ok(Monad { variables: parse_variables(input)? })
+}
+
+/// The model number checking program, reduced to the two variables of each digit check
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Monad {
+    variables: Vec<(isize, isize)>,
+}
+
+impl Monad {
+    /// The values added to x and y in the check of each digit
+    pub fn variables(&self) -> &[(isize, isize)] {
+        &self.variables
+    }
+
+    /// Returns whether the model number is accepted, None if it hasn't 14 digits from 1 to 9
+    pub fn accepts(&self, number: usize) -> Option<bool> {
+        let digits: Vec<isize> = number.to_string().bytes().map(|b| (b - b'0') as isize).collect();
+        if digits.len() != self.variables.len() || digits.contains(&0) {
+            return None;
+        }
+        let z = digits.iter().zip(&self.variables).try_fold(0, |z, (&w, &(n, m))| try_digit(z, n, m, w));
+        Some(z == Some(0))
+    }
+
+    fn find(&self, order: &[isize]) -> Option<usize> {
+        calculate_batch(&self.variables, 0, vec![], order).map(into_number)
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+fn calculate_batch(variables: &[(isize, isize)], z: isize, others: Vec<isize>, order: &[isize]) -> Option<Vec<isize>> {
+    if variables.is_empty() {
+        event!(Debug, "accepted", z = z, digits = others);
+        return Some(others);
+    }
+    if limit::stopped() {
+        return None;
+    }
+    for &w in order {
+        let mut new_others = others.clone();
+        new_others.push(w);
+        let (n, m) = variables[0];
+        event!(Trace, "try", position = others.len(), digit = w, z = z);
+        if let Some(z) = try_digit(z, n, m, w) {
+            if let Some(digits) = calculate_batch(&variables[1..], z, new_others.clone(), order) {
+                return Some(digits);
+            }
+        }
+    }
+
+    None
+}
+
+fn into_number(digits: Vec<isize>) -> usize {
+    let mut serial_n = 0;
+    for n in digits {
+        serial_n = serial_n * 10 + n as usize;
+    }
+    serial_n
+}
+
+/// Returns the two variables of each digit check
+fn parse_variables(input: &str) -> Result<Vec<(isize, isize)>> {
+    let instructions = parse_instructions(input)?;
+    // the program checks one digit in each block of 18 instructions
+    if instructions.len() != 14 * 18 {
+        return Err(Error::invalid(format!("expected 14 * 18 instructions, got {}", instructions.len())));
+    }
+    instructions.chunks(18).enumerate().map(|(n, batch)| get_variables(n, batch)).collect()
+}
+
+fn get_variables(n: usize, batch: &[Instruction]) -> Result<(isize, isize)> {
+    match (batch[5].get(2).map(|v| v.parse::<isize>()), batch[15].get(2).map(|v| v.parse::<isize>())) {
+        (Some(Ok(n)), Some(Ok(m))) => Ok((n, m)),
+        _ => Err(Error::invalid(format!("block {} doesn't look like a digit check", n + 1))),
+    }
+}
+
+fn try_digit(z: isize, n: isize, m: isize, w: isize) -> Option<isize> {
+    if n < 0 {
+        if (z % 26) + n == w {
+            Some(z / 26)
+        } else {
+            None
+        }
+    } else {
+        Some(26 * z + w + m)
+    }
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>> {
+    let _span = span!(Debug, "parse");
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            // the input may contain comments like `mul x 0  // x = 0`
+            let code = line.split_once("//").map_or(line, |(code, _)| code);
+            let parts = code.split_whitespace().collect::<Vec<&str>>();
+            match parts.as_slice() {
+                ["inp", _] | ["add" | "mul" | "div" | "mod" | "eql", _, _] => {
+                    Ok(parts.iter().map(|part| part.to_string()).collect())
+                },
+                _ => Err(Error::in_line(i, line, line, format!("invalid instruction '{}'", line))),
+            }
+        })
+        .collect()
+}
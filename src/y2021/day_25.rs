@@ -0,0 +1,167 @@
+use std::fmt::Display;
+
+use crate::{event, grid::{Grid, Tile}, limit, observe::{NoObserver, Observer}, span, Answer, Result, Solver};
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn solve_1(floor: &SeaFloor) -> usize {
+    simulate(floor, &mut NoObserver)
+}
+
+/// Moves the sea cucumbers until they stop, returns the first step in which none of them moves
+pub fn simulate(floor: &SeaFloor, observer: &mut dyn Observer) -> usize {
+    let mut steps = 0;
+    let mut g = floor.clone();
+    while !limit::stopped() && g.do_move() {
+        steps += 1;
+        event!(Trace, "step", step = steps);
+        observer.step(steps, &g.cells);
+    }
+    event!(Debug, "stopped", step = steps + 1);
+    steps + 1
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, _input: &str) -> Result<Answer> {
+        Ok(Answer::None)
+    }
+
+    fn observe(&self, part: u8, input: &str, observer: &mut dyn Observer) -> Option<Result<Answer>> {
+        match part {
+            1 => Some(parse(input).map(|floor| simulate(&floor, observer).into())),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cucumber {
+    South,
+    East,
+}
+
+/// `>` faces east, `v` south and `.` is empty
+impl Tile for Option<Cucumber> {
+    const NAME: &'static str = "sea cucumber";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(None),
+            '>' => Some(Some(Cucumber::East)),
+            'v' => Some(Some(Cucumber::South)),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Some(Cucumber::East) => '>',
+            Some(Cucumber::South) => 'v',
+            None => '.',
+        }
+    }
+}
+
+/// The sea floor, cucumbers which move over an edge appear on the opposite edge
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeaFloor {
+    cells: Grid<Option<Cucumber>>,
+}
+
+impl Display for SeaFloor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+impl SeaFloor {
+    pub fn cells(&self) -> &Grid<Option<Cucumber>> {
+        &self.cells
+    }
+
+    /// Returns the cucumber at (row, col), None if it's empty or out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<&Cucumber> {
+        self.cells.get(col, row)?.as_ref()
+    }
+
+    /// Moves the cucumbers facing `herd` on `old` and returns whether any of them moved
+    fn move_herd(&mut self, herd: Cucumber, old: &Grid<Option<Cucumber>>) -> bool {
+        let (dx, dy) = match herd {
+            Cucumber::East => (1, 0),
+            Cucumber::South => (0, 1),
+        };
+        let mut moved = false;
+        for (x, y) in old.positions() {
+            if old[(x, y)] != Some(herd) {
+                continue;
+            }
+            let next = old.wrap(x as isize + dx, y as isize + dy);
+            if old[next].is_none() {
+                self.cells[(x, y)] = None;
+                self.cells[next] = Some(herd);
+                moved = true;
+            }
+        }
+
+        moved
+    }
+
+    /// Moves the east facing cucumbers, then the south facing ones.
+    /// Returns false if none of them could move
+    pub fn do_move(&mut self) -> bool {
+        let moved_east = self.move_herd(Cucumber::East, &self.cells.clone());
+        let moved_south = self.move_herd(Cucumber::South, &self.cells.clone());
+        moved_east || moved_south
+    }
+}
+
+/// Parses the sea floor, `>` faces east, `v` south and `.` is empty
+pub fn parse(input: &str) -> Result<SeaFloor> {
+    let _span = span!(Debug, "parse");
+    Ok(SeaFloor { cells: Grid::parse(input, input)? })
+}
+
+#[test]
+fn test_stop() {
+    let mut steps = 0;
+    let mut g = parse(include_str!("../../data/2021/day_25_test.txt")).unwrap();
+    // println!("{}\n", g);
+    // g.do_move();
+    // println!("{}\n", g);
+    // g.do_move();
+    // println!("{}\n", g);
+    // g.do_move();
+    // println!("{}\n", g);
+    // g.do_move();
+    // println!("{}\n", g);
+    while g.do_move() {
+        steps += 1;
+        if steps % 10 == 0 {
+            println!("{}\n", g);
+        }
+    }
+    steps += 1;
+    assert_eq!(steps, 58);
+}
+
+#[test]
+fn test_wrap_around() {
+    let mut g = parse("...>>>>>...").unwrap();
+    assert!(g.do_move());
+    assert_eq!(g.to_string(), "...>>>>.>..");
+    // the south facing cucumber moves into the place the east facing one left
+    let mut g = parse(">.\n..\nv.").unwrap();
+    assert!(g.do_move());
+    assert_eq!(g.to_string(), "v>\n..\n..");
+    assert_eq!(g.get(0, 0), Some(&Cucumber::South));
+}
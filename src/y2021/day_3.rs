@@ -0,0 +1,193 @@
+use crate::{common::lines, span, Answer, Error, Result, Solver};
+
+/// The diagnostic report, binary numbers of the same length
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Report {
+    numbers: Vec<String>,
+}
+
+impl Report {
+    pub fn numbers(&self) -> &[String] {
+        &self.numbers
+    }
+
+    /// Gamma rate times epsilon rate
+    pub fn power_consumption(&self) -> u32 {
+        binary_diagnostic(&self.numbers)
+    }
+
+    /// Fails if the bit criteria don't leave a single number, like for duplicated numbers
+    pub fn oxygen_rating(&self) -> Result<u32> {
+        determine_oxygen_rating(&self.numbers)
+    }
+
+    pub fn co2_rating(&self) -> Result<u32> {
+        determine_o2_rating(&self.numbers)
+    }
+}
+
+/// Parses the report and checks that all numbers are binary, have the same length and fit into an u32
+pub fn parse(input: &str) -> Result<Report> {
+    let _span = span!(Debug, "parse");
+    let numbers = lines(input);
+    validate(&numbers)?;
+    Ok(Report { numbers })
+}
+
+pub fn solve_1(report: &Report) -> u32 {
+    report.power_consumption()
+}
+
+pub fn solve_2(report: &Report) -> Result<u32> {
+    Ok(report.oxygen_rating()? * report.co2_rating()?)
+}
+
+fn binary_diagnostic(input: &[String]) -> u32 {
+    let line_length = input[0].len();
+    let mut pos = vec![0_i32; line_length];
+    for line in input.iter().map(|line| line.as_bytes()) {
+        for (bit, count) in line.iter().zip(pos.iter_mut()) {
+            if *bit == 0x30 { *count -= 1 } else { *count += 1}; 
+        }
+    }
+
+    let mut result = 0;
+    pos.reverse();
+    for (i, count) in pos.iter().enumerate() {
+        if *count > 0 { result += 2_u32.pow(i as u32)}
+    }
+    result * (!result & (2_u32.pow(line_length as u32) - 1)) 
+}
+
+pub fn get_solution_1(input: &str) -> Result<u32> {
+    Ok(solve_1(&parse(input)?))
+}
+
+/// Checks that all lines are binary numbers of the same length, which fit into an u32
+fn validate(input: &[String]) -> Result<()> {
+    let line_length = input.first().map_or(0, |line| line.len());
+    if line_length == 0 || line_length > 32 {
+        return Err(Error::invalid("expected binary numbers with 1 to 32 digits"));
+    }
+    for (i, line) in input.iter().enumerate() {
+        if let Some((pos, c)) = line.char_indices().find(|(_, c)| *c != '0' && *c != '1') {
+            return Err(Error::in_line(i, line, &line[pos..pos + c.len_utf8()], "expected 0 or 1"));
+        }
+        if line.len() != line_length {
+            return Err(Error::in_line(i, line, line, format!("expected {} digits", line_length)));
+        }
+    }
+    Ok(())
+}
+
+fn determine_oxygen_rating(input: &[String]) -> Result<u32> {
+    filter_values(input, '0', '1')
+}
+
+fn determine_o2_rating(input: &[String]) -> Result<u32> {
+    filter_values(input, '1', '0')
+}
+
+fn filter_values(input: &[String], most_common_criteria_1: char, most_common_criteria_2: char) -> Result<u32> {
+    let mut common_bit;
+    let mut filtered = input.to_vec();
+    for i in 0..input[0].len() {
+        // the filtering stops as soon as one number is left, even if it's the only one of the report
+        if filtered.len() == 1 {
+            break;
+        }
+        common_bit = if most_common_bit(i, &filtered) < 0 { most_common_criteria_1 } else { most_common_criteria_2 };
+        filtered.retain(|n| n.chars().nth(i).unwrap() == common_bit);
+    }
+    match &filtered[..] {
+        // validate checked that the numbers are binary and fit into an u32
+        [number] => Ok(u32::from_str_radix(number, 2).expect("the numbers are validated")),
+        _ => Err(Error::invalid("no unique rating")),
+    }
+}
+
+fn most_common_bit(index: usize, input: &[String]) -> i32 {
+    let mut common_bit = 0;
+    for line in input {
+        // Unwrap is safe, since index is bound by line length
+        if line.chars().nth(index).unwrap() == '0' {
+            common_bit -= 1;
+        } else {
+            common_bit += 1;
+        }
+    }
+    if common_bit == 0 {
+        1      
+    } else {
+        common_bit
+    }
+}
+
+pub fn get_solution_2(input: &str) -> Result<u32> {
+    solve_2(&parse(input)?)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::day_3::binary_diagnostic;
+
+    use super::{determine_oxygen_rating, determine_o2_rating, get_solution_2};
+
+    #[test]
+    fn test_198() {
+        let input: Vec<String> = vec!["00100",
+                                "11110",
+                                "10110",
+                                "10111",
+                                "10101",
+                                "01111",
+                                "00111",
+                                "11100",
+                                "10000",
+                                "11001",
+                                "00010",
+                                "01010"].into_iter().map(String::from).collect();
+        
+        let result = binary_diagnostic(&input);
+        assert_eq!(result, 198)
+    }
+
+    #[test]
+    fn test_o2_oxygen_rating() {
+        let input: Vec<String> = vec!["00100",
+                                "11110",
+                                "10110",
+                                "10111",
+                                "10101",
+                                "01111",
+                                "00111",
+                                "11100",
+                                "10000",
+                                "11001",
+                                "00010",
+                                "01010"].into_iter().map(String::from).collect();
+        let oxygen_rating = determine_oxygen_rating(&input);
+        assert_eq!(oxygen_rating.unwrap(), 23);
+        let o2_rating = determine_o2_rating(&input);
+        assert_eq!(o2_rating.unwrap(), 10);
+    }
+
+    #[test]
+    fn test_no_unique_rating() {
+        assert_eq!(get_solution_2("10").unwrap(), 4);
+        assert_eq!(get_solution_2("10\n10").unwrap_err().to_string(), "invalid input: no unique rating");
+    }
+}
\ No newline at end of file
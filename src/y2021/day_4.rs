@@ -0,0 +1,204 @@
+use crate::{parse::{blocks, list, words}, span, Answer, Error, Result, Solver};
+
+type BoardMap = (i32, bool);
+
+/// The drawn numbers and the 5x5 boards, no number is marked
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bingo {
+    numbers: Vec<i32>,
+    boards: Vec<Vec<BoardMap>>,
+}
+
+impl Bingo {
+    pub fn numbers(&self) -> &[i32] {
+        &self.numbers
+    }
+
+    /// Returns the boards, each as 25 numbers row by row
+    pub fn boards(&self) -> impl Iterator<Item = Vec<i32>> + '_ {
+        self.boards.iter().map(|board| board.iter().map(|(n, _)| *n).collect())
+    }
+
+    /// Score of the board which wins first, -1 if none wins
+    pub fn first_winner_score(&self) -> i32 {
+        determine_winner(&self.numbers, &mut self.boards.clone())
+    }
+
+    /// Score of the board which wins last, -1 if not all boards win
+    pub fn last_winner_score(&self) -> i32 {
+        determine_last_winner(self.numbers.clone(), self.boards.clone())
+    }
+}
+
+/// Parses the drawn numbers and the boards, which are separated by empty lines
+pub fn parse(input: &str) -> Result<Bingo> {
+    let _span = span!(Debug, "parse");
+    let (numbers, boards) = parse_board(input)?;
+    Ok(Bingo { numbers, boards })
+}
+
+pub fn solve_1(bingo: &Bingo) -> i32 {
+    bingo.first_winner_score()
+}
+
+pub fn solve_2(bingo: &Bingo) -> i32 {
+    bingo.last_winner_score()
+}
+
+fn parse_board(input: &str) -> Result<(Vec<i32>, Vec<Vec<BoardMap>>)> {
+    let blocks = blocks(input);
+    let first = blocks.first().ok_or_else(|| Error::invalid("missing numbers"))?;
+    let numbers = list(input, first, ',')?;
+    let mut boards: Vec<Vec<BoardMap>> = vec![];
+    for block in blocks.iter().skip(1) {
+        let board = words::<i32>(input, block)?;
+        if board.len() != 25 {
+            return Err(Error::at(input, block, "board doesn't have 5x5 numbers"));
+        }
+        boards.push(board.into_iter().map(|n| (n, false)).collect());
+    }
+
+    Ok((numbers, boards))
+}
+
+fn determine_winner(numbers: &[i32], boards: &mut [Vec<BoardMap>]) -> i32 {
+
+    for n in numbers {
+        for board in boards.iter_mut(){
+            let index = index_of(*n, board); 
+            if index >= 0 {
+                board[index as usize].1 = true
+            }
+            if  is_row_complete(board) || is_column_complete(board) {
+                let sum: i32 = board.iter()
+                    .filter(|(_, marked)| !marked)
+                    .map(|(n, _)| *n)
+                    .sum();
+
+                return sum * n;
+            }
+        }
+    }
+    -1
+}    
+
+fn is_row_complete(board: &[BoardMap]) -> bool {
+    for i in 0..5 {
+        if board[i * 5..i * 5 + 5].iter().all(|(_, is_marked)| *is_marked) {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_column_complete(board: &[BoardMap]) -> bool {
+    for i in 0..5 {
+        let mut is_winner = true;
+        for j in 0..5 {
+            if !board[i + 5 * j].1 {
+                is_winner = false;
+            }
+        }
+        if is_winner {
+            return true;
+        } 
+    }
+    false
+}
+
+fn index_of(item: i32, container: &[BoardMap]) -> i32 
+{
+    for (i, (n, _)) in container.iter().enumerate() {
+        if *n == item {
+            return i as i32;
+        }
+    }
+    -1
+}
+
+fn determine_last_winner(numbers: Vec<i32>, mut boards: Vec<Vec<BoardMap>>) -> i32 {
+
+    for n in &numbers {
+        for board in boards.iter_mut() {
+            let index = index_of(*n, board); 
+            if index >= 0 {
+
+                board[index as usize].1 = true
+            }
+        }
+        let (won, left): (Vec<_>, Vec<_>) = boards.into_iter().partition(|b| is_row_complete(b) || is_column_complete(b));
+        // several boards can win with the same number, the last of them wins last
+        if let (true, Some(last)) = (left.is_empty(), won.last()) {
+            let sum: i32 = last.iter()
+                    .filter(|(_, marked)| !marked)
+                    .map(|(n, _)| *n)
+                    .sum();
+
+            return sum * n;
+        }
+        boards = left;
+    }
+    -1
+}
+
+
+fn _print_board(board: &[BoardMap]) {
+    let mut board_string = String::new();
+    for (i, (n, marked)) in board.iter().enumerate() {
+        if i != 0 && i % 5 == 0 {
+            board_string += "\n";
+        }
+        
+        if *marked {
+            board_string += &format!("{}. ", n);
+        } else {
+            board_string += &format!("{} ", n);
+        }
+
+    }
+    println!("{}", board_string);
+}
+
+
+pub fn get_solution_1(input: &str) -> Result<i32> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<i32> {
+    Ok(solve_2(&parse(input)?))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_board, determine_last_winner};
+    use crate::y2021::day_4::determine_winner;
+
+    #[test]
+    fn test_parse_board() {
+        let (numbers, mut boards) = parse_board(include_str!("../../data/2021/day_4.txt")).unwrap();
+        // println!("{:?}", numbers);
+        println!("{:?}", boards.len());
+        let result = determine_winner(&numbers, &mut boards);
+        println!("{}", result);
+    }
+
+    #[test]
+    fn test_determine_last_winner() {
+        let (numbers, boards) = parse_board(include_str!("../../data/2021/day_4_test.txt")).unwrap();
+        let result = determine_last_winner(numbers, boards);
+        assert_eq!(1924, result);
+    }
+}
\ No newline at end of file
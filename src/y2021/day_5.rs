@@ -1,25 +1,62 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
-use crate::parse_lines;
+use crate::{common::lines, error::parse_in_line, span, Answer, Error, Result, Solver};
 
-struct Line {
+/// A line of hydrothermal vents, iterating over it yields every point from start to end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line {
     start: (u32, u32),
     end: (u32, u32),
 }
 
 impl Line {
-    fn new(start: (u32, u32), end: (u32, u32)) -> Self {
+    /// Lines have to be horizontal, vertical or diagonal at 45 degrees
+    pub fn new(start: (u32, u32), end: (u32, u32)) -> Self {
         Line { start, end }
     }
 
-    fn is_horizontal(&self) -> bool {
+    pub fn start(&self) -> (u32, u32) {
+        self.start
+    }
+
+    pub fn end(&self) -> (u32, u32) {
+        self.end
+    }
+
+    /// Parses a line like "0,9 -> 5,9", `i` is the index of the line in the input
+    fn parse(i: usize, line: &str) -> Result<Self> {
+        let parse_point = |point: &str| -> Result<(u32, u32)> {
+            match point.trim().split_once(',') {
+                Some((x, y)) => Ok((parse_in_line(i, line, x)?, parse_in_line(i, line, y)?)),
+                None => Err(Error::in_line(i, line, point, "expected a point like 'x,y'")),
+            }
+        };
+        match line.split_once("->") {
+            Some((start, end)) => {
+                let parsed = Line::new(parse_point(start)?, parse_point(end)?);
+                if parsed.is_horizontal() || parsed.is_vertical() || parsed.is_diagonal() {
+                    Ok(parsed)
+                } else {
+                    Err(Error::in_line(i, line, line, "line has to be horizontal, vertical or diagonal at 45 degrees"))
+                }
+            },
+            None => Err(Error::in_line(i, line, line, "expected two points separated by '->'")),
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
         self.start.1 == self.end.1
     }
 
-    fn is_vertical(&self) -> bool {
+    pub fn is_vertical(&self) -> bool {
         self.start.0 == self.end.0
     }
 
+    pub fn is_diagonal(&self) -> bool {
+        self.start.0.abs_diff(self.end.0) == self.start.1.abs_diff(self.end.1)
+    }
+
     fn _print_map(map: &HashMap<(u32, u32), u32>, dim: u32) {
         for i in 0..dim {
             let mut row = String::new();
@@ -35,27 +72,12 @@ impl Line {
     }
 }
 
-impl From<String> for Line {
-    fn from(line: String) -> Self {
-        let touples: Vec<(u32, u32)> = line.split("->")
-            .map(|touple| {
-            let n: Vec<u32> = touple.split(",").map(|n| n.trim().parse::<u32>().unwrap()).collect();
-            (n[0], n[1])
-            })
-            .collect();
-        Line::new(touples[0], touples[1])
-    }
-}
 
-impl From<&str> for Line {
-    fn from(line: &str) -> Self {
-        let touples: Vec<(u32, u32)> = line.split("->")
-        .map(|touple| {
-        let n: Vec<u32> = touple.split(",").map(|n| n.trim().parse::<u32>().unwrap()).collect();
-        (n[0], n[1])
-        })
-        .collect();
-    Line::new(touples[0], touples[1])
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Line::parse(0, s)
     }
 }
 
@@ -163,14 +185,8 @@ fn create_map(lines: Vec<Line>) -> HashMap<(u32, u32), u32> {
     map
 }
 
-pub fn get_solution_1() -> u32 {
-    let raw_lines = parse_lines("data/day_5.txt");
-    let lines: Vec<Line> = raw_lines.into_iter()
-        .map(
-            Line::from)
-        .filter(|line| line.is_horizontal() || line.is_vertical())
-        .collect();
-
+/// Counts the points where at least two lines overlap
+pub fn count_overlaps(lines: Vec<Line>) -> u32 {
     let map = create_map(lines);
     let mut count = 0;
     for (_, val) in map {
@@ -179,18 +195,44 @@ pub fn get_solution_1() -> u32 {
     count
 }
 
-pub fn get_solution_2() -> u32 {
-    let raw_lines = parse_lines("data/day_5.txt");
-    let lines: Vec<Line> = raw_lines.into_iter()
-        .map(Line::from)
-        .collect();
+/// Parses lines like "0,9 -> 5,9"
+pub fn parse(input: &str) -> Result<Vec<Line>> {
+    let _span = span!(Debug, "parse");
+    lines(input)
+        .iter()
+        .enumerate()
+        .map(|(i, line)| Line::parse(i, line))
+        .collect()
+}
 
-    let map = create_map(lines);
-    let mut count = 0;
-    for (_, val) in map {
-        if val >= 2 { count += 1 };
+/// Counts the overlaps of the horizontal and vertical lines
+pub fn solve_1(lines: &[Line]) -> u32 {
+    count_overlaps(lines.iter().copied().filter(|line| line.is_horizontal() || line.is_vertical()).collect())
+}
+
+/// Counts the overlaps of all lines
+pub fn solve_2(lines: &[Line]) -> u32 {
+    count_overlaps(lines.to_vec())
+}
+
+pub fn get_solution_1(input: &str) -> Result<u32> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<u32> {
+    Ok(solve_2(&parse(input)?))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
     }
-    count
 }
 
 
@@ -207,13 +249,21 @@ fn test_vertical_horizontal() {
 
 #[test]
 fn test_from_string() {
-    let line = Line::from(String::from("7,0 -> 7,4"));
+    let line = Line::parse(0, "7,0 -> 7,4").unwrap();
     assert!(line.is_vertical());
+    assert_eq!("7,0 -> 7,4".parse::<Line>().ok(), Some(line));
+    assert_eq!("1,2 -> 3".parse::<Line>().unwrap_err().to_string(), "line 1, column 7: expected a point like 'x,y'");
+}
+
+#[test]
+fn test_reject_skewed_line() {
+    assert!(Line::parse(0, "0,0 -> 3,1").is_err());
+    assert!(Line::parse(0, "0,0 -> 3,3").unwrap().is_diagonal());
 }
 
 #[test]
 fn test_iterator_horizontal_asc() {
-    let mut line = Line::from(String::from("1,5 -> 4,5")).into_iter();
+    let mut line = Line::parse(0, "1,5 -> 4,5").unwrap().into_iter();
     assert_eq!(line.next(), Some((1, 5)));
     assert_eq!(line.next(), Some((2, 5)));
     assert_eq!(line.next(), Some((3, 5)));
@@ -223,7 +273,7 @@ fn test_iterator_horizontal_asc() {
 
 #[test]
 fn test_iterator_horizontal_desc() {
-    let mut line = Line::from(String::from("3,4 -> 1,4")).into_iter();
+    let mut line = Line::parse(0, "3,4 -> 1,4").unwrap().into_iter();
     assert_eq!(line.next(), Some((3, 4)));
     assert_eq!(line.next(), Some((2, 4)));
     assert_eq!(line.next(), Some((1, 4)));
@@ -232,7 +282,7 @@ fn test_iterator_horizontal_desc() {
 
 #[test]
 fn test_iterator_vertical_asc() {
-    let mut line = Line::from(String::from("7,0 -> 7,4")).into_iter();
+    let mut line = Line::parse(0, "7,0 -> 7,4").unwrap().into_iter();
     assert_eq!(line.next(), Some((7, 0)));
     assert_eq!(line.next(), Some((7, 1)));
     assert_eq!(line.next(), Some((7, 2)));
@@ -243,7 +293,7 @@ fn test_iterator_vertical_asc() {
 
 #[test]
 fn test_iterator_vertical_desc() {
-    let mut line = Line::from(String::from("7,4 -> 7,0")).into_iter();
+    let mut line = Line::parse(0, "7,4 -> 7,0").unwrap().into_iter();
     assert_eq!(line.next(), Some((7, 4)));
     assert_eq!(line.next(), Some((7, 3)));
     assert_eq!(line.next(), Some((7, 2)));
@@ -254,7 +304,7 @@ fn test_iterator_vertical_desc() {
 
 #[test]
 fn test_iterator_diag_right_down() {
-    let line = Line::from("0,1 -> 3,4");
+    let line = Line::parse(0, "0,1 -> 3,4").unwrap();
     let mut iter = line.into_iter();
     assert_eq!(Some((0, 1)), iter.next());
     assert_eq!(Some((1, 2)), iter.next());
@@ -265,7 +315,7 @@ fn test_iterator_diag_right_down() {
 
 #[test]
 fn test_iterator_diag_right_up() {
-    let line = Line::from("0,4 -> 3,1");
+    let line = Line::parse(0, "0,4 -> 3,1").unwrap();
     let mut iter = line.into_iter();
     assert_eq!(Some((0, 4)), iter.next());
     assert_eq!(Some((1, 3)), iter.next());
@@ -276,7 +326,7 @@ fn test_iterator_diag_right_up() {
 
 #[test]
 fn test_iterator_diag_left_down() {
-    let line = Line::from("3,1 -> 0,4");
+    let line = Line::parse(0, "3,1 -> 0,4").unwrap();
     let mut iter = line.into_iter();
     assert_eq!(Some((3, 1)), iter.next());
     assert_eq!(Some((2, 2)), iter.next());
@@ -287,7 +337,7 @@ fn test_iterator_diag_left_down() {
 
 #[test]
 fn test_iterator_diag_left_up() {
-    let line = Line::from("3,4 -> 0,1");
+    let line = Line::parse(0, "3,4 -> 0,1").unwrap();
     let mut iter = line.into_iter();
     assert_eq!(Some((3, 4)), iter.next());
     assert_eq!(Some((2, 3)), iter.next());
@@ -299,9 +349,10 @@ fn test_iterator_diag_left_up() {
 
 #[test]
 fn test_create_map() {
-    let raw_lines = parse_lines("data/day_5_test.txt");
+    let raw_lines = lines(include_str!("../../data/2021/day_5_test.txt"));
     let lines: Vec<Line> = raw_lines.into_iter()
-        .map(|line| Line::from(line))
+        .enumerate()
+        .map(|(i, line)| Line::parse(i, &line).unwrap())
         .filter(|line| line.is_horizontal() || line.is_vertical())
         .collect();
     
@@ -316,9 +367,10 @@ fn test_create_map() {
 
 #[test]
 fn test_create_map_all() {
-    let raw_lines = parse_lines("data/day_5_test.txt");
+    let raw_lines = lines(include_str!("../../data/2021/day_5_test.txt"));
     let lines: Vec<Line> = raw_lines.into_iter()
-        .map(|line| Line::from(line))
+        .enumerate()
+        .map(|(i, line)| Line::parse(i, &line).unwrap())
         .collect();
     
     let map = create_map(lines);
@@ -1,5 +1,5 @@
 use std::{collections::HashMap};
-use crate::split_commas;
+use crate::{event, parse::list, span, Answer, Result, Solver};
 
 fn create_spawn_map(fish: Vec<u8>) -> HashMap<u8, u128> {
     let mut spawn_map = HashMap::new();
@@ -42,27 +42,76 @@ fn count_fish(spawn_map: HashMap<u8, u128>) -> u128 {
     sum
 }
 
-pub fn get_solution_1() -> u128 {
-    let fish = split_commas("data/day_6.txt");
-    let mut spawn_map = create_spawn_map(fish);
-    for _ in 0..80 {
-        update_spawn_map(&mut spawn_map)
+/// The lanternfish, counted by the days until they spawn
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct School {
+    spawn_map: HashMap<u8, u128>,
+}
+
+impl School {
+    pub fn new(fish: Vec<u8>) -> Self {
+        School { spawn_map: create_spawn_map(fish) }
+    }
+
+    /// Lets one day pass
+    pub fn advance(&mut self) {
+        update_spawn_map(&mut self.spawn_map)
+    }
+
+    pub fn count(&self) -> u128 {
+        count_fish(self.spawn_map.clone())
+    }
+
+    /// Counts the fish after some days, without changing the school
+    pub fn count_after(&self, days: usize) -> u128 {
+        let mut school = self.clone();
+        for day in 1..=days {
+            school.advance();
+            event!(Trace, "day", day = day, fish = school.count());
+        }
+        school.count()
     }
-    count_fish(spawn_map)
 }
 
-pub fn get_solution_2() -> u128 {
-    let fish = split_commas("data/day_6.txt");
-    let mut spawn_map = create_spawn_map(fish);
-    for _ in 0..256 {
-        update_spawn_map(&mut spawn_map)
+/// Parses the comma separated timers of the fish
+pub fn parse(input: &str) -> Result<School> {
+    let _span = span!(Debug, "parse");
+    Ok(School::new(list(input, input, ',')?))
+}
+
+pub fn solve_1(school: &School) -> u128 {
+    school.count_after(80)
+}
+
+pub fn solve_2(school: &School) -> u128 {
+    school.count_after(256)
+}
+
+pub fn get_solution_1(input: &str) -> Result<u128> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<u128> {
+    Ok(solve_2(&parse(input)?))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
     }
-    count_fish(spawn_map)
 }
 
 #[test]
 fn test_spawn_map() {
-    let fish = split_commas("data/day_6_test.txt");
+    let input = include_str!("../../data/2021/day_6_test.txt");
+    let fish = list(input, input, ',').unwrap();
     let spawn_map = create_spawn_map(fish);
     assert_eq!(Some(&0), spawn_map.get(&0));
     assert_eq!(Some(&1), spawn_map.get(&1));
@@ -77,7 +126,8 @@ fn test_spawn_map() {
 
 #[test]
 fn test_update_spawn_map() {
-    let fish = split_commas("data/day_6_test.txt");
+    let input = include_str!("../../data/2021/day_6_test.txt");
+    let fish = list(input, input, ',').unwrap();
     let mut spawn_map = create_spawn_map(fish);
     update_spawn_map(&mut spawn_map);
     assert_eq!(Some(&1), spawn_map.get(&0));
@@ -94,7 +144,8 @@ fn test_update_spawn_map() {
 
 #[test]
 fn test_count_18_days() {
-    let fish = split_commas("data/day_6_test.txt");
+    let input = include_str!("../../data/2021/day_6_test.txt");
+    let fish = list(input, input, ',').unwrap();
     let mut spawn_map = create_spawn_map(fish);
     for _ in 0..18 {
         update_spawn_map(&mut spawn_map);
@@ -105,7 +156,8 @@ fn test_count_18_days() {
 
 #[test]
 fn test_count_80_days() {
-    let fish = split_commas("data/day_6_test.txt");
+    let input = include_str!("../../data/2021/day_6_test.txt");
+    let fish = list(input, input, ',').unwrap();
     let mut spawn_map = create_spawn_map(fish);
     for _ in 0..80 {
         update_spawn_map(&mut spawn_map);
@@ -0,0 +1,77 @@
+use crate::{parse::list, span, Answer, Result, Solver};
+
+pub fn get_solution_1(input: &str) -> Result<u32> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<u32> {
+    Ok(solve_2(&parse(input)?))
+}
+
+/// Parses the comma separated horizontal positions of the crabs
+pub fn parse(input: &str) -> Result<Vec<u32>> {
+    let _span = span!(Debug, "parse");
+    list(input, input, ',')
+}
+
+/// Least fuel to align the crabs, if every step costs 1
+pub fn solve_1(positions: &[u32]) -> u32 {
+    brute_force_fuel(positions)
+}
+
+/// Least fuel to align the crabs, if every step costs one more than the one before
+pub fn solve_2(positions: &[u32]) -> u32 {
+    brute_force_fuel_2(positions)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+fn brute_force_fuel(input: &[u32]) -> u32 {
+    let max = *input.iter().max().unwrap();
+    let mut min_fuel = u32::MAX;
+    for pos in 0..max {
+        let mut cur_fuel = 0;
+        for n in input {
+            cur_fuel += (pos).abs_diff(*n)
+        }
+        if cur_fuel < min_fuel {
+            min_fuel = cur_fuel;
+        }
+    }
+
+    min_fuel
+}
+
+fn brute_force_fuel_2(input: &[u32]) -> u32 {
+    let max = *input.iter().max().unwrap();
+    let mut min_fuel = u32::MAX;
+    for pos in 0..max {
+        let mut cur_fuel = 0;
+        for n in input {
+            let steps = (pos).abs_diff(*n);
+            cur_fuel += steps * (steps + 1) / 2;
+        }
+        if cur_fuel < min_fuel {
+            min_fuel = cur_fuel;
+        }
+    }
+
+    min_fuel
+}
+
+#[test]
+fn test_sum() {
+    let input = include_str!("../../data/2021/day_7_test.txt");
+    let result = brute_force_fuel(&list(input, input, ',').unwrap());
+    assert_eq!(result, 37);
+}
\ No newline at end of file
@@ -1,8 +1,8 @@
 use std::{collections::{HashSet, HashMap}, ops::Sub};
 
-use crate::parse_lines;
+use crate::{common::lines, span, Answer, Error, Result, Solver};
 
-#[derive(Debug, Eq, Hash)]
+#[derive(Debug, Eq)]
 struct Digit {
     chars: String,
     len: usize,
@@ -97,38 +97,71 @@ impl From<String> for Digit {
 
 impl From<&str> for Digit {
     fn from(chars: &str) -> Self {
-        let number = match chars.len() {
-            2 => Some(1),
-            3 => Some(7),
-            4 => Some(4),
-            7 => Some(8),
-            _ => None,
-        };
+        Digit::from(chars.to_string())
+    }
+}
 
-        let len = chars.len();
+/// A note of the display: the ten unique signal patterns and the four output digits
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entry {
+    patterns: Vec<String>,
+    output: Vec<String>,
+}
 
-        Digit { chars: chars.to_string(), len, number }
+impl Entry {
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
     }
-}
 
-pub fn get_solution_1() -> usize {
-    let lines = parse_lines("data/day_8.txt");
-    let digits: Vec<Vec<String>> = lines.into_iter().map(| l | {
-        let split_line: Vec<String> = l.split('|').map(| s | s.to_string()).collect();
-        split_line[1].split_whitespace().map(| s | s.to_string()).filter(| n | {
+    pub fn output(&self) -> &[String] {
+        &self.output
+    }
+
+    /// Counts the output digits which are a 1, 4, 7 or 8, they have a unique number of segments
+    pub fn count_unique(&self) -> usize {
+        self.output.iter().filter(| n | {
             let length = n.len();
             length == 2 || length == 3 || length == 4 || length == 7
-        }).collect()
-    }).collect();
+        }).count()
+    }
 
-    let mut count = 0;
-    for line in digits {
-        count += line.len();
+    /// Determines the wiring from the patterns and returns the output value.
+    /// `line_index` is the index of the entry in the input, the error of a wiring which can't be
+    /// determined points to its line
+    pub fn decode(&self, line_index: usize) -> Result<u32> {
+        let digits = self.patterns.iter().map(|pattern| Digit::from(pattern.as_str())).collect();
+        let number = self.output.iter().map(|digit| Digit::from(digit.as_str())).collect();
+        determine_numbers(digits)
+            .and_then(|map| calculate_result(map, number))
+            .ok_or_else(|| {
+                let line = format!("{} | {}", self.patterns.join(" "), self.output.join(" "));
+                Error::in_line(line_index, &line, &line, "the wiring of the patterns can't be determined")
+            })
     }
-    count
 }
 
-pub fn get_solution_2() -> u32 {
+/// Parses one entry per line like "acedgfb cdfbe ... | cdfeb fcadb cdfeb cdbaf"
+pub fn parse(input: &str) -> Result<Vec<Entry>> {
+    let _span = span!(Debug, "parse");
+    lines(input)
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let (patterns, output) = parse_entry(i, line)?;
+            Ok(Entry {
+                patterns: patterns.split_whitespace().map(String::from).collect(),
+                output: output.split_whitespace().map(String::from).collect(),
+            })
+        })
+        .collect()
+}
+
+pub fn solve_1(entries: &[Entry]) -> usize {
+    entries.iter().map(Entry::count_unique).sum()
+}
+
+pub fn solve_2(entries: &[Entry]) -> Result<u32> {
     // idea:
     // for each digit, create a set out of its letters
     // now determine numbers in the following order n (number of letters):
@@ -145,92 +178,118 @@ pub fn get_solution_2() -> u32 {
     // add numbers to set, by looping through the vector and testing if the above condition holds
     // for a given set size
 
-    let lines = parse_lines("data/day_8.txt");
-    let entries: Vec<Vec<String>> = lines.into_iter().map(|l| l.split('|').map(|n| n.to_string()).collect() ).collect();
-    let mut sum = 0;
-    for entry in entries {
-        let (digits, number) = parse_to_digits(entry);
-        let map = determine_numbers(digits);
-        let result = calculate_result(map, number);
-        sum += result;
-    }
-    sum
+    entries.iter().enumerate().map(|(i, entry)| entry.decode(i)).sum()
+}
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<u32> {
+    solve_2(&parse(input)?)
 }
 
-fn parse_to_digits(entries: Vec<String>) -> (Vec<Digit>, Vec<Digit>) {
-    let mut digits = vec![];
-    let mut number = vec![];
-    for n in entries[0].split_whitespace(){
-        digits.push(Digit::from(n));
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
     }
 
-    for n in entries[1].split_whitespace() {
-        number.push(Digit::from(n));
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
     }
+}
 
-    (digits, number)
+/// Splits an entry into the ten patterns and the four digits of the output
+fn parse_entry(i: usize, line: &str) -> Result<(&str, &str)> {
+    let (patterns, output) = line.split_once('|')
+        .ok_or_else(|| Error::in_line(i, line, line, "expected patterns and output separated by '|'"))?;
+    if let Some((pos, c)) = line.char_indices().find(|(_, c)| !matches!(c, 'a'..='g' | '|' | ' ')) {
+        return Err(Error::in_line(i, line, &line[pos..pos + c.len_utf8()], "expected a segment from a to g"));
+    }
+    if patterns.split_whitespace().count() != 10 {
+        return Err(Error::in_line(i, line, patterns, "expected ten patterns"));
+    }
+    if output.split_whitespace().count() != 4 {
+        return Err(Error::in_line(i, line, output, "expected four output digits"));
+    }
+
+    Ok((patterns, output))
 }
 
-fn determine_numbers(digits: Vec<Digit>) -> HashMap<i8, Digit> {
+/// Returns None if the patterns aren't the ten digits of a wiring
+fn determine_numbers(digits: Vec<Digit>) -> Option<HashMap<i8, Digit>> {
     let mut map: HashMap<i8, Digit> = HashMap::new();
     let mut undefined = vec![];
     for digit in digits {
         match digit.number {
-            Some(n) => { map.insert(n as i8, digit); },
+            // two patterns with the segment count of a 1, 4, 7 or 8
+            Some(n) => if map.insert(n as i8, digit).is_some() { return None },
             None => { undefined.push(digit); },
         }
     }
 
     // determine 3
-    determine_digit(&mut undefined, &mut map, 1, 3, 3, 5);
+    determine_digit(&mut undefined, &mut map, 1, 3, 3, 5)?;
     
     // determine 9
-    determine_digit(&mut undefined, &mut map, 3, 1, 9, 6);
+    determine_digit(&mut undefined, &mut map, 3, 1, 9, 6)?;
     
     // determine 6
-    determine_digit(&mut undefined, &mut map, 1, 6, 6, 6);
+    determine_digit(&mut undefined, &mut map, 1, 6, 6, 6)?;
     
     // determine 0
-    determine_digit(&mut undefined, &mut map, 3, 3, 0, 6);
+    determine_digit(&mut undefined, &mut map, 3, 3, 0, 6)?;
     
     // determine 5
-    determine_digit(&mut undefined, &mut map, 6, 1, 5, 5);
+    determine_digit(&mut undefined, &mut map, 6, 1, 5, 5)?;
     
     // insert last digit(2)
-    assert_eq!(undefined.len(), 1);
+    if undefined.len() != 1 || undefined[0].len != 5 {
+        return None;
+    }
     let mut digit = undefined.remove(0);
     digit.number = Some(2);
     map.insert(2, digit);
     
-    map
+    Some(map)
 }
 
-fn calculate_result(map: HashMap<i8, Digit>, number: Vec<Digit>) -> u32 {
+/// Returns None if a digit of the output isn't exactly one of the patterns
+fn calculate_result(map: HashMap<i8, Digit>, number: Vec<Digit>) -> Option<u32> {
     let mut result = 0;
     for (i, digit) in number.iter().rev().enumerate() {
-        for (n, d) in &map {
-            if digit == d {
-                result += *n as u32 * 10_u32.pow(i as u32);
-            }
+        let mut matches = map.iter().filter(|(_, d)| digit == *d);
+        match (matches.next(), matches.next()) {
+            (Some((n, _)), None) => result += *n as u32 * 10_u32.pow(i as u32),
+            _ => return None,
         }
     }
 
-    result as u32
+    Some(result)
 }
 
-fn determine_digit(undefined: &mut Vec<Digit>, map: &mut HashMap<i8, Digit>, comparator: i8, compare_result: u64, number: u8, char_length: usize) {
-    let mut index = 0;
-    for (i, digit) in undefined.iter().enumerate() {
-        let comp = map.get(&comparator).unwrap();
-        if  char_length == digit.len && (digit - comp) == compare_result {
-
-            index = i;
-            break;
-        }
-    }
+/// Moves the pattern with `char_length` segments, which differs from the digit `comparator` in
+/// `compare_result` segments, to `map`. Returns None if there is no such pattern
+fn determine_digit(undefined: &mut Vec<Digit>, map: &mut HashMap<i8, Digit>, comparator: i8, compare_result: u64, number: u8, char_length: usize) -> Option<()> {
+    let comp = map.get(&comparator)?;
+    let index = undefined.iter().position(|digit| char_length == digit.len && (digit - comp) == compare_result)?;
     let mut digit = undefined.remove(index);
     digit.number = Some(number);
     map.insert(number as i8, digit);
+    Some(())
+}
+
+#[test]
+fn test_unknown_wiring() {
+    let input = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+cfbeag cbgd ecdfgab bacgde gecda acfed eagb ecg gafedb cg | afgecb gec cefda gdbc
+ab cd abcdefg abc abcd abcde abcdf abcef abcdeg abcdfg | ab cd abc abcd";
+    let error = get_solution_2(input).unwrap_err().to_string();
+    assert_eq!(error, "line 2, column 1: the wiring of the patterns can't be determined");
+    assert!(get_solution_2(input.lines().next().unwrap()).is_ok());
+    assert!(get_solution_2(input.lines().last().unwrap()).is_err());
 }
 
 #[test]
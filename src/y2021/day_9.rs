@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+
+use crate::{grid::Grid, span, Answer, Error, Result, Solver};
+
+/// The heights of the locations
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeightMap<T: PartialOrd> {
+    heights: Grid<T>,
+}
+
+impl<T: PartialOrd> HeightMap<T> {
+    pub fn heights(&self) -> &Grid<T> {
+        &self.heights
+    }
+
+    pub fn width(&self) -> usize {
+        self.heights.width()
+    }
+
+    pub fn len(&self) -> usize {
+        self.heights.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heights.is_empty()
+    }
+
+    /// Returns the positions of the low points, which are lower than all their neighbours
+    pub fn determine_lows(&self) -> Vec<(usize, usize)> {
+        let heights = &self.heights;
+        heights.positions()
+            .filter(|&(x, y)| heights.neighbours4(x, y).all(|n| heights[n] > heights[(x, y)]))
+            .collect()
+    }
+
+    fn get_unexplored_neighbours(&self, (x, y): (usize, usize), explored: &HashSet<(usize, usize)>) -> HashSet<(usize, usize)> {
+        self.heights.neighbours4(x, y).filter(|n| !explored.contains(n)).collect()
+    }
+}
+
+impl HeightMap<u8> {
+    fn calculate_result(&self, lows: Vec<(usize, usize)>) -> usize {
+        let mut sum = 0;
+        for low in lows {
+            sum += self.heights[low] as usize + 1;
+        }
+        sum
+    }
+
+    /// Sum of the risk levels of the low points
+    pub fn risk_level(&self) -> usize {
+        self.calculate_result(self.determine_lows())
+    }
+
+    /// Size of the basin around a low point, locations of height 9 aren't part of any basin
+    // builds a search frontier and adds neighbours to it until all neighbours have been explored
+    pub fn determine_basin_size(&self, low: (usize, usize)) -> usize {
+        let mut explored = HashSet::new();
+        let mut frontier = vec![low];
+        while let Some(next) = frontier.pop() {
+            explored.insert(next);
+            let neighbours = self.get_unexplored_neighbours(next, &explored);
+            for n in neighbours {
+                if self.heights[n] != 9 {
+                    frontier.push(n);
+                }
+            }
+        }
+
+        explored.len()
+    }
+}
+
+pub fn get_solution_1(input: &str) -> Result<usize> {
+    Ok(solve_1(&parse(input)?))
+}
+
+pub fn get_solution_2(input: &str) -> Result<usize> {
+    solve_2(&parse(input)?)
+}
+
+pub fn solve_1(map: &HeightMap<u8>) -> usize {
+    map.risk_level()
+}
+
+/// Product of the sizes of the three largest basins
+pub fn solve_2(map: &HeightMap<u8>) -> Result<usize> {
+    // build a search frontier:
+    // two sets, one with unexplored neighbours, one with explored
+    // write a get_neighbors function, which returns indices of all neighbors of a given node
+    // a valid neighbor is, if it is not in explored, and it is not 9
+    let lows = map.determine_lows();
+    let mut basins = vec![];
+    for low in lows {
+        basins.push(map.determine_basin_size(low));
+    } 
+    basins.sort_by(|a, b| b.cmp(a));
+    if basins.len() < 3 {
+        return Err(Error::invalid("expected at least three basins"));
+    }
+    Ok(basins[0] * basins[1] * basins[2])
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn part_1(&self, input: &str) -> Result<Answer> {
+        get_solution_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer> {
+        get_solution_2(input).map(Answer::from)
+    }
+}
+
+/// Parses the heights, one digit per location
+pub fn parse(input: &str) -> Result<HeightMap<u8>> {
+    let _span = span!(Debug, "parse");
+    parse_numbers(input)
+}
+
+fn parse_numbers(input: &str) -> Result<HeightMap<u8>> {
+    Ok(HeightMap { heights: Grid::parse(input, input)? })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashSet;
+
+    use crate::grid::Grid;
+
+    use super::{HeightMap, parse_numbers, get_solution_1, get_solution_2};
+
+    fn create_map(numbers: Vec<u8>) -> HeightMap<u8> {
+        HeightMap { heights: Grid::new(10, numbers).unwrap() }
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        let numbers = parse_numbers(include_str!("../../data/2021/day_9_test.txt")).unwrap();
+        assert_eq!(numbers, create_map(
+            vec![
+                2,1,9,9,9,4,3,2,1,0,
+                3,9,8,7,8,9,4,9,2,1,
+                9,8,5,6,7,8,9,8,9,2,
+                8,7,6,7,8,9,6,7,8,9,
+                9,8,9,9,9,6,5,6,7,8
+            ])
+        );
+    }
+
+    #[test]
+    fn test_determine_lows() {
+        let map = create_map(
+            vec![
+                2,1,9,9,9,4,3,2,1,0,
+                3,9,8,7,8,9,4,9,2,1,
+                9,8,5,6,7,8,9,8,9,2,
+                8,7,6,7,8,9,6,7,8,7,
+                9,8,9,9,9,6,5,6,7,8
+            ]);
+        // lows on the edges have fewer neighbours
+        assert_eq!(map.determine_lows(), vec![(1, 0), (9, 0), (2, 2), (6, 4)]);
+    }
+
+    #[test]
+    fn test_get_solution_1() {
+        let result = get_solution_1(include_str!("../../data/2021/day_9_test.txt"));
+        assert_eq!(result.unwrap(), 15);
+    }
+
+    #[test]
+    fn test_get_unexplored_neighbours() {
+        let map = create_map(
+            vec![
+                2,1,9,9,9,4,3,2,1,0,
+                3,9,7,7,8,9,4,9,2,1,
+                9,8,5,6,7,8,9,8,9,2,
+                8,7,6,7,8,9,6,7,8,7,
+                9,8,9,9,9,6,5,6,7,8
+            ]);
+        // test top
+        let explored = HashSet::from([(1, 0)]);
+        let diff = map.get_unexplored_neighbours((2, 0), &explored);
+        assert_eq!(diff, HashSet::from([(3, 0), (2, 1)]));
+
+        // test center
+        let explored = HashSet::from([(0, 0), (1, 0), (2, 0), (1, 2)]);
+        let diff = map.get_unexplored_neighbours((1, 1), &explored);
+        assert_eq!(diff, HashSet::from([(0, 1), (2, 1)]));
+    }
+
+    #[test]
+    fn test_determine_basin_size() {
+        let map = create_map(
+            vec![
+                2,1,9,9,9,4,3,2,1,0,
+                3,9,8,7,8,9,4,9,2,1,
+                9,8,5,6,7,8,9,8,9,2,
+                8,7,6,7,8,9,6,7,8,9,
+                9,8,9,9,9,6,5,6,7,8
+            ]);
+        
+        let size = map.determine_basin_size((1, 0));
+        assert_eq!(size, 3);
+        
+        let size = map.determine_basin_size((9, 0));
+        assert_eq!(size, 9);
+
+        let size = map.determine_basin_size((2, 2));
+        assert_eq!(size, 14);
+        
+        let size = map.determine_basin_size((6, 4));
+        assert_eq!(size, 9);
+    }
+
+    #[test]
+    fn test_get_solution_2() {
+        let solution = get_solution_2(include_str!("../../data/2021/day_9_test.txt"));
+        assert_eq!(solution.unwrap(), 1134);
+    }
+}
@@ -0,0 +1,76 @@
+//! Checks for the parsers, used by the fuzz targets in `fuzz/` and the property tests in `tests/properties.rs`.
+//!
+//! A parser has to return an error for any input it can't handle, it must never panic.
+//! Models which are printed in the format of the input have to parse to the same model again.
+
+use crate::Result;
+
+use super::*;
+
+/// Runs the parser of a day, None for days which don't exist
+pub fn parse(day: usize, input: &str) -> Option<Result<()>> {
+    let parsed = match day {
+        1 => day_1::parse(input).map(drop),
+        2 => day_2::parse(input).map(drop),
+        3 => day_3::parse(input).map(drop),
+        4 => day_4::parse(input).map(drop),
+        5 => day_5::parse(input).map(drop),
+        6 => day_6::parse(input).map(drop),
+        7 => day_7::parse(input).map(drop),
+        8 => day_8::parse(input).map(drop),
+        9 => day_9::parse(input).map(drop),
+        10 => day_10::parse(input).map(drop),
+        11 => day_11::parse(input).map(drop),
+        12 => day_12::parse(input).map(drop),
+        13 => day_13::parse(input).map(drop),
+        14 => day_14::parse(input).map(drop),
+        15 => day_15::parse(input).map(drop),
+        16 => day_16::parse(input).map(drop),
+        17 => day_17::parse(input).map(drop),
+        18 => day_18::parse(input).map(drop),
+        19 => day_19::parse(input).map(drop),
+        20 => day_20::parse(input).map(drop),
+        21 => day_21::parse(input).map(drop),
+        22 => day_22::parse(input).map(drop),
+        23 => day_23::parse(input).map(drop),
+        24 => day_24::parse(input).map(drop),
+        25 => day_25::parse(input).map(drop),
+        _ => return None,
+    };
+    Some(parsed)
+}
+
+/// Parses `data` with the parser of `day` and prints the model again if it has the format of the input.
+/// Panics if the parser panics or the printed model doesn't parse to the same model
+pub fn check(day: usize, data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    if parse(day, input).is_none_or(|parsed| parsed.is_err()) {
+        return;
+    }
+    match day {
+        18 => {
+            for number in day_18::parse(input).unwrap() {
+                let printed = number.to_string();
+                assert_eq!(printed.parse::<day_18::SnailNumber>().ok(), Some(number), "printed as {}", printed);
+            }
+        },
+        20 => {
+            let image = day_20::parse(input).unwrap();
+            let printed = format!("{}\n\n{}", image.algorithm(), image.pixels());
+            assert_eq!(day_20::parse(&printed).ok(), Some(image), "printed as\n{}", printed);
+        },
+        23 => {
+            let diagram = day_23::parse(input).unwrap();
+            let printed = diagram.to_string();
+            assert_eq!(day_23::parse(&printed).ok(), Some(diagram), "printed as\n{}", printed);
+        },
+        25 => {
+            let floor = day_25::parse(input).unwrap();
+            let printed = floor.to_string();
+            assert_eq!(day_25::parse(&printed).ok(), Some(floor), "printed as\n{}", printed);
+        },
+        _ => (),
+    }
+}
@@ -0,0 +1,590 @@
+//! Random puzzle inputs for stress and differential tests.
+//!
+//! Every generated input is valid, i.e. it parses and has an answer, but it's otherwise as
+//! random as the puzzle allows. What `size` and `density` mean depends on the day, see [`input`].
+
+use std::{collections::{BTreeSet, HashSet}, fmt::Write};
+
+use crate::random::Rng;
+
+/// Parameters of a generated input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Params {
+    /// The same seed always gives the same input
+    pub seed: u64,
+    /// Number of lines or width of a grid
+    pub size: usize,
+    /// Share of filled cells or of special lines, between 0 and 1
+    pub density: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { seed: 0, size: 10, density: 0.5 }
+    }
+}
+
+impl Params {
+    pub fn new(seed: u64, size: usize, density: f64) -> Self {
+        Params { seed, size, density }
+    }
+}
+
+/// Generates an input for a day, None for days which don't exist.
+///
+/// | day | size | density |
+/// |-----|------|---------|
+/// | 1, 6, 7 | numbers | spread of the numbers |
+/// | 2 | commands | share of forward commands |
+/// | 3 | numbers | share of 1 bits |
+/// | 4 | boards | share of the numbers 0 to 99 which are drawn |
+/// | 5 | lines | share of diagonal lines |
+/// | 8 | entries | - |
+/// | 9, 15, 25 | width and height | share of 9s / - / share of sea cucumbers |
+/// | 10 | lines | share of corrupted lines |
+/// | 11, 17, 21 | - | - |
+/// | 12 | small caves (at most 6) | share of connected caves |
+/// | 13 | width of the code | share of dots in the code |
+/// | 14 | length of the template | - |
+/// | 16 | packets | share of operators |
+/// | 18 | snail numbers | share of pairs |
+/// | 19 | scanners (at least 2) | - |
+/// | 20 | width and height of the image | share of lit pixels |
+/// | 22 | reboot steps | share of steps outside of the initialization area |
+/// | 23 | - | share of diagrams whose rooms are already 4 rows deep |
+/// | 24 | - | - |
+///
+/// Sparse sea cucumbers (day 25) usually never stop moving, like in the puzzle the density should be above 0.5.
+/// Part 2 of a day 23 diagram with 4 rows unfolds to 6 rows, which the amphipods can rarely organize.
+pub fn input(day: usize, params: &Params) -> Option<String> {
+    let generators: [fn(&mut Rng, usize, f64) -> String; 25] = [
+        day_1, day_2, day_3, day_4, day_5, day_6, day_7, day_8, day_9, day_10, day_11, day_12, day_13,
+        day_14, day_15, day_16, day_17, day_18, day_19, day_20, day_21, day_22, day_23, day_24, day_25,
+    ];
+    let generator = generators.get(day.checked_sub(1)?)?;
+    let mut rng = Rng::new(params.seed);
+    Some(generator(&mut rng, params.size.max(1), params.density.clamp(0.0, 1.0)))
+}
+
+/// Joins the lines with a newline after each
+fn join<T: ToString>(lines: impl IntoIterator<Item = T>) -> String {
+    lines.into_iter().fold(String::new(), |mut s, line| {
+        let _ = writeln!(s, "{}", line.to_string());
+        s
+    })
+}
+
+fn spread(density: f64) -> i64 {
+    1 + (density * 1000.0) as i64
+}
+
+fn day_1(rng: &mut Rng, size: usize, density: f64) -> String {
+    let mut depth = rng.range(100..200);
+    join((0..size).map(|_| {
+        depth = (depth + rng.range(-spread(density) / 4..spread(density) / 2 + 1)).max(0);
+        depth
+    }))
+}
+
+fn day_2(rng: &mut Rng, size: usize, density: f64) -> String {
+    let mut aim = 0;
+    join((0..size).map(|_| {
+        let n = rng.range(1..10);
+        if rng.chance(density) {
+            format!("forward {}", n)
+        } else if rng.chance(0.5) && aim >= n {
+            // the submarine never rises above the surface
+            aim -= n;
+            format!("up {}", n)
+        } else {
+            aim += n;
+            format!("down {}", n)
+        }
+    }))
+}
+
+fn day_3(rng: &mut Rng, size: usize, density: f64) -> String {
+    let width = 12.min(size.max(2));
+    // the ratings filter until one number is left, so the numbers are distinct
+    let count = size.min(1 << width);
+    let mut numbers = BTreeSet::new();
+    while numbers.len() < count {
+        let number: String = (0..width).map(|_| if rng.chance(density) { '1' } else { '0' }).collect();
+        if !numbers.insert(number) {
+            // a density of 0 or 1 would never give another number
+            numbers.insert((0..width).map(|_| if rng.chance(0.5) { '1' } else { '0' }).collect());
+        }
+    }
+    let mut numbers: Vec<String> = numbers.into_iter().collect();
+    rng.shuffle(&mut numbers);
+    join(numbers)
+}
+
+fn day_4(rng: &mut Rng, size: usize, density: f64) -> String {
+    let mut numbers: Vec<u32> = (0..100).collect();
+    rng.shuffle(&mut numbers);
+    // every board has to win at some point, so all its numbers are drawn
+    let drawn = ((100.0 * density) as usize).clamp(25, 100);
+    let mut s = numbers[..drawn].iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+    s += "\n";
+    for _ in 0..size {
+        let mut pool = numbers[..drawn].to_vec();
+        rng.shuffle(&mut pool);
+        s += "\n";
+        for row in pool[..25].chunks(5) {
+            s += &row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" ");
+            s += "\n";
+        }
+    }
+    s
+}
+
+fn day_5(rng: &mut Rng, size: usize, density: f64) -> String {
+    let dim = 10.max(size as i64);
+    join((0..size).map(|_| {
+        let start = (rng.range(0..dim), rng.range(0..dim));
+        let len = rng.range(0..dim);
+        let (dx, dy) = if rng.chance(density) {
+            *rng.choose(&[(1, 1), (1, -1), (-1, 1), (-1, -1)]).unwrap()
+        } else {
+            *rng.choose(&[(1, 0), (-1, 0), (0, 1), (0, -1)]).unwrap()
+        };
+        // shorten the line until it stays on the map
+        let len = (0..=len).rev().find(|len| (0..dim).contains(&(start.0 + dx * len)) && (0..dim).contains(&(start.1 + dy * len))).unwrap_or(0);
+        format!("{},{} -> {},{}", start.0, start.1, start.0 + dx * len, start.1 + dy * len)
+    }))
+}
+
+fn day_6(rng: &mut Rng, size: usize, density: f64) -> String {
+    let max = 1 + (density * 7.0) as i64;
+    (0..size).map(|_| rng.range(0..max.min(7)).to_string()).collect::<Vec<_>>().join(",") + "\n"
+}
+
+fn day_7(rng: &mut Rng, size: usize, density: f64) -> String {
+    (0..size).map(|_| rng.range(0..spread(density)).to_string()).collect::<Vec<_>>().join(",") + "\n"
+}
+
+/// Segments of the digits 0 to 9 in the correct wiring
+const DIGITS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+fn day_8(rng: &mut Rng, size: usize, _density: f64) -> String {
+    join((0..size).map(|_| {
+        let mut wiring: Vec<char> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wiring);
+        let scramble = |rng: &mut Rng, digit: &str| {
+            let mut segments: Vec<char> = digit.bytes().map(|b| wiring[(b - b'a') as usize]).collect();
+            rng.shuffle(&mut segments);
+            segments.into_iter().collect::<String>()
+        };
+        let mut patterns: Vec<String> = DIGITS.iter().map(|digit| scramble(rng, digit)).collect();
+        let output: Vec<String> = (0..4).map(|_| {
+            let digit = DIGITS[rng.below(10)];
+            scramble(rng, digit)
+        }).collect();
+        rng.shuffle(&mut patterns);
+        format!("{} | {}", patterns.join(" "), output.join(" "))
+    }))
+}
+
+fn digit_grid(rng: &mut Rng, width: usize, height: usize, digit: impl Fn(&mut Rng) -> u8) -> String {
+    join((0..height).map(|_| (0..width).map(|_| (b'0' + digit(rng)) as char).collect::<String>()))
+}
+
+fn day_9(rng: &mut Rng, size: usize, density: f64) -> String {
+    // every basin (cells between the 9s) flows down to a single low point, like the puzzle says,
+    // so its heights are the distances to a random cell of it
+    let mut heights: Vec<Vec<u8>> = (0..size).map(|_| (0..size).map(|_| if rng.chance(density) { 9 } else { u8::MAX }).collect()).collect();
+    let mut cells: Vec<(usize, usize)> = (0..size).flat_map(|r| (0..size).map(move |c| (r, c))).collect();
+    rng.shuffle(&mut cells);
+    for (r, c) in cells {
+        if heights[r][c] != u8::MAX {
+            continue;
+        }
+        heights[r][c] = 0;
+        let mut queue = std::collections::VecDeque::from([(r, c)]);
+        while let Some((r, c)) = queue.pop_front() {
+            let next = (heights[r][c] + 1).min(8);
+            for (nr, nc) in [(r.wrapping_sub(1), c), (r + 1, c), (r, c.wrapping_sub(1)), (r, c + 1)] {
+                if nr < size && nc < size && heights[nr][nc] == u8::MAX {
+                    heights[nr][nc] = next;
+                    queue.push_back((nr, nc));
+                }
+            }
+        }
+    }
+    join(heights.iter().map(|row| row.iter().map(|h| (b'0' + h) as char).collect::<String>()))
+}
+
+fn day_10(rng: &mut Rng, size: usize, density: f64) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    join((0..size).map(|i| {
+        // the first line is incomplete, so there's always a completion score
+        let corrupted = i > 0 && rng.chance(density);
+        let len = 5 + rng.below(40);
+        let mut line = String::new();
+        let mut stack = vec![];
+        while line.len() < len || stack.is_empty() {
+            if stack.is_empty() || rng.chance(0.55) {
+                let (open, close) = *rng.choose(&PAIRS).unwrap();
+                line.push(open);
+                stack.push(close);
+            } else {
+                line.push(stack.pop().unwrap());
+            }
+        }
+        if corrupted {
+            let expected = stack.pop().unwrap();
+            let wrong: Vec<char> = PAIRS.iter().map(|(_, close)| *close).filter(|close| *close != expected).collect();
+            line.push(*rng.choose(&wrong).unwrap());
+            // what follows the first wrong character doesn't matter
+            for _ in 0..rng.below(5) {
+                line.push(rng.choose(&PAIRS).unwrap().0);
+            }
+        }
+        line
+    }))
+}
+
+fn day_11(rng: &mut Rng, _size: usize, _density: f64) -> String {
+    digit_grid(rng, 10, 10, |rng| rng.below(10) as u8)
+}
+
+fn day_12(rng: &mut Rng, size: usize, density: f64) -> String {
+    let small: Vec<String> = ["ab", "cd", "ef", "gh", "ij", "kl"].iter().take(size.clamp(1, 6)).map(|s| s.to_string()).collect();
+    let big: Vec<String> = ["XY", "ZW"].iter().take(1 + rng.below(2)).map(|s| s.to_string()).collect();
+    let mut caves = vec!["start".to_string(), "end".to_string()];
+    caves.extend(small.iter().cloned());
+    caves.extend(big.iter().cloned());
+
+    let mut edges = BTreeSet::new();
+    for (i, a) in caves.iter().enumerate() {
+        for b in &caves[i + 1..] {
+            // two connected big caves would have infinitely many paths
+            let both_big = big.contains(a) && big.contains(b);
+            if !(both_big || a == "start" && b == "end") && rng.chance(density) {
+                edges.insert((a.clone(), b.clone()));
+            }
+        }
+    }
+    // start and end are connected to something, so there's a chance of a path
+    edges.insert(("start".to_string(), rng.choose(&caves[2..]).unwrap().clone()));
+    edges.insert(("end".to_string(), rng.choose(&caves[2..]).unwrap().clone()));
+
+    let mut lines: Vec<String> = edges.into_iter().map(|(a, b)| if rng.chance(0.5) && a != "start" {
+        format!("{}-{}", b, a)
+    } else {
+        format!("{}-{}", a, b)
+    }).collect();
+    rng.shuffle(&mut lines);
+    join(lines)
+}
+
+fn day_13(rng: &mut Rng, size: usize, density: f64) -> String {
+    let (width, height) = (size, 6);
+    // every fold is exactly in the middle, so unfolding doubles the size plus the fold line
+    let mut folds = vec![];
+    let (mut w, mut h) = (width, height);
+    let (n_x, n_y) = (1 + rng.below(3), 1 + rng.below(3));
+    let mut axes: Vec<bool> = std::iter::repeat_n(true, n_x - 1).chain(std::iter::repeat_n(false, n_y - 1)).collect();
+    rng.shuffle(&mut axes);
+    // the first two folds are along x and y, they determine the size of the paper
+    let first = rng.chance(0.5);
+    axes.push(!first);
+    axes.push(first);
+    for along_x in axes {
+        if along_x {
+            folds.push(('x', w));
+            w = 2 * w + 1;
+        } else {
+            folds.push(('y', h));
+            h = 2 * h + 1;
+        }
+    }
+    folds.reverse();
+
+    let mut dots = HashSet::new();
+    for y in 0..height {
+        for x in 0..width {
+            if !rng.chance(density) {
+                continue;
+            }
+            for _ in 0..1 + rng.below(2) {
+                // a random dot which ends up at x, y
+                let (mut x, mut y) = (x, y);
+                for &(axis, line) in folds.iter().rev() {
+                    if rng.chance(0.5) {
+                        match axis {
+                            'x' => x = 2 * line - x,
+                            _ => y = 2 * line - y,
+                        }
+                    }
+                }
+                dots.insert((x, y));
+            }
+        }
+    }
+    let mut dots: Vec<(usize, usize)> = dots.into_iter().collect();
+    dots.sort();
+    rng.shuffle(&mut dots);
+
+    let mut s = join(dots.iter().map(|(x, y)| format!("{},{}", x, y)));
+    s += "\n";
+    s + &join(folds.iter().map(|(axis, line)| format!("fold along {}={}", axis, line)))
+}
+
+fn day_14(rng: &mut Rng, size: usize, _density: f64) -> String {
+    let mut elements: Vec<char> = "BCFHKNOPSV".chars().collect();
+    rng.shuffle(&mut elements);
+    elements.truncate(2 + rng.below(9));
+    let template: String = (0..size.max(2)).map(|_| *rng.choose(&elements).unwrap()).collect();
+    let mut rules = vec![];
+    for a in &elements {
+        for b in &elements {
+            rules.push(format!("{}{} -> {}", a, b, rng.choose(&elements).unwrap()));
+        }
+    }
+    rng.shuffle(&mut rules);
+    format!("{}\n\n{}", template, join(rules))
+}
+
+fn day_15(rng: &mut Rng, size: usize, _density: f64) -> String {
+    digit_grid(rng, size, size, |rng| 1 + rng.below(9) as u8)
+}
+
+fn day_16(rng: &mut Rng, size: usize, density: f64) -> String {
+    fn push(bits: &mut String, n: u64, len: usize) {
+        let _ = write!(bits, "{:0len$b}", n, len = len);
+    }
+
+    /// Appends a packet with `budget` packets at most, returns the number of packets
+    fn packet(rng: &mut Rng, bits: &mut String, budget: usize, depth: usize, density: f64) -> usize {
+        push(bits, rng.below(8) as u64, 3);
+        if budget < 3 || depth > 4 || !rng.chance(density) {
+            push(bits, 4, 3);
+            // small values, so products of many of them don't overflow
+            let value = rng.below(16) as u64;
+            bits.push('0');
+            push(bits, value, 4);
+            return 1;
+        }
+        let typ = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]).unwrap();
+        push(bits, typ, 3);
+        let n_sub = match typ {
+            5..=7 => 2,
+            1 => 1 + rng.below(3),
+            _ => 1 + rng.below(4),
+        };
+        let mut sub = String::new();
+        let mut used = 1;
+        for i in 0..n_sub {
+            used += packet(rng, &mut sub, budget.saturating_sub(used) / (n_sub - i), depth + 1, density);
+        }
+        if rng.chance(0.5) {
+            bits.push('0');
+            push(bits, sub.len() as u64, 15);
+        } else {
+            bits.push('1');
+            push(bits, n_sub as u64, 11);
+        }
+        bits.push_str(&sub);
+        used
+    }
+
+    let mut bits = String::new();
+    packet(rng, &mut bits, size, 0, density);
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+    let hex: String = bits.as_bytes().chunks(4).map(|nibble| {
+        let n = nibble.iter().fold(0, |acc, bit| acc * 2 + (bit - b'0') as u32);
+        char::from_digit(n, 16).unwrap().to_ascii_uppercase()
+    }).collect();
+    hex + "\n"
+}
+
+fn day_17(rng: &mut Rng, _size: usize, _density: f64) -> String {
+    // like the puzzle's inputs, some x velocity comes to a stop above the target,
+    // before the highest shot comes down after 2 * |y_start| steps
+    let y_start = rng.range(-150..-2);
+    let vel_x = rng.range(3..(2 * -y_start).min(20));
+    let width = rng.range(0..30);
+    let x_start = (vel_x * (vel_x + 1) / 2 - rng.range(0..width + 1)).max(1);
+    format!("target area: x={}..{}, y={}..{}\n", x_start, x_start + width, y_start, (y_start + rng.range(0..20)).min(-1))
+}
+
+fn day_18(rng: &mut Rng, size: usize, density: f64) -> String {
+    /// A reduced snail number, nested at most 4 pairs deep and with single digits
+    fn number(rng: &mut Rng, depth: usize, density: f64) -> String {
+        if depth == 4 || (depth > 0 && !rng.chance(density)) {
+            rng.below(10).to_string()
+        } else {
+            format!("[{},{}]", number(rng, depth + 1, density), number(rng, depth + 1, density))
+        }
+    }
+    join((0..size.max(2)).map(|_| number(rng, 0, density)))
+}
+
+/// The 24 orientations of a scanner
+fn rotations() -> Vec<[[i64; 3]; 3]> {
+    let mut rotations = vec![];
+    for perm in [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]] {
+        for signs in 0..8 {
+            let mut m = [[0; 3]; 3];
+            for (row, &col) in perm.iter().enumerate() {
+                m[row][col] = if signs & (1 << row) == 0 { 1 } else { -1 };
+            }
+            let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+            if det == 1 {
+                rotations.push(m);
+            }
+        }
+    }
+    rotations
+}
+
+fn day_19(rng: &mut Rng, size: usize, _density: f64) -> String {
+    let n_scanners = size.clamp(2, 6);
+    let mut scanners = vec![[0i64; 3]];
+    let mut beacons = HashSet::new();
+    let random_in = |rng: &mut Rng, low: [i64; 3], high: [i64; 3]| [rng.range(low[0]..high[0] + 1), rng.range(low[1]..high[1] + 1), rng.range(low[2]..high[2] + 1)];
+    for i in 1..n_scanners {
+        // every scanner overlaps with the one before, they share at least 12 beacons
+        let prev = scanners[rng.below(i)];
+        let next = [prev[0] + rng.range(-1200..1201), prev[1] + rng.range(-1200..1201), prev[2] + rng.range(-1200..1201)];
+        let low = [0, 1, 2].map(|a| prev[a].max(next[a]) - 1000);
+        let high = [0, 1, 2].map(|a| prev[a].min(next[a]) + 1000);
+        for _ in 0..12 + rng.below(4) {
+            beacons.insert(random_in(rng, low, high));
+        }
+        scanners.push(next);
+    }
+    for scanner in &scanners {
+        for _ in 0..rng.below(10) {
+            beacons.insert(random_in(rng, scanner.map(|c| c - 1000), scanner.map(|c| c + 1000)));
+        }
+    }
+
+    let rotations = rotations();
+    let mut s = String::new();
+    for (i, scanner) in scanners.iter().enumerate() {
+        let rotation = if i == 0 { rotations[0] } else { *rng.choose(&rotations).unwrap() };
+        let mut seen: Vec<[i64; 3]> = beacons.iter()
+            .map(|b| [b[0] - scanner[0], b[1] - scanner[1], b[2] - scanner[2]])
+            .filter(|b| b.iter().all(|c| c.abs() <= 1000))
+            .map(|b| rotation.map(|row| row[0] * b[0] + row[1] * b[1] + row[2] * b[2]))
+            .collect();
+        seen.sort();
+        rng.shuffle(&mut seen);
+        if i > 0 {
+            s += "\n";
+        }
+        let _ = writeln!(s, "--- scanner {} ---", i);
+        s += &join(seen.iter().map(|b| format!("{},{},{}", b[0], b[1], b[2])));
+    }
+    s
+}
+
+fn day_20(rng: &mut Rng, size: usize, density: f64) -> String {
+    let mut algorithm: Vec<char> = (0..512).map(|_| if rng.chance(density) { '#' } else { '.' }).collect();
+    // otherwise infinitely many pixels would be lit after an odd number of steps
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+    let image = (0..size).map(|_| (0..size).map(|_| if rng.chance(density) { '#' } else { '.' }).collect::<String>());
+    format!("{}\n\n{}", algorithm.into_iter().collect::<String>(), join(image))
+}
+
+fn day_21(rng: &mut Rng, _size: usize, _density: f64) -> String {
+    format!("Player 1 starting position: {}\nPlayer 2 starting position: {}\n", 1 + rng.below(10), 1 + rng.below(10))
+}
+
+fn day_22(rng: &mut Rng, size: usize, density: f64) -> String {
+    join((0..size).map(|i| {
+        // the first step turns cubes on, otherwise it does nothing
+        let on = i == 0 || rng.chance(0.6);
+        let (low, high, len) = if rng.chance(density) { (-100_000, 100_000, 50_000) } else { (-50, 50, 30) };
+        let ranges: Vec<String> = ["x", "y", "z"].iter().map(|axis| {
+            let start = rng.range(low..high - len);
+            format!("{}={}..{}", axis, start, start + rng.range(0..len))
+        }).collect();
+        format!("{} {}", if on { "on" } else { "off" }, ranges.join(","))
+    }))
+}
+
+fn day_23(rng: &mut Rng, _size: usize, density: f64) -> String {
+    let depth = if rng.chance(density) { 4 } else { 2 };
+    let mut pods: Vec<char> = "ABCD".repeat(depth).chars().collect();
+    rng.shuffle(&mut pods);
+    let rows: Vec<String> = pods.chunks(4).map(|row| row.iter().map(|c| c.to_string()).collect::<Vec<_>>().join("#")).collect();
+    let mut s = format!("#############\n#...........#\n###{}###\n", rows[0]);
+    for row in &rows[1..] {
+        let _ = writeln!(s, "  #{}#", row);
+    }
+    s + "  #########\n"
+}
+
+fn day_24(rng: &mut Rng, _size: usize, _density: f64) -> String {
+    // seven digits are pushed onto a stack in base 26 and seven are compared with the top of it,
+    // the difference of the compared digits is at most 8, so there's always an accepted number
+    let mut order = vec![];
+    let (mut pushed, mut open) = (0, 0);
+    while order.len() < 14 {
+        if open == 0 || (pushed < 7 && rng.chance(0.5)) {
+            order.push(true);
+            pushed += 1;
+            open += 1;
+        } else {
+            order.push(false);
+            open -= 1;
+        }
+    }
+
+    let mut stack = vec![];
+    let mut s = String::new();
+    for push in order {
+        let (div, n, m) = if push {
+            let m = rng.range(9..17);
+            stack.push(m);
+            (1, rng.range(10..17), m)
+        } else {
+            let delta = rng.range(-8..9);
+            (26, delta - stack.pop().unwrap(), rng.range(0..17))
+        };
+        s += &format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n",
+            div, n, m,
+        );
+    }
+    s
+}
+
+fn day_25(rng: &mut Rng, size: usize, density: f64) -> String {
+    join((0..size).map(|_| (0..size).map(|_| match rng.chance(density) {
+        true if rng.chance(0.5) => '>',
+        true => 'v',
+        false => '.',
+    }).collect::<String>()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::fuzz::parse;
+
+    use super::{input, Params};
+
+    #[test]
+    fn test_inputs_parse() {
+        for day in 1..=25 {
+            for seed in 0..5 {
+                let params = Params::new(seed, 8, 0.4);
+                let input = input(day, &params).unwrap();
+                assert_eq!(input, super::input(day, &params).unwrap(), "day {} isn't deterministic", day);
+                let parsed = parse(day, &input).unwrap();
+                assert!(parsed.is_ok(), "day {} seed {}: {:?}\n{}", day, seed, parsed, input);
+            }
+        }
+        assert!(input(26, &Params::default()).is_none());
+    }
+}
@@ -0,0 +1,65 @@
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+#[cfg(any(test, feature = "testing"))]
+pub mod fuzz;
+#[cfg(any(test, feature = "testing"))]
+pub mod generate;
+#[cfg(any(test, feature = "testing"))]
+pub mod reference;
+
+use crate::Solver;
+
+/// All days of the calendar, day n is at index n - 1
+pub static SOLVERS: [&dyn Solver; 25] = [
+    &day_1::Solution,
+    &day_2::Solution,
+    &day_3::Solution,
+    &day_4::Solution,
+    &day_5::Solution,
+    &day_6::Solution,
+    &day_7::Solution,
+    &day_8::Solution,
+    &day_9::Solution,
+    &day_10::Solution,
+    &day_11::Solution,
+    &day_12::Solution,
+    &day_13::Solution,
+    &day_14::Solution,
+    &day_15::Solution,
+    &day_16::Solution,
+    &day_17::Solution,
+    &day_18::Solution,
+    &day_19::Solution,
+    &day_20::Solution,
+    &day_21::Solution,
+    &day_22::Solution,
+    &day_23::Solution,
+    &day_24::Solution,
+    &day_25::Solution,
+];
+
+include!(concat!(env!("OUT_DIR"), "/versions_2021.rs"));
+
@@ -0,0 +1,1043 @@
+//! Naive solutions to compare the solvers with on generated inputs.
+//!
+//! They do what the puzzle says in the most obvious way (grow the polymer, visit every cube,
+//! try every velocity) and parse the inputs on their own, so they only share bugs with the
+//! solvers by coincidence. They're slow, so keep the generated inputs small.
+//! [`differential`] runs a solver and its reference on many generated inputs.
+
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, fmt::Display};
+
+use crate::Answer;
+
+use super::{generate::{self, Params}, SOLVERS};
+
+/// The result of comparing a solver with its reference on one input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    /// There's no reference for the part, or the input has no answer (like a simulation which never stops)
+    Skipped,
+    Mismatch { expected: String, actual: String },
+}
+
+/// A generated input on which a solver and its reference disagree
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    pub day: usize,
+    pub part: u8,
+    pub params: Params,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {} part {} (seed {}, size {}, density {}): expected {}, got {}\n{}",
+            self.day, self.part, self.params.seed, self.params.size, self.params.density, self.expected, self.actual, self.input)
+    }
+}
+
+/// Solves a part of a day the naive way, None if there's no reference or the input has no answer
+pub fn solve(day: usize, part: u8, input: &str) -> Option<Answer> {
+    match (day, part) {
+        (1, _) => day_1(input, part),
+        (2, _) => day_2(input, part),
+        (3, _) => day_3(input, part),
+        (4, _) => day_4(input, part),
+        (5, _) => day_5(input, part),
+        (6, _) => day_6(input, part),
+        (7, _) => day_7(input, part),
+        (8, _) => day_8(input, part),
+        (9, _) => day_9(input, part),
+        (10, _) => day_10(input, part),
+        (11, _) => day_11(input, part),
+        (12, _) => day_12(input, part),
+        (13, _) => day_13(input, part),
+        (14, _) => day_14(input, part),
+        (15, _) => day_15(input, part),
+        (16, _) => day_16(input, part),
+        (17, _) => day_17(input, part),
+        (18, _) => day_18(input, part),
+        (19, _) => day_19(input, part),
+        (20, _) => day_20(input, part),
+        (21, _) => day_21(input, part),
+        (22, _) => day_22(input, part),
+        (23, _) => day_23(input, part),
+        (24, _) => day_24(input, part),
+        (25, 1) => day_25(input),
+        _ => None,
+    }
+}
+
+/// Runs the solver and the reference on `input`. The reference runs first, so a solver
+/// isn't run on an input which has no answer (it might never return)
+pub fn compare(day: usize, part: u8, input: &str) -> Outcome {
+    let expected = match solve(day, part, input) {
+        Some(expected) => expected.to_string(),
+        None => return Outcome::Skipped,
+    };
+    let actual = match SOLVERS.get(day.wrapping_sub(1)).and_then(|solver| solver.solve(part, input)) {
+        Some(Ok(answer)) => answer.to_string(),
+        Some(Err(e)) => format!("error: {}", e),
+        None => return Outcome::Skipped,
+    };
+    if expected == actual {
+        Outcome::Match
+    } else {
+        Outcome::Mismatch { expected, actual }
+    }
+}
+
+/// Compares both parts of a day on `cases` inputs, with the seeds counting up from `params.seed`.
+/// Returns the number of compared parts or the first mismatch
+pub fn differential(day: usize, params: Params, cases: u64) -> Result<usize, Box<Mismatch>> {
+    let mut compared = 0;
+    for seed in params.seed..params.seed + cases {
+        let params = Params { seed, ..params };
+        let Some(input) = generate::input(day, &params) else {
+            break;
+        };
+        for part in [1, 2] {
+            match compare(day, part, &input) {
+                Outcome::Match => compared += 1,
+                Outcome::Skipped => (),
+                Outcome::Mismatch { expected, actual } => {
+                    return Err(Box::new(Mismatch { day, part, params, input, expected, actual }));
+                }
+            }
+        }
+    }
+    Ok(compared)
+}
+
+fn numbers<T: std::str::FromStr>(s: &str) -> Vec<T> {
+    s.split(|c: char| !c.is_ascii_digit() && c != '-')
+        .filter(|n| !n.is_empty())
+        .filter_map(|n| n.parse().ok())
+        .collect()
+}
+
+fn day_1(input: &str, part: u8) -> Option<Answer> {
+    let depths: Vec<i64> = numbers(input);
+    let sums: Vec<i64> = match part {
+        1 => depths,
+        _ => depths.windows(3).map(|w| w.iter().sum()).collect(),
+    };
+    Some(sums.windows(2).filter(|w| w[1] > w[0]).count().into())
+}
+
+fn day_2(input: &str, part: u8) -> Option<Answer> {
+    let (mut pos, mut depth, mut aim) = (0i64, 0i64, 0i64);
+    for line in input.lines() {
+        let (command, n) = line.split_once(' ')?;
+        let n: i64 = n.parse().ok()?;
+        match (command, part) {
+            ("forward", 1) => pos += n,
+            ("forward", _) => {
+                pos += n;
+                depth += aim * n;
+            },
+            ("down", 1) => depth += n,
+            ("up", 1) => depth -= n,
+            ("down", _) => aim += n,
+            _ => aim -= n,
+        }
+    }
+    Some((pos * depth).into())
+}
+
+fn day_3(input: &str, part: u8) -> Option<Answer> {
+    let numbers: Vec<&str> = input.lines().collect();
+    let width = numbers.first()?.len();
+    let ones = |numbers: &[&str], i: usize| numbers.iter().filter(|n| n.as_bytes()[i] == b'1').count();
+    let value = |n: &str| u64::from_str_radix(n, 2).ok();
+    if part == 1 {
+        let gamma: String = (0..width).map(|i| if 2 * ones(&numbers, i) > numbers.len() { '1' } else { '0' }).collect();
+        let epsilon: String = gamma.chars().map(|c| if c == '1' { '0' } else { '1' }).collect();
+        return Some((value(&gamma)? * value(&epsilon)?).into());
+    }
+    let rating = |most_common: bool| {
+        let mut left = numbers.clone();
+        for i in 0..width {
+            if left.len() == 1 {
+                break;
+            }
+            let ones = ones(&left, i);
+            let keep_ones = (2 * ones >= left.len()) == most_common;
+            left.retain(|n| (n.as_bytes()[i] == b'1') == keep_ones);
+        }
+        value(left.first()?)
+    };
+    Some((rating(true)? * rating(false)?).into())
+}
+
+fn day_4(input: &str, part: u8) -> Option<Answer> {
+    let mut blocks = input.split("\n\n");
+    let drawn: Vec<u32> = numbers(blocks.next()?);
+    let boards: Vec<Vec<u32>> = blocks.map(numbers).collect();
+    let mut marked = HashSet::new();
+    let mut won = vec![false; boards.len()];
+    let mut scores = vec![];
+    for n in drawn {
+        marked.insert(n);
+        for (board, won) in boards.iter().zip(won.iter_mut()) {
+            let row = (0..5).any(|r| (0..5).all(|c| marked.contains(&board[r * 5 + c])));
+            let col = (0..5).any(|c| (0..5).all(|r| marked.contains(&board[r * 5 + c])));
+            if !*won && (row || col) {
+                *won = true;
+                scores.push(board.iter().filter(|n| !marked.contains(n)).sum::<u32>() * n);
+            }
+        }
+    }
+    let score = if part == 1 { scores.first() } else { scores.last() };
+    Some((*score?).into())
+}
+
+fn day_5(input: &str, part: u8) -> Option<Answer> {
+    let mut counts = HashMap::new();
+    for line in input.lines() {
+        let [x1, y1, x2, y2]: [i64; 4] = numbers::<i64>(line).try_into().ok()?;
+        let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+        if part == 1 && dx != 0 && dy != 0 {
+            continue;
+        }
+        let len = (x2 - x1).abs().max((y2 - y1).abs());
+        for i in 0..=len {
+            *counts.entry((x1 + i * dx, y1 + i * dy)).or_insert(0) += 1;
+        }
+    }
+    Some(counts.values().filter(|n| **n >= 2).count().into())
+}
+
+fn day_6(input: &str, part: u8) -> Option<Answer> {
+    let fish: Vec<u64> = numbers(input);
+    if part == 1 {
+        let mut fish = fish;
+        for _ in 0..80 {
+            let new = fish.iter().filter(|f| **f == 0).count();
+            fish = fish.into_iter().map(|f| if f == 0 { 6 } else { f - 1 }).collect();
+            fish.extend(std::iter::repeat_n(8, new));
+        }
+        return Some(fish.len().into());
+    }
+    // a fish and all its children, counted from the day
+    fn family(timer: u64, days: u64, cache: &mut HashMap<(u64, u64), u64>) -> u64 {
+        if days <= timer {
+            return 1;
+        }
+        if let Some(n) = cache.get(&(timer, days)) {
+            return *n;
+        }
+        let n = family(6, days - timer - 1, cache) + family(8, days - timer - 1, cache);
+        cache.insert((timer, days), n);
+        n
+    }
+    let mut cache = HashMap::new();
+    Some(fish.iter().map(|f| family(*f, 256, &mut cache)).sum::<u64>().into())
+}
+
+fn day_7(input: &str, part: u8) -> Option<Answer> {
+    let crabs: Vec<i64> = numbers(input);
+    let cost = |d: i64| if part == 1 { d } else { d * (d + 1) / 2 };
+    (*crabs.iter().min()?..=*crabs.iter().max()?)
+        .map(|pos| crabs.iter().map(|c| cost((c - pos).abs())).sum::<i64>())
+        .min()
+        .map(Answer::from)
+}
+
+fn day_8(input: &str, part: u8) -> Option<Answer> {
+    const DIGITS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+    let mut total = 0;
+    for line in input.lines() {
+        let (patterns, output) = line.split_once(" | ")?;
+        if part == 1 {
+            total += output.split_whitespace().filter(|d| [2, 3, 4, 7].contains(&d.len())).count();
+            continue;
+        }
+        // try every wiring until all patterns are digits
+        let digit = |wiring: &[u8], pattern: &str| {
+            let mut segments: Vec<u8> = pattern.bytes().map(|b| wiring[(b - b'a') as usize]).collect();
+            segments.sort();
+            DIGITS.iter().position(|d| d.as_bytes() == segments)
+        };
+        let mut wiring: Vec<u8> = b"abcdefg".to_vec();
+        let mut found = None;
+        permutations(&mut wiring, 0, &mut |wiring| {
+            if found.is_none() && patterns.split_whitespace().all(|p| digit(wiring, p).is_some()) {
+                found = Some(wiring.to_vec());
+            }
+        });
+        let wiring = found?;
+        total += output.split_whitespace().try_fold(0, |n, d| Some(n * 10 + digit(&wiring, d)?))?;
+    }
+    Some(total.into())
+}
+
+fn permutations(items: &mut [u8], k: usize, f: &mut impl FnMut(&[u8])) {
+    if k == items.len() {
+        f(items);
+        return;
+    }
+    for i in k..items.len() {
+        items.swap(k, i);
+        permutations(items, k + 1, f);
+        items.swap(k, i);
+    }
+}
+
+fn digit_grid(input: &str) -> Vec<Vec<u32>> {
+    input.lines().map(|line| line.chars().filter_map(|c| c.to_digit(10)).collect()).collect()
+}
+
+fn neighbours(grid: &[Vec<u32>], (r, c): (usize, usize), diagonal: bool) -> Vec<(usize, usize)> {
+    let mut result = vec![];
+    for dr in -1..=1_isize {
+        for dc in -1..=1_isize {
+            if (dr == 0 && dc == 0) || (!diagonal && dr != 0 && dc != 0) {
+                continue;
+            }
+            let (nr, nc) = (r as isize + dr, c as isize + dc);
+            if nr >= 0 && nc >= 0 && (nr as usize) < grid.len() && (nc as usize) < grid[0].len() {
+                result.push((nr as usize, nc as usize));
+            }
+        }
+    }
+    result
+}
+
+fn day_9(input: &str, part: u8) -> Option<Answer> {
+    let grid = digit_grid(input);
+    let cells: Vec<(usize, usize)> = (0..grid.len()).flat_map(|r| (0..grid[0].len()).map(move |c| (r, c))).collect();
+    if part == 1 {
+        let lows = cells.iter().filter(|&&(r, c)| neighbours(&grid, (r, c), false).iter().all(|&(nr, nc)| grid[nr][nc] > grid[r][c]));
+        return Some(lows.map(|&(r, c)| grid[r][c] + 1).sum::<u32>().into());
+    }
+    let mut seen = HashSet::new();
+    let mut basins = vec![];
+    for &start in &cells {
+        if grid[start.0][start.1] == 9 || !seen.insert(start) {
+            continue;
+        }
+        let mut queue = vec![start];
+        let mut size = 0;
+        while let Some(cell) = queue.pop() {
+            size += 1;
+            for next in neighbours(&grid, cell, false) {
+                if grid[next.0][next.1] != 9 && seen.insert(next) {
+                    queue.push(next);
+                }
+            }
+        }
+        basins.push(size);
+    }
+    if basins.len() < 3 {
+        return None;
+    }
+    basins.sort_by(|a, b| b.cmp(a));
+    Some((basins[0] * basins[1] * basins[2]).into())
+}
+
+fn day_10(input: &str, part: u8) -> Option<Answer> {
+    let mut errors = 0;
+    let mut completions = vec![];
+    for line in input.lines() {
+        // remove matching pairs until none are left
+        let mut line = line.to_string();
+        loop {
+            let reduced = line.replace("()", "").replace("[]", "").replace("{}", "").replace("<>", "");
+            if reduced == line {
+                break;
+            }
+            line = reduced;
+        }
+        match line.chars().find(|c| ")]}>".contains(*c)) {
+            Some(c) => errors += [3, 57, 1197, 25137][")]}>".find(c)?],
+            None => completions.push(line.chars().rev().fold(0u64, |score, c| score * 5 + 1 + "([{<".find(c).unwrap() as u64)),
+        }
+    }
+    if part == 1 {
+        return Some(errors.into());
+    }
+    completions.sort();
+    completions.get(completions.len() / 2).map(|n| (*n).into())
+}
+
+fn day_11(input: &str, part: u8) -> Option<Answer> {
+    let mut grid = digit_grid(input);
+    let mut flashes = 0;
+    for step in 1..10_000 {
+        let mut queue = VecDeque::new();
+        for (r, row) in grid.iter_mut().enumerate() {
+            for (c, energy) in row.iter_mut().enumerate() {
+                *energy += 1;
+                if *energy == 10 {
+                    queue.push_back((r, c));
+                }
+            }
+        }
+        let mut flashed = HashSet::new();
+        while let Some(cell) = queue.pop_front() {
+            flashed.insert(cell);
+            for (nr, nc) in neighbours(&grid, cell, true) {
+                grid[nr][nc] += 1;
+                if grid[nr][nc] == 10 {
+                    queue.push_back((nr, nc));
+                }
+            }
+        }
+        for &(r, c) in &flashed {
+            grid[r][c] = 0;
+        }
+        flashes += flashed.len();
+        if part == 1 && step == 100 {
+            return Some(flashes.into());
+        }
+        if part == 2 && flashed.len() == grid.len() * grid[0].len() {
+            return Some(step.into());
+        }
+    }
+    None
+}
+
+fn day_12(input: &str, part: u8) -> Option<Answer> {
+    let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+    for line in input.lines() {
+        let (a, b) = line.split_once('-')?;
+        edges.entry(a).or_default().push(b);
+        edges.entry(b).or_default().push(a);
+    }
+    fn paths<'a>(cave: &'a str, path: &mut Vec<&'a str>, revisit: bool, edges: &HashMap<&'a str, Vec<&'a str>>) -> usize {
+        if cave == "end" {
+            return 1;
+        }
+        let mut count = 0;
+        for &next in &edges[cave] {
+            let small = next.chars().all(|c| c.is_ascii_lowercase());
+            let visited = small && path.contains(&next);
+            if next == "start" || (visited && !revisit) {
+                continue;
+            }
+            path.push(next);
+            count += paths(next, path, revisit && !visited, edges);
+            path.pop();
+        }
+        count
+    }
+    Some(paths("start", &mut vec!["start"], part == 2, &edges).into())
+}
+
+fn day_13(input: &str, part: u8) -> Option<Answer> {
+    let (dots, folds) = input.split_once("\n\n")?;
+    let mut dots: HashSet<(usize, usize)> = dots.lines().filter_map(|l| {
+        let (x, y) = l.split_once(',')?;
+        Some((x.parse().ok()?, y.parse().ok()?))
+    }).collect();
+    let (mut width, mut height) = (0, 0);
+    for (i, fold) in folds.lines().enumerate() {
+        let (axis, line) = fold.strip_prefix("fold along ")?.split_once('=')?;
+        let line: usize = line.parse().ok()?;
+        dots = dots.into_iter().map(|(x, y)| match axis {
+            "x" if x > line => (2 * line - x, y),
+            "y" if y > line => (x, 2 * line - y),
+            _ => (x, y),
+        }).collect();
+        if axis == "x" { width = line } else { height = line }
+        if part == 1 && i == 0 {
+            return Some(dots.len().into());
+        }
+    }
+    let mut code = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            code.push(if dots.contains(&(x, y)) { '#' } else { '.' });
+        }
+        code.push('\n');
+    }
+    Some(code.into())
+}
+
+fn day_14(input: &str, part: u8) -> Option<Answer> {
+    let (template, rules) = input.split_once("\n\n")?;
+    let rules: HashMap<(char, char), char> = rules.lines().filter_map(|l| {
+        let (pair, insert) = l.split_once(" -> ")?;
+        let mut pair = pair.chars();
+        Some(((pair.next()?, pair.next()?), insert.chars().next()?))
+    }).collect();
+    let mut counts: HashMap<char, u64> = HashMap::new();
+    if part == 1 {
+        let mut polymer: Vec<char> = template.trim().chars().collect();
+        for _ in 0..10 {
+            let mut grown = vec![polymer[0]];
+            for w in polymer.windows(2) {
+                grown.push(rules[&(w[0], w[1])]);
+                grown.push(w[1]);
+            }
+            polymer = grown;
+        }
+        for c in polymer {
+            *counts.entry(c).or_default() += 1;
+        }
+    } else {
+        // counts of the elements inserted between a and b within some steps
+        fn inserted(a: char, b: char, steps: usize, rules: &HashMap<(char, char), char>, cache: &mut HashMap<(char, char, usize), HashMap<char, u64>>) -> HashMap<char, u64> {
+            if steps == 0 {
+                return HashMap::new();
+            }
+            if let Some(counts) = cache.get(&(a, b, steps)) {
+                return counts.clone();
+            }
+            let c = rules[&(a, b)];
+            let mut counts = inserted(a, c, steps - 1, rules, cache);
+            for (element, n) in inserted(c, b, steps - 1, rules, cache) {
+                *counts.entry(element).or_default() += n;
+            }
+            *counts.entry(c).or_default() += 1;
+            cache.insert((a, b, steps), counts.clone());
+            counts
+        }
+        let template: Vec<char> = template.trim().chars().collect();
+        let mut cache = HashMap::new();
+        for c in &template {
+            *counts.entry(*c).or_default() += 1;
+        }
+        for w in template.windows(2) {
+            for (element, n) in inserted(w[0], w[1], 40, &rules, &mut cache) {
+                *counts.entry(element).or_default() += n;
+            }
+        }
+    }
+    Some((counts.values().max()? - counts.values().min()?).into())
+}
+
+fn day_15(input: &str, part: u8) -> Option<Answer> {
+    let mut grid = digit_grid(input);
+    if part == 2 {
+        let (h, w) = (grid.len(), grid[0].len());
+        grid = (0..h * 5).map(|r| (0..w * 5).map(|c| (grid[r % h][c % w] + (r / h + c / w) as u32 - 1) % 9 + 1).collect()).collect();
+    }
+    // relax every cell until nothing changes
+    let (h, w) = (grid.len(), grid[0].len());
+    let mut risk = vec![vec![u32::MAX; w]; h];
+    risk[0][0] = 0;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for r in 0..h {
+            for c in 0..w {
+                for (nr, nc) in neighbours(&grid, (r, c), false) {
+                    if risk[nr][nc] != u32::MAX && risk[nr][nc] + grid[r][c] < risk[r][c] {
+                        risk[r][c] = risk[nr][nc] + grid[r][c];
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+    Some(risk[h - 1][w - 1].into())
+}
+
+fn day_16(input: &str, part: u8) -> Option<Answer> {
+    let bits: Vec<u8> = input.trim().chars().filter_map(|c| c.to_digit(16)).flat_map(|n| (0..4).rev().map(move |i| (n >> i & 1) as u8)).collect();
+    fn read(bits: &[u8], pos: &mut usize, len: usize) -> Option<u64> {
+        let n = bits.get(*pos..*pos + len)?.iter().fold(0, |n, b| n * 2 + *b as u64);
+        *pos += len;
+        Some(n)
+    }
+    /// Returns (version sum, value)
+    fn packet(bits: &[u8], pos: &mut usize) -> Option<(u64, u64)> {
+        let version = read(bits, pos, 3)?;
+        let typ = read(bits, pos, 3)?;
+        if typ == 4 {
+            let mut value = 0;
+            loop {
+                let more = read(bits, pos, 1)?;
+                value = value * 16 + read(bits, pos, 4)?;
+                if more == 0 {
+                    return Some((version, value));
+                }
+            }
+        }
+        let mut subs = vec![];
+        if read(bits, pos, 1)? == 0 {
+            let end = read(bits, pos, 15)? as usize + *pos;
+            while *pos < end {
+                subs.push(packet(bits, pos)?);
+            }
+        } else {
+            for _ in 0..read(bits, pos, 11)? {
+                subs.push(packet(bits, pos)?);
+            }
+        }
+        let values: Vec<u64> = subs.iter().map(|(_, v)| *v).collect();
+        let value = match typ {
+            0 => values.iter().sum(),
+            1 => values.iter().product(),
+            2 => *values.iter().min()?,
+            3 => *values.iter().max()?,
+            5 => (values[0] > values[1]) as u64,
+            6 => (values[0] < values[1]) as u64,
+            _ => (values[0] == values[1]) as u64,
+        };
+        Some((version + subs.iter().map(|(v, _)| v).sum::<u64>(), value))
+    }
+    let (versions, value) = packet(&bits, &mut 0)?;
+    Some(if part == 1 { versions } else { value }.into())
+}
+
+fn day_17(input: &str, part: u8) -> Option<Answer> {
+    let [x1, x2, y1, y2]: [i64; 4] = numbers::<i64>(input).try_into().ok()?;
+    let mut heights = vec![];
+    for vx in 0..=x2 {
+        for vy in y1..=y1.abs() {
+            let (mut x, mut y, mut vx, mut vy, mut top) = (0, 0, vx, vy, 0);
+            while x <= x2 && y >= y1 {
+                x += vx;
+                y += vy;
+                top = top.max(y);
+                vx -= vx.signum();
+                vy -= 1;
+                if (x1..=x2).contains(&x) && (y1..=y2).contains(&y) {
+                    heights.push(top);
+                    break;
+                }
+            }
+        }
+    }
+    Some(if part == 1 { (*heights.iter().max()?).into() } else { heights.len().into() })
+}
+
+#[derive(Clone)]
+enum Snail {
+    Regular(u32),
+    Pair(Box<Snail>, Box<Snail>),
+}
+
+impl Snail {
+    fn parse(s: &mut std::str::Chars) -> Option<Snail> {
+        match s.next()? {
+            '[' => {
+                let left = Snail::parse(s)?;
+                s.next()?; // ','
+                let right = Snail::parse(s)?;
+                s.next()?; // ']'
+                Some(Snail::Pair(Box::new(left), Box::new(right)))
+            },
+            c => Some(Snail::Regular(c.to_digit(10)?)),
+        }
+    }
+
+    fn add_leftmost(&mut self, n: u32) {
+        match self {
+            Snail::Regular(v) => *v += n,
+            Snail::Pair(left, _) => left.add_leftmost(n),
+        }
+    }
+
+    fn add_rightmost(&mut self, n: u32) {
+        match self {
+            Snail::Regular(v) => *v += n,
+            Snail::Pair(_, right) => right.add_rightmost(n),
+        }
+    }
+
+    /// Explodes the leftmost pair nested in four pairs, returns the numbers which go left and right
+    fn explode(&mut self, depth: usize) -> Option<(u32, u32)> {
+        let Snail::Pair(left, right) = self else {
+            return None;
+        };
+        if depth == 4 {
+            let (Snail::Regular(l), Snail::Regular(r)) = (left.as_ref(), right.as_ref()) else {
+                return None;
+            };
+            let exploded = (*l, *r);
+            *self = Snail::Regular(0);
+            return Some(exploded);
+        }
+        if let Some((l, r)) = left.explode(depth + 1) {
+            right.add_leftmost(r);
+            return Some((l, 0));
+        }
+        if let Some((l, r)) = right.explode(depth + 1) {
+            left.add_rightmost(l);
+            return Some((0, r));
+        }
+        None
+    }
+
+    fn split(&mut self) -> bool {
+        match self {
+            Snail::Regular(n) if *n >= 10 => {
+                *self = Snail::Pair(Box::new(Snail::Regular(*n / 2)), Box::new(Snail::Regular(*n - *n / 2)));
+                true
+            },
+            Snail::Regular(_) => false,
+            Snail::Pair(left, right) => left.split() || right.split(),
+        }
+    }
+
+    fn add(self, other: Snail) -> Snail {
+        let mut sum = Snail::Pair(Box::new(self), Box::new(other));
+        while sum.explode(0).is_some() || sum.split() {}
+        sum
+    }
+
+    fn magnitude(&self) -> u32 {
+        match self {
+            Snail::Regular(n) => *n,
+            Snail::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+}
+
+fn day_18(input: &str, part: u8) -> Option<Answer> {
+    let numbers = input.lines().map(|l| Snail::parse(&mut l.chars())).collect::<Option<Vec<_>>>()?;
+    if part == 1 {
+        let sum = numbers.into_iter().reduce(Snail::add)?;
+        return Some(sum.magnitude().into());
+    }
+    let mut max = 0;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                max = max.max(a.clone().add(b.clone()).magnitude());
+            }
+        }
+    }
+    Some(max.into())
+}
+
+fn day_19(input: &str, part: u8) -> Option<Answer> {
+    let mut scanners: Vec<Vec<[i64; 3]>> = vec![];
+    for line in input.lines().filter(|l| !l.is_empty()) {
+        if line.starts_with("---") {
+            scanners.push(vec![]);
+        } else {
+            let n: Vec<i64> = numbers(line);
+            scanners.last_mut()?.push([*n.first()?, *n.get(1)?, *n.get(2)?]);
+        }
+    }
+    // a rotation turns the unit vectors into rows r0, r1, r2 with r0 x r1 = r2, the other 24 mirror
+    let units = [[1, 0, 0], [-1, 0, 0], [0, 1, 0], [0, -1, 0], [0, 0, 1], [0, 0, -1]];
+    let cross = |a: [i64; 3], b: [i64; 3]| [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+    let rotations: Vec<[[i64; 3]; 3]> = units.iter()
+        .flat_map(|&r0| units.iter().filter(move |&&r1| cross(r0, r1) != [0; 3]).map(move |&r1| [r0, r1, cross(r0, r1)]))
+        .collect();
+    let rotate = |m: &[[i64; 3]; 3], b: &[i64; 3]| m.map(|row| row[0] * b[0] + row[1] * b[1] + row[2] * b[2]);
+    let add = |a: &[i64; 3], b: &[i64; 3]| [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+
+    // places the scanners one after the other, trying every rotation and counting the offsets between every pair of beacons
+    let mut beacons: HashSet<[i64; 3]> = scanners.first()?.iter().copied().collect();
+    let mut positions = vec![[0; 3]];
+    let mut left: Vec<usize> = (1..scanners.len()).collect();
+    while !left.is_empty() {
+        let (i, offset, rotated) = left.iter().enumerate().find_map(|(i, &s)| {
+            rotations.iter().find_map(|m| {
+                let rotated: Vec<[i64; 3]> = scanners[s].iter().map(|b| rotate(m, b)).collect();
+                let mut offsets = HashMap::new();
+                for a in &beacons {
+                    for b in &rotated {
+                        *offsets.entry([a[0] - b[0], a[1] - b[1], a[2] - b[2]]).or_insert(0) += 1;
+                    }
+                }
+                let (offset, _) = offsets.into_iter().find(|(_, count)| *count >= 12)?;
+                Some((i, offset, rotated))
+            })
+        })?;
+        left.remove(i);
+        positions.push(offset);
+        beacons.extend(rotated.iter().map(|b| add(b, &offset)));
+    }
+
+    if part == 1 {
+        return Some((beacons.len() as u64).into());
+    }
+    let distance = |a: &[i64; 3], b: &[i64; 3]| (0..3).map(|i| (a[i] - b[i]).abs()).sum::<i64>();
+    positions.iter().flat_map(|a| positions.iter().map(|b| distance(a, b))).max().map(Answer::from)
+}
+
+fn day_20(input: &str, part: u8) -> Option<Answer> {
+    let (algorithm, image) = input.split_once("\n\n")?;
+    let algorithm: Vec<bool> = algorithm.trim().chars().map(|c| c == '#').collect();
+    let mut lit: HashSet<(i64, i64)> = HashSet::new();
+    for (y, line) in image.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c == '#' {
+                lit.insert((x as i64, y as i64));
+            }
+        }
+    }
+    let (mut low, mut high) = (0, image.lines().count().max(image.lines().next()?.len()) as i64);
+    let mut outside = false;
+    for _ in 0..if part == 1 { 2 } else { 50 } {
+        let is_lit = |x: i64, y: i64| if (low..high).contains(&x) && (low..high).contains(&y) { lit.contains(&(x, y)) } else { outside };
+        let mut next = HashSet::new();
+        for y in low - 1..high + 1 {
+            for x in low - 1..high + 1 {
+                let index = (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (dx, dy))).fold(0, |i, (dx, dy)| i * 2 + is_lit(x + dx, y + dy) as usize);
+                if algorithm[index] {
+                    next.insert((x, y));
+                }
+            }
+        }
+        outside = algorithm[if outside { 511 } else { 0 }];
+        lit = next;
+        low -= 1;
+        high += 1;
+    }
+    Some(lit.len().into())
+}
+
+fn day_21(input: &str, part: u8) -> Option<Answer> {
+    let starts: Vec<u64> = input.lines().filter_map(|l| l.rsplit(' ').next()?.parse().ok()).collect();
+    if part == 1 {
+        let (mut pos, mut score, mut rolls) = ([starts[0], starts[1]], [0, 0], 0);
+        for turn in 0.. {
+            let player = turn % 2;
+            let moved: u64 = (0..3).map(|i| (rolls + i) % 100 + 1).sum();
+            rolls += 3;
+            pos[player] = (pos[player] + moved - 1) % 10 + 1;
+            score[player] += pos[player];
+            if score[player] >= 1000 {
+                return Some((score[1 - player] * rolls).into());
+            }
+        }
+    }
+    /// Wins of the player whose turn it is and of the other one
+    fn wins(pos: u64, score: u64, other_pos: u64, other_score: u64, cache: &mut HashMap<(u64, u64, u64, u64), (u64, u64)>) -> (u64, u64) {
+        if other_score >= 21 {
+            return (0, 1);
+        }
+        if let Some(w) = cache.get(&(pos, score, other_pos, other_score)) {
+            return *w;
+        }
+        let mut total = (0, 0);
+        for a in 1..=3 {
+            for b in 1..=3 {
+                for c in 1..=3 {
+                    let pos = (pos + a + b + c - 1) % 10 + 1;
+                    let (other, me) = wins(other_pos, other_score, pos, score + pos, cache);
+                    total = (total.0 + me, total.1 + other);
+                }
+            }
+        }
+        cache.insert((pos, score, other_pos, other_score), total);
+        total
+    }
+    let (a, b) = wins(starts[0], 0, starts[1], 0, &mut HashMap::new());
+    Some(a.max(b).into())
+}
+
+fn day_22(input: &str, part: u8) -> Option<Answer> {
+    let mut steps = vec![];
+    for line in input.lines() {
+        let on = line.starts_with("on");
+        let mut cuboid: [i64; 6] = numbers::<i64>(line).try_into().ok()?;
+        if part == 1 {
+            // only the cubes in -50..=50 count
+            for axis in 0..3 {
+                cuboid[2 * axis] = cuboid[2 * axis].max(-50);
+                cuboid[2 * axis + 1] = cuboid[2 * axis + 1].min(50);
+            }
+            if (0..3).any(|axis| cuboid[2 * axis] > cuboid[2 * axis + 1]) {
+                continue;
+            }
+        }
+        steps.push((on, cuboid));
+    }
+    // split the space at the edge of every cuboid and turn the blocks on and off
+    let edges: Vec<Vec<i64>> = (0..3).map(|axis| {
+        let mut edges: Vec<i64> = steps.iter().flat_map(|(_, c)| [c[2 * axis], c[2 * axis + 1] + 1]).collect();
+        edges.sort();
+        edges.dedup();
+        edges
+    }).collect();
+    let (nx, ny, nz) = (edges[0].len(), edges[1].len(), edges[2].len());
+    let mut on = vec![false; nx * ny * nz];
+    for (turn_on, c) in &steps {
+        let range = |axis: usize| {
+            let start = edges[axis].binary_search(&c[2 * axis]).unwrap();
+            start..edges[axis].binary_search(&(c[2 * axis + 1] + 1)).unwrap()
+        };
+        for x in range(0) {
+            for y in range(1) {
+                for z in range(2) {
+                    on[(x * ny + y) * nz + z] = *turn_on;
+                }
+            }
+        }
+    }
+    let mut count = 0;
+    for x in 0..nx.saturating_sub(1) {
+        for y in 0..ny.saturating_sub(1) {
+            for z in 0..nz.saturating_sub(1) {
+                if on[(x * ny + y) * nz + z] {
+                    count += (edges[0][x + 1] - edges[0][x]) * (edges[1][y + 1] - edges[1][y]) * (edges[2][z + 1] - edges[2][z]);
+                }
+            }
+        }
+    }
+    Some(count.into())
+}
+
+fn day_23(input: &str, part: u8) -> Option<Answer> {
+    let mut rows: Vec<Vec<u8>> = input.lines().map(|l| l.bytes().filter(u8::is_ascii_uppercase).collect::<Vec<_>>()).filter(|r| !r.is_empty()).collect();
+    if part == 2 {
+        rows.splice(1..1, [b"DCBA".to_vec(), b"DBAC".to_vec()]);
+    }
+    let depth = rows.len();
+    // the hallway (11 cells) followed by the rooms, each from top to bottom
+    let mut start = vec![b'.'; 11];
+    for room in 0..4 {
+        start.extend(rows.iter().map(|row| row[room]));
+    }
+    let cell = |room: usize, i: usize| 11 + room * depth + i;
+    let energy = |pod: u8| 10u64.pow((pod - b'A') as u32);
+    let done = |state: &[u8]| (0..4).all(|room| (0..depth).all(|i| state[cell(room, i)] == b'A' + room as u8));
+
+    let mut queue = BinaryHeap::new();
+    let mut best = HashMap::new();
+    queue.push(Reverse((0, start)));
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        if done(&state) {
+            return Some(cost.into());
+        }
+        if best.get(&state).is_some_and(|b| *b < cost) {
+            continue;
+        }
+        let mut moves = vec![];
+        for room in 0..4 {
+            let door = 2 + 2 * room;
+            let own = b'A' + room as u8;
+            // pods leave a room if one of them or one below them belongs somewhere else
+            if let Some(i) = (0..depth).find(|i| state[cell(room, *i)] != b'.') {
+                if (i..depth).any(|j| state[cell(room, j)] != own) {
+                    for h in [0, 1, 3, 5, 7, 9, 10] {
+                        let (a, b) = (h.min(door), h.max(door));
+                        if (a..=b).all(|x| state[x] == b'.') {
+                            moves.push((cell(room, i), h, i + 1 + b - a));
+                        }
+                    }
+                }
+            }
+            // pods enter their room if only pods of its type are in it
+            if (0..depth).all(|i| state[cell(room, i)] == b'.' || state[cell(room, i)] == own) {
+                let Some(i) = (0..depth).rev().find(|i| state[cell(room, *i)] == b'.') else {
+                    continue;
+                };
+                for h in [0, 1, 3, 5, 7, 9, 10] {
+                    if state[h] != own {
+                        continue;
+                    }
+                    let (a, b) = (h.min(door), h.max(door));
+                    if (a..=b).all(|x| x == h || state[x] == b'.') {
+                        moves.push((h, cell(room, i), i + 1 + b - a));
+                    }
+                }
+            }
+        }
+        for (from, to, steps) in moves {
+            let mut next = state.clone();
+            next.swap(from, to);
+            let next_cost = cost + steps as u64 * energy(state[from]);
+            if best.get(&next).is_none_or(|b| next_cost < *b) {
+                best.insert(next.clone(), next_cost);
+                queue.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+fn day_24(input: &str, part: u8) -> Option<Answer> {
+    let lines: Vec<&str> = input.lines().map(|l| l.split("//").next().unwrap().trim()).collect();
+    // the digit checks are pairs of a push and a pop on a base 26 stack, which digit is larger
+    // depends on the offsets, the other one follows from it
+    let mut digits = [0i64; 14];
+    let mut stack = vec![];
+    for (i, block) in lines.chunks(18).enumerate() {
+        let arg = |line: &str| line.rsplit(' ').next()?.parse::<i64>().ok();
+        let (n, m) = (arg(block[5])?, arg(block[15])?);
+        if n > 0 {
+            stack.push((i, m));
+        } else {
+            let (j, m) = stack.pop()?;
+            let delta = m + n;
+            let first = if part == 1 { 9.min(9 - delta) } else { 1.max(1 - delta) };
+            digits[j] = first;
+            digits[i] = first + delta;
+        }
+    }
+    // run the program on the number to make sure it's accepted
+    let mut input = digits.iter();
+    let mut regs = [0i64; 4];
+    for line in &lines {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let reg = |name: &str| (name.as_bytes()[0] - b'w') as usize;
+        let a = reg(parts[1]);
+        let b = parts.get(2).map(|p| p.parse().unwrap_or_else(|_| regs[reg(p)]));
+        regs[a] = match (parts[0], b) {
+            ("inp", _) => *input.next()?,
+            ("add", Some(b)) => regs[a] + b,
+            ("mul", Some(b)) => regs[a] * b,
+            ("div", Some(b)) if b != 0 => regs[a] / b,
+            ("mod", Some(b)) if b > 0 => regs[a] % b,
+            ("eql", Some(b)) => (regs[a] == b) as i64,
+            _ => return None,
+        };
+    }
+    if regs[3] != 0 {
+        return None;
+    }
+    Some(digits.iter().fold(0, |n, d| n * 10 + *d as u64).into())
+}
+
+fn day_25(input: &str) -> Option<Answer> {
+    let mut grid: Vec<Vec<u8>> = input.lines().map(|l| l.bytes().collect()).collect();
+    let (h, w) = (grid.len(), grid[0].len());
+    for step in 1..10_000 {
+        let mut moved = false;
+        for (herd, (dr, dc)) in [(b'>', (0, 1)), (b'v', (1, 0))] {
+            let before = grid.clone();
+            for r in 0..h {
+                for c in 0..w {
+                    let (nr, nc) = ((r + dr) % h, (c + dc) % w);
+                    if before[r][c] == herd && before[nr][nc] == b'.' {
+                        grid[r][c] = b'.';
+                        grid[nr][nc] = herd;
+                        moved = true;
+                    }
+                }
+            }
+        }
+        if !moved {
+            return Some(step.into());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::solve;
+
+    #[test]
+    fn test_examples() {
+        let example = |day: usize| std::fs::read_to_string(format!("{}/data/2021/day_{}_test.txt", env!("CARGO_MANIFEST_DIR"), day)).unwrap();
+        let expected: [(usize, u64, u64); 21] = [
+            (1, 7, 5), (2, 150, 900), (3, 198, 230), (4, 4512, 1924), (5, 5, 12), (6, 5934, 26984457539),
+            (7, 37, 168), (8, 26, 61229), (9, 15, 1134), (10, 26397, 288957), (11, 1656, 195), (12, 10, 36),
+            (14, 1588, 2188189693529), (15, 40, 315), (17, 45, 112), (18, 4140, 3993), (19, 79, 3621), (20, 35, 3351),
+            (21, 739785, 444356092776315), (23, 12521, 44169), (25, 58, 0),
+        ];
+        for (day, part_1, part_2) in expected {
+            let input = example(day);
+            assert_eq!(solve(day, 1, &input).map(|a| a.to_string()), Some(part_1.to_string()), "day {}", day);
+            if day != 25 {
+                assert_eq!(solve(day, 2, &input).map(|a| a.to_string()), Some(part_2.to_string()), "day {}", day);
+            }
+        }
+        assert_eq!(solve(16, 1, "8A004A801A8002F478").map(|a| a.to_string()), Some("16".to_string()));
+        assert_eq!(solve(16, 2, "9C0141080250320F1802104A08").map(|a| a.to_string()), Some("1".to_string()));
+    }
+}
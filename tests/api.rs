@@ -0,0 +1,62 @@
+//! Uses the parsed models of the days from outside of the crate.
+
+use advent_of_code::y2021::{day_13, day_15, day_16, day_18, day_22, day_23, day_24};
+
+const DATA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/2021");
+
+fn read(name: &str) -> String {
+    std::fs::read_to_string(format!("{}/{}.txt", DATA, name)).unwrap()
+}
+
+#[test]
+fn test_snail_number_arithmetic() {
+    let mut sum: day_18::SnailNumber = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+    sum += "[1,1]".parse().unwrap();
+    assert_eq!(sum, "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]".parse().unwrap());
+    assert_eq!(sum.magnitude(), 1384);
+
+    let numbers = day_18::parse(&read("day_18_test")).unwrap();
+    assert_eq!(day_18::solve_1(&numbers).unwrap(), 4140);
+}
+
+#[test]
+fn test_cuboid_volume() {
+    let a = day_22::Cuboid::from([[10, 12], [10, 12], [10, 12]]);
+    let b = day_22::Cuboid::from([[11, 13], [11, 13], [11, 13]]);
+    assert_eq!(a.volume(), 27);
+    assert_eq!(a.intersection(&b).map(|c| c.volume()), Some(8));
+    assert_eq!(a.intersection(&day_22::Cuboid::from([[20, 21], [0, 0], [0, 0]])), None);
+
+    let instructions = day_22::parse("on x=10..12,y=10..12,z=10..12\non x=11..13,y=11..13,z=11..13").unwrap();
+    assert_eq!(day_22::reboot(&instructions), 27 + 19);
+    assert_eq!(day_22::parse("on x=12..10,y=10..12,z=12..10").unwrap(), day_22::parse("on x=10..12,y=10..12,z=10..12").unwrap());
+}
+
+#[test]
+fn test_models() {
+    let cave = day_15::parse(&read("day_15_test")).unwrap();
+    assert_eq!(cave.lowest_risk(), Some(40));
+    assert_eq!(cave.enlarge(5).dim(), (50, 50));
+
+    let packet = day_16::parse("8A004A801A8002F478").unwrap();
+    assert_eq!(packet.version_sum(), 16);
+
+    let paper = day_13::parse(&read("day_13_test")).unwrap();
+    assert_eq!(paper.folds().len(), 2);
+    assert_eq!(day_13::solve_1(&paper), 17);
+
+    let diagram = day_23::parse(&read("day_23_test")).unwrap();
+    assert_eq!(diagram.rows().count(), 2);
+    assert_eq!(diagram.unfolded().rows().count(), 4);
+}
+
+#[test]
+fn test_monad_accepts() {
+    let monad = day_24::parse(&read("day_24")).unwrap();
+    let largest = day_24::solve_1(&monad).unwrap();
+    assert_eq!(monad.accepts(largest), Some(true));
+    // every digit is checked against another one, so changing one of them is rejected
+    assert_eq!(monad.accepts(largest - 10_usize.pow(13)), Some(false));
+    assert_eq!(monad.accepts(day_24::solve_2(&monad).unwrap()), Some(true));
+    assert_eq!(monad.accepts(1), None);
+}
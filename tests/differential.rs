@@ -0,0 +1,32 @@
+//! Compares the solvers with the naive references of `y2021::reference` on generated inputs.
+//!
+//! `AOC_STRESS_SEED` and `AOC_STRESS_CASES` change the seeds, e.g. to run more cases than the
+//! few which run by default: `AOC_STRESS_CASES=1000 cargo test --release --test differential`.
+
+use advent_of_code::y2021::{generate::Params, reference::differential};
+
+fn env(name: &str, default: u64) -> u64 {
+    std::env::var(name).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+fn check(day: usize, size: usize, density: f64, cases: u64) {
+    let seed = env("AOC_STRESS_SEED", 0);
+    let cases = env("AOC_STRESS_CASES", cases);
+    match differential(day, Params::new(seed, size, density), cases) {
+        Ok(compared) => assert!(compared > 0, "day {} compared nothing", day),
+        Err(mismatch) => panic!("{}", mismatch),
+    }
+}
+
+#[test]
+fn test_differential() {
+    // day 25 needs enough sea cucumbers to get stuck
+    for day in 1..=24 {
+        // the references of these days take seconds in debug builds
+        let cases = if [19, 20, 21, 23, 24].contains(&day) { 2 } else { 20 };
+        check(day, 10, 0.4, cases);
+    }
+    check(25, 10, 0.7, 20);
+    // rooms which are already 4 rows deep
+    check(23, 10, 1.0, 1);
+}
@@ -0,0 +1,29 @@
+//! Runs the solvers on the example inputs in `data/<YEAR>/`.
+//!
+//! A test is generated by `build.rs` for every `day_N_test*.txt` with a sibling `day_N_test*.toml`,
+//! which holds the expected answers like `data/2021/answers.toml`. Parts without an answer are skipped,
+//! so adding an example is done by adding the two files.
+
+use std::{fs, path::Path};
+
+use advent_of_code::{answers::{Answers, Verdict}, get_solver, input};
+
+fn check_fixture(year: u16, day: usize, name: &str) {
+    let dir = input::year_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join("data"), year);
+    let input = fs::read_to_string(dir.join(format!("{}.txt", name))).unwrap();
+    let answers = Answers::parse(&fs::read_to_string(dir.join(format!("{}.toml", name))).unwrap()).unwrap();
+    let solver = get_solver(year, day).unwrap();
+
+    let mut checked = 0;
+    for part in [1, 2] {
+        if answers.get(day, part).is_none() {
+            continue;
+        }
+        let answer = solver.solve(part, &input).unwrap().unwrap();
+        assert_eq!(answers.check(day, part, &answer), Verdict::Pass, "{} part {}", name, part);
+        checked += 1;
+    }
+    assert!(checked > 0, "{}.toml has no answers for day {}", name, day);
+}
+
+include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
@@ -0,0 +1,40 @@
+//! Runs jobs with the counting allocator of `limit` installed, like the binary does.
+
+use std::time::Duration;
+
+use advent_of_code::{get_solver, limit::{run, stopped, CountingAlloc, Limits, Stop, Token}};
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+const DATA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/2021");
+
+#[test]
+fn test_memory_is_counted() {
+    let limited = run(Limits::default(), Token::new(), || vec![1u8; 1 << 20].iter().map(|n| *n as usize).sum::<usize>());
+    assert_eq!(limited.result, Ok(1 << 20));
+    assert!(limited.memory.unwrap() >= 1 << 20);
+}
+
+#[test]
+fn test_memory_cap() {
+    let limits = Limits { timeout: None, memory: Some(1 << 20) };
+    let limited = run(limits, Token::new(), || {
+        let mut chunks = vec![];
+        while !stopped() {
+            chunks.push(vec![0u8; 1 << 16]);
+        }
+        chunks.len()
+    });
+    assert_eq!(limited.result, Err(Stop::Memory));
+    assert!(limited.memory.unwrap() > 1 << 20);
+}
+
+#[test]
+fn test_solver_timeout() {
+    let input = std::fs::read_to_string(format!("{}/day_23_test.txt", DATA)).unwrap();
+    let solver = get_solver(2021, 23).unwrap();
+    let limits = Limits { timeout: Some(Duration::from_millis(1)), memory: None };
+    let limited = run(limits, Token::new(), move || solver.solve(2, &input));
+    assert_eq!(limited.result.err(), Some(Stop::Timeout));
+}
@@ -0,0 +1,82 @@
+//! Feeds arbitrary input to the parser of every day, see `y2021::fuzz::check` for what's checked.
+//!
+//! The inputs are random text, random bytes and generated puzzle inputs with random edits, which get
+//! further into the parsers. `AOC_STRESS_SEED` and `AOC_STRESS_CASES` work like for the differential tests.
+
+use std::panic;
+
+use advent_of_code::{random::Rng, y2021::{fuzz::check, generate}};
+
+/// Characters which appear in the puzzle inputs
+const ALPHABET: &[u8] = b"0123456789-,.=[]#>v<> ABCDEFXYZabcdefghijklmnopqrstuvwxyz:|\n";
+
+fn env(name: &str, default: u64) -> u64 {
+    std::env::var(name).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+fn text(rng: &mut Rng) -> Vec<u8> {
+    let len = rng.below(200);
+    (0..len).map(|_| *rng.choose(ALPHABET).unwrap()).collect()
+}
+
+fn bytes(rng: &mut Rng) -> Vec<u8> {
+    let len = rng.below(100);
+    (0..len).map(|_| rng.below(256) as u8).collect()
+}
+
+/// A generated input with a few characters changed, inserted or removed, a huge number inserted or truncated
+fn edited(rng: &mut Rng, day: usize) -> Vec<u8> {
+    let params = generate::Params::new(rng.next_u64(), 1 + rng.below(8), rng.below(10) as f64 / 10.);
+    let mut input = generate::input(day, &params).unwrap().into_bytes();
+    for _ in 0..1 + rng.below(3) {
+        if input.is_empty() {
+            break;
+        }
+        let i = rng.below(input.len());
+        match rng.below(5) {
+            0 => input[i] = *rng.choose(ALPHABET).unwrap(),
+            1 => input.insert(i, *rng.choose(ALPHABET).unwrap()),
+            2 => drop(input.remove(i)),
+            3 => drop(input.splice(i..i, b"99999999999999999999".iter().copied())),
+            _ => input.truncate(i),
+        }
+    }
+    input
+}
+
+fn check_all(name: &str, mut input: impl FnMut(&mut Rng, usize) -> Vec<u8>) {
+    let seed = env("AOC_STRESS_SEED", 0);
+    let cases = env("AOC_STRESS_CASES", 200);
+    let mut rng = Rng::new(seed);
+    for day in 1..=25 {
+        for _ in 0..cases {
+            let data = input(&mut rng, day);
+            if panic::catch_unwind(|| check(day, &data)).is_err() {
+                panic!("day {} failed on {} input {:?}", day, name, String::from_utf8_lossy(&data));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_random_text() {
+    check_all("random text", |rng, _| text(rng));
+}
+
+#[test]
+fn test_random_bytes() {
+    check_all("random bytes", |rng, _| bytes(rng));
+}
+
+#[test]
+fn test_edited_inputs() {
+    check_all("edited", edited);
+}
+
+#[test]
+fn test_generated_inputs_round_trip() {
+    check_all("generated", |rng, day| {
+        let params = generate::Params::new(rng.next_u64(), 1 + rng.below(10), 0.7);
+        generate::input(day, &params).unwrap().into_bytes()
+    });
+}
@@ -0,0 +1,54 @@
+//! Saves parsed models to JSON and loads them again, only built with `--features serde`.
+#![cfg(feature = "serde")]
+
+use std::fmt::Debug;
+
+use advent_of_code::y2021::{day_12, day_18, day_19, day_22};
+use serde::{de::DeserializeOwned, Serialize};
+
+const DATA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/2021");
+
+fn read(name: &str) -> String {
+    std::fs::read_to_string(format!("{}/{}.txt", DATA, name)).unwrap()
+}
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) -> T {
+    let json = serde_json::to_string(value).unwrap();
+    let loaded: T = serde_json::from_str(&json).unwrap();
+    assert_eq!(&loaded, value);
+    loaded
+}
+
+#[test]
+fn test_aligned_scanners() {
+    let scanners = day_19::parse(&read("day_19_test")).unwrap();
+    let map = round_trip(&day_19::Map::align(&scanners).unwrap());
+    assert_eq!(map.beacons().len(), 79);
+    assert_eq!(map.largest_distance(), 3621);
+}
+
+#[test]
+fn test_cuboid_grid() {
+    let instructions = round_trip(&day_22::parse(&read("day_22_test")).unwrap());
+    let grid = round_trip(&day_22::lit_cuboids(&instructions));
+    assert_eq!(grid.iter().map(day_22::Cuboid::volume).sum::<i64>(), 39);
+}
+
+#[test]
+fn test_snail_number() {
+    let numbers = day_18::parse(&read("day_18_test")).unwrap();
+    let mut sum = round_trip(&numbers[0]);
+    for number in &numbers[1..] {
+        sum += number.clone();
+    }
+    assert_eq!(round_trip(&sum).magnitude(), 4140);
+}
+
+#[test]
+fn test_graph() {
+    let caves = round_trip(&day_12::parse(&read("day_12_test")).unwrap());
+    let graph = round_trip(&caves.graph(1));
+    assert_eq!(graph.cave(0), Some("start"));
+    assert_eq!(graph.cave(1), Some("end"));
+    assert_eq!(graph.adj_matrix().len(), 6);
+}
@@ -0,0 +1,42 @@
+//! Runs the server on a free port and sends it requests like another tool would.
+
+use std::{thread, time::Duration};
+
+use advent_of_code::{http::{PlainHttp, Request, Transport}, limit::Limits, server::Server};
+
+const DATA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/2021");
+
+fn start(limits: Limits) -> String {
+    let server = Server::bind("127.0.0.1:0", limits).unwrap();
+    let url = server.url().unwrap();
+    thread::spawn(move || server.serve());
+    url
+}
+
+#[test]
+fn test_solve_over_http() {
+    let url = start(Limits::default());
+    let input = std::fs::read_to_string(format!("{}/day_13_test.txt", DATA)).unwrap();
+
+    let response = PlainHttp::default().send(&Request::post(format!("{}/solve/13/1", url), input)).unwrap();
+    assert_eq!(response.status, 200);
+    assert!(response.body.ends_with("\"answer\": \"17\"}"), "{}", response.body);
+
+    let response = PlainHttp::default().send(&Request::post(format!("{}/solve/13/1", url), "1,x\n")).unwrap();
+    assert_eq!(response.status, 422);
+    assert!(response.body.contains("\"line\": 1, \"column\": 3"), "{}", response.body);
+
+    let response = PlainHttp::default().send(&Request::get(format!("{}/days", url))).unwrap();
+    assert_eq!(response.status, 200);
+    assert!(response.body.contains("\"year\": 2021"));
+}
+
+#[test]
+fn test_timeout_over_http() {
+    let url = start(Limits { timeout: Some(Duration::from_millis(1)), memory: None });
+    let input = std::fs::read_to_string(format!("{}/day_23.txt", DATA)).unwrap();
+
+    let response = PlainHttp::default().send(&Request::post(format!("{}/solve/23/2", url), input)).unwrap();
+    assert_eq!(response.status, 503);
+    assert!(response.body.ends_with("\"error\": \"TIMEOUT\"}"), "{}", response.body);
+}